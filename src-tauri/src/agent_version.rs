@@ -0,0 +1,161 @@
+//! Agent Version Module
+//!
+//! Agents move their skills directory between releases. For agents configured in
+//! `agent_versions`, the installed version is read from a version file and matched
+//! against version ranges, each naming the skills path used by those releases.
+//! When the version cannot be read or no range matches, the agent's default path
+//! is kept.
+
+use crate::Agent;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fs;
+use std::path::PathBuf;
+
+/// Skills path used by a range of agent versions
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct VersionedPath {
+    pub min_version: Option<String>, // Inclusive lower bound, unbounded when None
+    pub max_version: Option<String>, // Exclusive upper bound, unbounded when None
+    pub path: String,                // Skills directory relative to home
+}
+
+/// How to detect an agent's version and which path each version range uses
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct AgentVersioning {
+    /// File relative to home holding the version, either as plain text or as a JSON
+    /// object with a `version` field
+    pub version_file: String,
+    /// Ranges in priority order; the first matching range wins
+    pub paths: Vec<VersionedPath>,
+}
+
+/// Reads an agent's version from its version file.
+pub fn read_agent_version(home: &PathBuf, versioning: &AgentVersioning) -> Option<String> {
+    let content = fs::read_to_string(home.join(&versioning.version_file)).ok()?;
+    let version = match serde_json::from_str::<serde_json::Value>(&content) {
+        Ok(serde_json::Value::Object(object)) => object.get("version")?.as_str()?.to_string(),
+        _ => content.lines().map(str::trim).find(|line| !line.is_empty())?.to_string(),
+    };
+    Some(version.trim_start_matches('v').to_string())
+}
+
+/// Compares dotted versions numerically, treating missing components as zero.
+/// Non-numeric suffixes of a component (as in `3-beta`) are ignored.
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    let parse = |version: &str| -> Vec<u64> {
+        version
+            .split('.')
+            .map(|part| part.chars().take_while(char::is_ascii_digit).collect::<String>().parse().unwrap_or(0))
+            .collect()
+    };
+    let (a, b) = (parse(a), parse(b));
+    for i in 0..a.len().max(b.len()) {
+        let ordering = a.get(i).unwrap_or(&0).cmp(b.get(i).unwrap_or(&0));
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+    Ordering::Equal
+}
+
+/// Returns the path of the first range containing `version`.
+pub fn select_versioned_path<'a>(version: &str, paths: &'a [VersionedPath]) -> Option<&'a str> {
+    paths
+        .iter()
+        .find(|range| {
+            let above_min = range.min_version.as_deref().map(|min| compare_versions(version, min) != Ordering::Less).unwrap_or(true);
+            let below_max = range.max_version.as_deref().map(|max| compare_versions(version, max) == Ordering::Less).unwrap_or(true);
+            above_min && below_max
+        })
+        .map(|range| range.path.as_str())
+}
+
+/// Sets an agent's version and, when a range matches, its skills path and detection.
+pub fn apply_agent_version(agent: &mut Agent, home: &PathBuf, versioning: &AgentVersioning) {
+    agent.version = read_agent_version(home, versioning);
+    if let Some(path) = agent.version.as_deref().and_then(|version| select_versioned_path(version, &versioning.paths)) {
+        agent.path = path.to_string();
+        agent.detected = home.join(path).exists();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{config, delete_local_skill_with_home, detect_agents_with_home, upload_to_global_with_home, AppConfig};
+    use tempfile::TempDir;
+
+    /// Test that a detected version selects the alternate skills path, and other versions keep the default
+    #[test]
+    fn test_detected_version_selects_alternate_path() {
+        // Arrange: cursor moves its skills to `.cursor/skills-v2` from version 2.0
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        fs::create_dir_all(home_path.join(".cursor/skills-v2")).expect("Failed to create skills directory");
+        let versioning = AgentVersioning {
+            version_file: ".cursor/version.json".to_string(),
+            paths: vec![VersionedPath { min_version: Some("2.0".to_string()), path: ".cursor/skills-v2".to_string(), ..Default::default() }],
+        };
+        let config = AppConfig { agent_versions: [("cursor".to_string(), versioning)].into_iter().collect(), ..Default::default() };
+        config::save_config_with_home(&config, &home_path).expect("Failed to save config");
+        let cursor = || detect_agents_with_home(&home_path).into_iter().find(|a| a.id == "cursor").expect("cursor missing");
+
+        // Act & Assert: Unknown version keeps the default path
+        let agent = cursor();
+        assert_eq!(agent.version, None);
+        assert_eq!(agent.path, ".cursor/skills");
+
+        // Act & Assert: A 2.x version selects the alternate path
+        fs::write(home_path.join(".cursor/version.json"), r#"{"version": "2.1.0"}"#).expect("Failed to write version");
+        let agent = cursor();
+        assert_eq!(agent.version.as_deref(), Some("2.1.0"));
+        assert_eq!(agent.path, ".cursor/skills-v2");
+        assert!(agent.detected);
+
+        // Act & Assert: An older version falls back to the default path
+        fs::write(home_path.join(".cursor/version.json"), "1.9.3\n").expect("Failed to write version");
+        let agent = cursor();
+        assert_eq!(agent.version.as_deref(), Some("1.9.3"));
+        assert_eq!(agent.path, ".cursor/skills");
+    }
+
+    /// Test that local-skill operations act on the skills path selected by the agent's version
+    #[test]
+    fn test_local_skill_operations_use_versioned_path() {
+        // Arrange: cursor 2.1 keeps skills in `.cursor/skills-v2`, each path holding a local skill
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        for dir in [".cursor/skills/old-skill", ".cursor/skills-v2/new-skill", ".cursor/skills-v2/old-skill"] {
+            fs::create_dir_all(home_path.join(dir)).expect("Failed to create skill directory");
+        }
+        fs::write(home_path.join(".cursor/version.json"), r#"{"version": "2.1.0"}"#).expect("Failed to write version");
+        let versioning = AgentVersioning {
+            version_file: ".cursor/version.json".to_string(),
+            paths: vec![VersionedPath { min_version: Some("2.0".to_string()), path: ".cursor/skills-v2".to_string(), ..Default::default() }],
+        };
+        let config = AppConfig { agent_versions: [("cursor".to_string(), versioning)].into_iter().collect(), ..Default::default() };
+        config::save_config_with_home(&config, &home_path).expect("Failed to save config");
+
+        // Act
+        let uploaded = upload_to_global_with_home("cursor", "new-skill", &home_path);
+        let deleted = delete_local_skill_with_home("cursor", "old-skill", &home_path);
+
+        // Assert: Both operations used `.cursor/skills-v2`, leaving the default path alone
+        assert!(uploaded.is_ok(), "upload should succeed: {:?}", uploaded);
+        assert!(home_path.join(".agents/skills/new-skill").is_dir());
+        assert!(deleted.is_ok(), "delete should succeed: {:?}", deleted);
+        assert!(!home_path.join(".cursor/skills-v2/old-skill").exists());
+        assert!(home_path.join(".cursor/skills/old-skill").is_dir());
+    }
+
+    /// Test that versions compare numerically rather than as strings
+    #[test]
+    fn test_compare_versions_numeric() {
+        assert_eq!(compare_versions("1.10", "1.9"), Ordering::Greater);
+        assert_eq!(compare_versions("2.0", "2"), Ordering::Equal);
+        assert_eq!(compare_versions("1.2.3-beta", "1.2.4"), Ordering::Less);
+    }
+}
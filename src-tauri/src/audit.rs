@@ -0,0 +1,232 @@
+//! Symlink Audit Module
+//!
+//! This module walks every agent's skills directory and classifies each symlink
+//! it finds against the global skill roots. The audit is read-only; it is the
+//! diagnostic used by the repair features to decide what needs fixing.
+
+use crate::{detect_agents_with_home, get_home_dir, global_skill_roots_with_home};
+use std::fs;
+use std::path::PathBuf;
+
+/// Classification of a symlink in an agent's skills directory
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SymlinkStatus {
+    /// Points at the global skill with the same name
+    ValidGlobal,
+    /// Target does not exist
+    Broken,
+    /// Target exists but is not inside any global skill root
+    OutsideGlobal,
+    /// Target is inside a global root, but not a global skill directory
+    WrongTarget,
+    /// Target is a global skill whose directory name differs from the link's name,
+    /// typically a rename that did not reach the agent
+    NameTargetMismatch,
+}
+
+/// A single symlink found by the audit
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct SymlinkAuditEntry {
+    pub agent_id: String,
+    pub skill_name: String, // Name of the link in the agent's skills directory
+    pub target: String,     // Raw link target
+    pub status: SymlinkStatus,
+}
+
+/// Result of auditing every symlink across all agents
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct SymlinkAudit {
+    pub valid_global: usize,
+    pub broken: usize,
+    pub outside_global: usize,
+    pub wrong_target: usize,
+    #[serde(default)]
+    pub name_target_mismatch: usize,
+    pub entries: Vec<SymlinkAuditEntry>,
+}
+
+/// Classifies a symlink named `skill_name` against the canonicalized global roots.
+fn classify_symlink(link: &std::path::Path, skill_name: &str, roots: &[PathBuf]) -> SymlinkStatus {
+    let Ok(resolved) = fs::canonicalize(link) else {
+        return SymlinkStatus::Broken;
+    };
+    for root in roots {
+        if resolved.starts_with(root) {
+            // Valid only when the link resolves to the root's direct child of the same name
+            return if resolved.parent() != Some(root.as_path()) {
+                SymlinkStatus::WrongTarget
+            } else if resolved.file_name().map(|n| n == skill_name).unwrap_or(false) {
+                SymlinkStatus::ValidGlobal
+            } else {
+                SymlinkStatus::NameTargetMismatch
+            };
+        }
+    }
+    SymlinkStatus::OutsideGlobal
+}
+
+/// Enumerates every symlink in every agent's skills directory and classifies it.
+/// Entries are ordered by agent definition order, then skill name.
+pub fn audit_symlinks_with_home(home: &PathBuf) -> SymlinkAudit {
+    let roots: Vec<PathBuf> = global_skill_roots_with_home(home)
+        .iter()
+        .filter_map(|root| fs::canonicalize(root).ok())
+        .collect();
+    let mut audit = SymlinkAudit::default();
+
+    for agent in detect_agents_with_home(home) {
+        if !agent.detected {
+            continue;
+        }
+        let Ok(entries) = fs::read_dir(home.join(&agent.path)) else {
+            continue;
+        };
+        let mut links: Vec<(String, PathBuf)> = entries
+            .flatten()
+            .map(|entry| (entry.file_name().to_string_lossy().to_string(), entry.path()))
+            .filter(|(name, _)| !name.starts_with('.'))
+            .filter(|(_, path)| fs::symlink_metadata(path).map(|m| m.file_type().is_symlink()).unwrap_or(false))
+            .collect();
+        links.sort();
+
+        for (skill_name, path) in links {
+            let status = classify_symlink(&path, &skill_name, &roots);
+            match status {
+                SymlinkStatus::ValidGlobal => audit.valid_global += 1,
+                SymlinkStatus::Broken => audit.broken += 1,
+                SymlinkStatus::OutsideGlobal => audit.outside_global += 1,
+                SymlinkStatus::WrongTarget => audit.wrong_target += 1,
+                SymlinkStatus::NameTargetMismatch => audit.name_target_mismatch += 1,
+            }
+            audit.entries.push(SymlinkAuditEntry {
+                agent_id: agent.id.clone(),
+                target: fs::read_link(&path).map(|t| t.to_string_lossy().to_string()).unwrap_or_default(),
+                skill_name,
+                status,
+            });
+        }
+    }
+
+    audit
+}
+
+#[tauri::command]
+pub fn audit_symlinks() -> SymlinkAudit {
+    let home = get_home_dir();
+    audit_symlinks_with_home(&home)
+}
+
+/// Lists every agent symlink whose target no longer exists, as `(agent_id, skill_name)`,
+/// typically left behind when a global skill is deleted outside the app. Local
+/// directories are never reported. Ordered like the audit.
+pub fn find_dangling_skill_references_with_home(home: &PathBuf) -> Vec<(String, String)> {
+    audit_symlinks_with_home(home)
+        .entries
+        .into_iter()
+        .filter(|entry| entry.status == SymlinkStatus::Broken)
+        .map(|entry| (entry.agent_id, entry.skill_name))
+        .collect()
+}
+
+#[tauri::command]
+pub fn find_dangling_skill_references() -> Vec<(String, String)> {
+    let home = get_home_dir();
+    find_dangling_skill_references_with_home(&home)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::create_symlink;
+    use tempfile::TempDir;
+
+    /// Test that one symlink of each classification is catalogued correctly
+    #[test]
+    fn test_audit_symlinks_classifies_each_kind() {
+        // Arrange: Two global skills and an unrelated directory outside the library
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        fs::create_dir_all(global_dir.join("alpha")).expect("Failed to create alpha");
+        fs::create_dir_all(global_dir.join("beta/docs")).expect("Failed to create beta");
+        let outside = home_path.join("projects/gamma");
+        fs::create_dir_all(&outside).expect("Failed to create outside directory");
+        let cursor_dir = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_dir).expect("Failed to create cursor directory");
+        // A local directory is not a symlink and is not audited
+        fs::create_dir_all(cursor_dir.join("local")).expect("Failed to create local skill");
+
+        create_symlink(global_dir.join("alpha"), cursor_dir.join("alpha")).expect("Failed to create valid link");
+        create_symlink(global_dir.join("missing"), cursor_dir.join("missing")).expect("Failed to create broken link");
+        create_symlink(&outside, cursor_dir.join("gamma")).expect("Failed to create outside link");
+        create_symlink(global_dir.join("beta/docs"), cursor_dir.join("nested")).expect("Failed to create wrong link");
+
+        // Act
+        let audit = audit_symlinks_with_home(&home_path);
+
+        // Assert
+        assert_eq!((audit.valid_global, audit.broken, audit.outside_global, audit.wrong_target), (1, 1, 1, 1));
+        let statuses: Vec<(&str, SymlinkStatus)> = audit
+            .entries
+            .iter()
+            .map(|e| (e.skill_name.as_str(), e.status))
+            .collect();
+        assert_eq!(statuses, vec![
+            ("alpha", SymlinkStatus::ValidGlobal),
+            ("gamma", SymlinkStatus::OutsideGlobal),
+            ("missing", SymlinkStatus::Broken),
+            ("nested", SymlinkStatus::WrongTarget),
+        ]);
+        assert!(audit.entries.iter().all(|e| e.agent_id == "cursor"));
+        assert_eq!(audit.entries[2].target, global_dir.join("missing").to_string_lossy());
+    }
+
+    /// Test that a link named differently from the global skill it points at is flagged
+    #[test]
+    fn test_audit_symlinks_flags_name_target_mismatch() {
+        // Arrange: `aws-helper` points at the global skill `aws_helper`
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        fs::create_dir_all(global_dir.join("aws_helper")).expect("Failed to create skill directory");
+        let cursor_dir = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_dir).expect("Failed to create cursor directory");
+        create_symlink(global_dir.join("aws_helper"), cursor_dir.join("aws-helper")).expect("Failed to create symlink");
+
+        // Act
+        let audit = audit_symlinks_with_home(&home_path);
+
+        // Assert
+        assert_eq!(audit.name_target_mismatch, 1);
+        assert_eq!(audit.wrong_target, 0);
+        assert_eq!(audit.entries[0].skill_name, "aws-helper");
+        assert_eq!(audit.entries[0].status, SymlinkStatus::NameTargetMismatch);
+    }
+
+    /// Test that deleting a global skill leaves a dangling reference in each linking agent
+    #[test]
+    fn test_find_dangling_skill_references_after_global_delete() {
+        // Arrange: A skill linked into cursor and claude, a same-named local copy in windsurf
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let skill_dir = home_path.join(".agents/skills/review");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        for agent_dir in [".cursor/skills", ".claude/skills"] {
+            fs::create_dir_all(home_path.join(agent_dir)).expect("Failed to create agent directory");
+            create_symlink(&skill_dir, home_path.join(agent_dir).join("review")).expect("Failed to create symlink");
+        }
+        fs::create_dir_all(home_path.join(".codeium/windsurf/skills/review")).expect("Failed to create local skill");
+        assert!(find_dangling_skill_references_with_home(&home_path).is_empty());
+
+        // Act: The global skill is deleted without cleaning up its links
+        fs::remove_dir_all(&skill_dir).expect("Failed to delete skill");
+        let dangling = find_dangling_skill_references_with_home(&home_path);
+
+        // Assert
+        assert_eq!(dangling, vec![
+            ("claude-code".to_string(), "review".to_string()),
+            ("cursor".to_string(), "review".to_string()),
+        ]);
+    }
+}
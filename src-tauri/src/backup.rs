@@ -0,0 +1,303 @@
+//! Library Backup Module
+//!
+//! Before destructive batch operations, the global library (every configured global
+//! root) can be archived to a timestamped tar.gz in the app data directory, outside the
+//! managed `~/.agents` tree so a restore or cleanup there never touches them. Each root
+//! is stored under its absolute path, relative to the filesystem root, so roots with the
+//! same directory name do not collide. Archives are created and extracted with the
+//! system `tar`, which keeps symlinks as symlinks.
+//!
+//! Restoring extracts each root beside its current directory first, then swaps them in;
+//! the current directories are kept aside until every swap succeeded and put back if
+//! one fails, so a bad archive or a failed rename never loses the library.
+
+use crate::{ensure_not_safe_mode, get_home_dir, global_skill_roots_with_home};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::process::Command;
+
+/// Directory, relative to home, that holds library backups: `backups` in the app data
+/// directory Tauri uses for the app's identifier
+#[cfg(target_os = "macos")]
+pub const BACKUP_DIR: &str = "Library/Application Support/com.skills-manager.app/backups";
+#[cfg(target_os = "windows")]
+pub const BACKUP_DIR: &str = "AppData/Roaming/com.skills-manager.app/backups";
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+pub const BACKUP_DIR: &str = ".local/share/com.skills-manager.app/backups";
+
+/// Runs the system `tar` with the given arguments, returning its standard output.
+fn run_tar(args: &[&std::ffi::OsStr]) -> Result<String, String> {
+    let output = Command::new("tar").args(args).output().map_err(|e| format!("Failed to run tar: {}", e))?;
+    if !output.status.success() {
+        return Err(format!("tar failed: {}", String::from_utf8_lossy(&output.stderr).trim()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Splits an absolute path into its filesystem root and the path below it, as stored in
+/// the archive (e.g. `/home/u/.agents/skills` into `/` and `home/u/.agents/skills`).
+fn split_filesystem_root(path: &Path) -> (PathBuf, PathBuf) {
+    let (prefix, rest): (Vec<Component>, Vec<Component>) =
+        path.components().partition(|c| matches!(c, Component::Prefix(_) | Component::RootDir));
+    (prefix.iter().collect(), rest.iter().collect())
+}
+
+/// The path of an archive member, `/`-separated, as `tar` lists it.
+fn member_name(path: &Path) -> String {
+    path.components().map(|c| c.as_os_str().to_string_lossy().to_string()).collect::<Vec<_>>().join("/")
+}
+
+/// Archives every existing global root and returns the path of the new archive.
+pub fn backup_library_with_home(home: &PathBuf) -> Result<String, String> {
+    let roots: Vec<PathBuf> = global_skill_roots_with_home(home)
+        .into_iter()
+        .filter(|root| root.is_dir())
+        .filter_map(|root| fs::canonicalize(root).ok())
+        .collect();
+    if roots.is_empty() {
+        return Err("No global skills directory exists".to_string());
+    }
+    let backup_dir = home.join(BACKUP_DIR);
+    fs::create_dir_all(&backup_dir).map_err(|e| format!("Failed to create backup directory: {}", e))?;
+
+    let millis = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or_default();
+    let mut archive = backup_dir.join(format!("skills-{}.tar.gz", millis));
+    let mut suffix = 1;
+    while archive.exists() {
+        archive = backup_dir.join(format!("skills-{}-{}.tar.gz", millis, suffix));
+        suffix += 1;
+    }
+
+    let (filesystem_root, _) = split_filesystem_root(&roots[0]);
+    let members: Vec<PathBuf> = roots.iter().map(|root| split_filesystem_root(root).1).collect();
+    let mut args: Vec<&std::ffi::OsStr> = vec!["-czf".as_ref(), archive.as_os_str(), "-C".as_ref(), filesystem_root.as_os_str()];
+    args.extend(members.iter().map(|member| member.as_os_str()));
+    run_tar(&args)?;
+    Ok(archive.to_string_lossy().to_string())
+}
+
+#[tauri::command]
+pub fn backup_library() -> Result<String, String> {
+    let home = get_home_dir();
+    backup_library_with_home(&home)
+}
+
+/// Backs up the library when `auto_backup` is requested by a destructive command.
+/// Refused in safe mode first, since the command itself would be refused.
+pub fn backup_if_requested(auto_backup: Option<bool>, home: &PathBuf) -> Result<(), String> {
+    ensure_not_safe_mode(home)?;
+    if auto_backup.unwrap_or(false) {
+        backup_library_with_home(home)?;
+    }
+    Ok(())
+}
+
+/// A global root being restored: the extracted copy waiting beside it, and where the
+/// current directory is kept until the restore completes
+struct PendingRestore {
+    root: PathBuf,
+    staging: PathBuf,
+    aside: PathBuf,
+}
+
+/// Extracts one root from the archive into a staging directory beside the root.
+fn extract_root(archive_path: &Path, root: &Path, member: &Path) -> Result<PendingRestore, String> {
+    let parent = root.parent().ok_or_else(|| format!("Cannot restore '{}'", root.display()))?;
+    let name = root.file_name().unwrap_or_default().to_string_lossy().to_string();
+    let id = std::process::id();
+    let staging = parent.join(format!(".{}.restore-{}", name, id));
+    let aside = parent.join(format!(".{}.pre-restore-{}", name, id));
+    if staging.exists() {
+        let _ = fs::remove_dir_all(&staging);
+    }
+    fs::create_dir_all(&staging).map_err(|e| format!("Failed to create staging directory: {}", e))?;
+
+    // Keep only the root's own directory name from the stored path
+    let strip = member.components().count().saturating_sub(1).to_string();
+    let member = member_name(member);
+    let extracted = run_tar(&[
+        "-xzf".as_ref(),
+        archive_path.as_os_str(),
+        "-C".as_ref(),
+        staging.as_os_str(),
+        "--strip-components".as_ref(),
+        strip.as_ref(),
+        member.as_ref(),
+    ]);
+    let restored = staging.join(&name);
+    match extracted {
+        Ok(_) if restored.is_dir() => Ok(PendingRestore { root: root.to_path_buf(), staging, aside }),
+        Ok(_) => {
+            let _ = fs::remove_dir_all(&staging);
+            Err(format!("Backup does not contain '{}'", root.display()))
+        }
+        Err(e) => {
+            let _ = fs::remove_dir_all(&staging);
+            Err(e)
+        }
+    }
+}
+
+/// Moves the current root aside and the restored copy into its place.
+fn swap_in(pending: &PendingRestore) -> Result<(), String> {
+    let restored = pending.staging.join(pending.root.file_name().unwrap_or_default());
+    let had_root = pending.root.exists();
+    if had_root {
+        fs::rename(&pending.root, &pending.aside).map_err(|e| format!("Failed to move current library aside: {}", e))?;
+    }
+    fs::rename(&restored, &pending.root).map_err(|e| {
+        if had_root {
+            let _ = fs::rename(&pending.aside, &pending.root);
+        }
+        format!("Failed to restore library: {}", e)
+    })
+}
+
+/// Puts a swapped-in root back the way it was before the restore.
+fn undo_swap(pending: &PendingRestore) {
+    let restored = pending.staging.join(pending.root.file_name().unwrap_or_default());
+    if fs::rename(&pending.root, &restored).is_ok() && pending.aside.exists() {
+        let _ = fs::rename(&pending.aside, &pending.root);
+    }
+}
+
+/// Replaces the global roots with their contents in a backup archive. Roots the archive
+/// does not contain are left untouched. Either every contained root is restored or none is.
+pub fn restore_library_with_home(archive_path: &Path, home: &PathBuf) -> Result<(), String> {
+    ensure_not_safe_mode(home)?;
+    if !archive_path.is_file() {
+        return Err(format!("Backup '{}' not found", archive_path.display()));
+    }
+    let listing = run_tar(&["-tzf".as_ref(), archive_path.as_os_str()])?;
+    let members: Vec<&str> = listing.lines().map(|line| line.trim_end_matches('/')).collect();
+
+    // Phase 1: extract every contained root beside its current directory
+    let mut pending: Vec<PendingRestore> = Vec::new();
+    let mut result = Ok(());
+    for root in global_skill_roots_with_home(home) {
+        let root = fs::canonicalize(&root).unwrap_or(root);
+        let member = split_filesystem_root(&root).1;
+        if !members.contains(&member_name(&member).as_str()) {
+            continue;
+        }
+        match extract_root(archive_path, &root, &member) {
+            Ok(extracted) => pending.push(extracted),
+            Err(e) => {
+                result = Err(e);
+                break;
+            }
+        }
+    }
+    if result.is_ok() && pending.is_empty() {
+        result = Err("Backup does not contain any configured global skills directory".to_string());
+    }
+
+    // Phase 2: swap them in, undoing earlier swaps if one fails
+    if result.is_ok() {
+        for (index, restore) in pending.iter().enumerate() {
+            if let Err(e) = swap_in(restore) {
+                pending[..index].iter().rev().for_each(undo_swap);
+                result = Err(e);
+                break;
+            }
+        }
+    }
+
+    for restore in &pending {
+        let _ = fs::remove_dir_all(&restore.staging);
+        if result.is_ok() {
+            let _ = fs::remove_dir_all(&restore.aside);
+        }
+    }
+    result
+}
+
+#[tauri::command]
+pub fn restore_library(archive_path: String) -> Result<(), String> {
+    let home = get_home_dir();
+    restore_library_with_home(Path::new(&archive_path), &home)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    /// Test that restoring a backup undoes changes made to the library after it was taken
+    #[test]
+    fn test_backup_and_restore_library() {
+        // Arrange: A library with one skill and a backup of it
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        fs::create_dir_all(global_dir.join("alpha")).expect("Failed to create skill directory");
+        fs::write(global_dir.join("alpha/SKILL.md"), "---\nname: Alpha\n---\n").expect("Failed to write SKILL.md");
+        let archive = backup_library_with_home(&home_path).expect("backup should succeed");
+        assert!(archive.ends_with(".tar.gz"));
+        assert!(Path::new(&archive).starts_with(home_path.join(BACKUP_DIR)));
+
+        // Act: Modify the library, then restore
+        fs::write(global_dir.join("alpha/SKILL.md"), "---\nname: Changed\n---\n").expect("Failed to write SKILL.md");
+        fs::create_dir_all(global_dir.join("beta")).expect("Failed to create skill directory");
+        restore_library_with_home(Path::new(&archive), &home_path).expect("restore should succeed");
+
+        // Assert
+        let content = fs::read_to_string(global_dir.join("alpha/SKILL.md")).expect("Failed to read SKILL.md");
+        assert_eq!(content, "---\nname: Alpha\n---\n");
+        assert!(!global_dir.join("beta").exists());
+        let backups: Vec<_> = fs::read_dir(home_path.join(BACKUP_DIR)).expect("Failed to read backups").flatten().collect();
+        assert_eq!(backups.len(), 1, "Only the archive should remain in the backup directory");
+    }
+
+    /// Test that a requested backup is refused in safe mode without writing an archive
+    #[test]
+    fn test_backup_if_requested_refused_in_safe_mode() {
+        // Arrange: A library with one skill and safe mode on
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        fs::create_dir_all(home_path.join(".agents/skills/alpha")).expect("Failed to create skill directory");
+        let config = crate::AppConfig { safe_mode: true, ..Default::default() };
+        crate::config::save_config_with_home(&config, &home_path).expect("Failed to save config");
+
+        // Act
+        let result = backup_if_requested(Some(true), &home_path);
+
+        // Assert
+        assert_eq!(result, Err(crate::SAFE_MODE_ERROR.to_string()));
+        assert!(!home_path.join(BACKUP_DIR).exists());
+    }
+
+    /// Test that every configured root is backed up and restored, even roots sharing a name
+    #[test]
+    fn test_backup_and_restore_multiple_roots() {
+        // Arrange: Two roots both named `skills`, one outside home
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let team = TempDir::new().expect("Failed to create temp directory");
+        let team_root = team.path().join("skills");
+        let config = crate::AppConfig {
+            global_paths: vec!["~/.agents/skills".to_string(), team_root.to_string_lossy().to_string()],
+            ..Default::default()
+        };
+        crate::config::save_config_with_home(&config, &home_path).expect("Failed to save config");
+        let personal_root = home_path.join(".agents/skills");
+        for (root, skill) in [(&personal_root, "mine"), (&team_root, "shared")] {
+            fs::create_dir_all(root.join(skill)).expect("Failed to create skill directory");
+            fs::write(root.join(skill).join("SKILL.md"), skill).expect("Failed to write SKILL.md");
+        }
+        let archive = backup_library_with_home(&home_path).expect("backup should succeed");
+
+        // Act: Delete from both roots, then restore
+        fs::remove_dir_all(personal_root.join("mine")).expect("Failed to delete skill");
+        fs::remove_dir_all(team_root.join("shared")).expect("Failed to delete skill");
+        restore_library_with_home(Path::new(&archive), &home_path).expect("restore should succeed");
+
+        // Assert: Both skills are back, with nothing left beside the roots
+        assert_eq!(fs::read_to_string(personal_root.join("mine/SKILL.md")).unwrap(), "mine");
+        assert_eq!(fs::read_to_string(team_root.join("shared/SKILL.md")).unwrap(), "shared");
+        assert_eq!(fs::read_dir(team.path()).unwrap().count(), 1);
+    }
+}
@@ -0,0 +1,191 @@
+//! Configuration Module
+//!
+//! This module loads and saves the user's Skills Manager settings, stored as JSON
+//! in `~/.agents/skills-manager.json`. A missing or unreadable config file is not
+//! an error: every setting has a default that matches the behaviour of an
+//! unconfigured installation.
+
+use crate::agent_version::AgentVersioning;
+use crate::LinkLayout;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// Location of the config file, relative to the home directory
+pub const CONFIG_FILE: &str = ".agents/skills-manager.json";
+
+/// Default global skills directory, relative to the home directory
+pub const DEFAULT_GLOBAL_PATH: &str = ".agents/skills";
+
+/// Environment variable that turns on safe mode regardless of the config file
+pub const SAFE_MODE_ENV: &str = "SKILLS_MANAGER_SAFE_MODE";
+
+/// Environment variable naming a single global skills directory, overriding the config file
+pub const GLOBAL_PATH_ENV: &str = "SKILLS_MANAGER_GLOBAL_PATH";
+
+/// `SKILLS_MANAGER_GLOBAL_PATH` as read at startup; it does not change while the app runs
+static GLOBAL_PATH_FROM_ENV: std::sync::OnceLock<Option<String>> = std::sync::OnceLock::new();
+
+/// User settings persisted in the config file
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct AppConfig {
+    /// Global skill search paths in priority order. Entries may be absolute,
+    /// start with `~/`, or be relative to the home directory.
+    /// When empty, only the default `~/.agents/skills` is searched.
+    pub global_paths: Vec<String>,
+    /// Also surface unknown `~/*/skills` and `~/*/*/skills` directories as agents
+    pub discover_agents: bool,
+    /// When non-empty, only these agent IDs are shown and touched by any operation
+    pub enabled_agents: Vec<String>,
+    /// Agent IDs that are never shown or touched, applied after `enabled_agents`
+    pub hidden_agents: Vec<String>,
+    /// Descriptions longer than this many characters are flagged by validation.
+    /// When unset, `DEFAULT_MAX_DESCRIPTION_LEN` (1024) is used.
+    pub max_description_len: Option<usize>,
+    /// Per-agent link layout, by agent ID. Agents not listed use `LinkLayout::Directory`.
+    pub link_layouts: BTreeMap<String, LinkLayout>,
+    /// Agent IDs whose skills directory also holds plugin subdirectories of skills
+    pub nested_scan_agents: Vec<String>,
+    /// Per-agent list of tools the agent can execute, by agent ID.
+    /// Agents not listed are assumed to support every tool.
+    pub supported_tools: BTreeMap<String, Vec<String>>,
+    /// Per-agent version detection and version-specific skills paths, by agent ID
+    pub agent_versions: BTreeMap<String, AgentVersioning>,
+    /// Saved agent groups: agent IDs by group name
+    pub agent_groups: BTreeMap<String, Vec<String>>,
+    /// Refuse every operation that changes the filesystem, for demos and shared machines
+    pub safe_mode: bool,
+}
+
+/// Loads the config file from the given home directory.
+/// Falls back to the default config if the file is missing or invalid.
+pub fn load_config_with_home(home: &PathBuf) -> AppConfig {
+    fs::read_to_string(home.join(CONFIG_FILE))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Whether safe mode is on, from the config file or the `SKILLS_MANAGER_SAFE_MODE`
+/// environment variable (`1`, `true`, `yes` or `on`).
+pub fn safe_mode_enabled_with_home(home: &PathBuf) -> bool {
+    let from_env = std::env::var(SAFE_MODE_ENV)
+        .map(|value| matches!(value.trim().to_ascii_lowercase().as_str(), "1" | "true" | "yes" | "on"))
+        .unwrap_or(false);
+    from_env || load_config_with_home(home).safe_mode
+}
+
+/// Writes the config file to the given home directory, creating `~/.agents` if needed.
+pub fn save_config_with_home(config: &AppConfig, home: &PathBuf) -> Result<(), String> {
+    let path = home.join(CONFIG_FILE);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create config directory: {}", e))?;
+    }
+    let content = serde_json::to_string_pretty(config)
+        .map_err(|e| format!("Failed to serialize config: {}", e))?;
+    fs::write(&path, content).map_err(|e| format!("Failed to write config: {}", e))
+}
+
+/// Resolves a configured path against the home directory.
+pub fn resolve_path(home: &PathBuf, path: &str) -> PathBuf {
+    if path == "~" {
+        home.clone()
+    } else if let Some(rest) = path.strip_prefix("~/") {
+        home.join(rest)
+    } else {
+        // Joining an absolute path replaces the home prefix entirely
+        home.join(path)
+    }
+}
+
+/// `SKILLS_MANAGER_GLOBAL_PATH`, read once; the app calls this at startup so later
+/// changes to the process environment have no effect.
+pub fn global_path_from_env() -> Option<&'static str> {
+    GLOBAL_PATH_FROM_ENV.get_or_init(|| std::env::var(GLOBAL_PATH_ENV).ok()).as_deref()
+}
+
+/// Returns the global skill roots in priority order, as chosen by [`resolve_global_roots`].
+/// The first root wins when the same skill name exists in several roots.
+pub fn global_skill_roots_with_home(home: &PathBuf) -> Vec<PathBuf> {
+    resolve_global_roots(home, global_path_from_env(), &load_config_with_home(home))
+}
+
+/// Chooses the global skill roots, in order of precedence:
+/// 1. `env_path` (from `SKILLS_MANAGER_GLOBAL_PATH`), if set and non-empty, as the only root
+/// 2. `global_paths` from the config file, if non-empty
+/// 3. The default `~/.agents/skills`
+pub fn resolve_global_roots(home: &PathBuf, env_path: Option<&str>, config: &AppConfig) -> Vec<PathBuf> {
+    if let Some(path) = env_path.map(str::trim).filter(|path| !path.is_empty()) {
+        return vec![resolve_path(home, path)];
+    }
+    if config.global_paths.is_empty() {
+        return vec![home.join(DEFAULT_GLOBAL_PATH)];
+    }
+    config
+        .global_paths
+        .iter()
+        .map(|path| resolve_path(home, path))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    /// Test that a missing config file yields the defaults
+    #[test]
+    fn test_load_config_defaults_when_missing() {
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+
+        let config = load_config_with_home(&home_path);
+
+        assert_eq!(config, AppConfig::default());
+        assert_eq!(global_skill_roots_with_home(&home_path), vec![home_path.join(".agents/skills")]);
+    }
+
+    /// Test that configured global paths are resolved against home in order
+    #[test]
+    fn test_global_skill_roots_resolve_configured_paths() {
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let shared = temp_home.path().join("mnt/shared");
+
+        let config = AppConfig {
+            global_paths: vec![
+                "~/.agents/skills".to_string(),
+                "team/skills".to_string(),
+                shared.to_string_lossy().to_string(),
+            ],
+            ..Default::default()
+        };
+        save_config_with_home(&config, &home_path).expect("Failed to save config");
+
+        let roots = global_skill_roots_with_home(&home_path);
+
+        assert_eq!(roots, vec![
+            home_path.join(".agents/skills"),
+            home_path.join("team/skills"),
+            shared,
+        ]);
+    }
+
+    /// Test that the environment path wins over the config, which wins over the default
+    #[test]
+    fn test_resolve_global_roots_precedence() {
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let config = AppConfig { global_paths: vec!["team/skills".to_string()], ..Default::default() };
+
+        assert_eq!(resolve_global_roots(&home_path, Some("~/ci/skills"), &config), vec![home_path.join("ci/skills")]);
+        assert_eq!(resolve_global_roots(&home_path, Some(""), &config), vec![home_path.join("team/skills")]);
+        assert_eq!(resolve_global_roots(&home_path, None, &config), vec![home_path.join("team/skills")]);
+        assert_eq!(
+            resolve_global_roots(&home_path, None, &AppConfig::default()),
+            vec![home_path.join(DEFAULT_GLOBAL_PATH)]
+        );
+    }
+}
@@ -0,0 +1,39 @@
+//! Confirmation Token Module
+//!
+//! Destructive batch commands only run after the caller has seen what they would
+//! remove. A preview returns the affected items together with a confirmation token
+//! derived from the operation, its subject and those items; the destructive command
+//! recomputes the preview and refuses unless the caller passes back the same token.
+//! A token therefore goes stale as soon as the set of affected items changes.
+
+use crate::Fnv1a;
+
+/// What a destructive command would affect, and the token that confirms it
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct ConfirmPreview {
+    pub affected: Vec<String>, // Items the command would remove, sorted
+    pub confirm_token: String, // Pass back to the command to run it
+}
+
+impl ConfirmPreview {
+    /// Builds a preview for `operation` on `subject`, sorting the affected items.
+    pub fn new(operation: &str, subject: &str, mut affected: Vec<String>) -> Self {
+        affected.sort();
+        let mut hash = Fnv1a::default();
+        hash.feed_field(operation.as_bytes());
+        hash.feed_field(subject.as_bytes());
+        for item in &affected {
+            hash.feed_field(item.as_bytes());
+        }
+        ConfirmPreview { affected, confirm_token: format!("{:016x}", hash.0) }
+    }
+
+    /// Fails unless `token` is this preview's confirmation token.
+    pub fn verify(&self, token: Option<&str>) -> Result<(), String> {
+        match token {
+            Some(token) if token == self.confirm_token => Ok(()),
+            Some(_) => Err("Confirmation token does not match the current preview; preview again".to_string()),
+            None => Err("A confirmation token from a preview is required".to_string()),
+        }
+    }
+}
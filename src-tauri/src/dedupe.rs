@@ -0,0 +1,202 @@
+//! Skill File Deduplication Module
+//!
+//! Large skills sometimes carry several byte-identical copies of the same asset.
+//! On Unix, duplicates inside one skill can be replaced with hardlinks to a single
+//! copy. Only regular files inside the skill are considered: symlinks are never
+//! followed, files that already have other hardlinks are skipped, and files whose
+//! permissions differ are kept separate so an executable bit is never lost.
+
+#[cfg(unix)]
+use crate::{ensure_not_safe_mode, find_global_skill, get_home_dir, global_skill_roots_with_home, Fnv1a};
+#[cfg(unix)]
+use std::collections::{BTreeMap, HashMap};
+#[cfg(unix)]
+use std::fs;
+#[cfg(unix)]
+use std::io::Read;
+#[cfg(unix)]
+use std::os::unix::fs::MetadataExt;
+#[cfg(unix)]
+use std::path::{Path, PathBuf};
+
+/// Outcome of deduplicating one skill
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct DedupeReport {
+    pub deduplicated: Vec<String>, // Files replaced by a hardlink, relative to the skill, sorted
+    pub bytes_saved: u64,
+}
+
+/// A regular file considered for deduplication
+#[cfg(unix)]
+struct Candidate {
+    relative: String,
+    path: PathBuf,
+    len: u64,
+    mode: u32,
+}
+
+/// Collects the regular, non-hidden files below `dir` that have no other hardlinks.
+#[cfg(unix)]
+fn collect_candidates(dir: &Path, prefix: &str, candidates: &mut Vec<Candidate>) -> Result<(), String> {
+    let entries = fs::read_dir(dir).map_err(|e| format!("Failed to read directory: {}", e))?;
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("Failed to read entry: {}", e))?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name.starts_with('.') {
+            continue;
+        }
+        let relative = format!("{}{}", prefix, name);
+        let metadata = fs::symlink_metadata(entry.path()).map_err(|e| format!("Failed to read metadata: {}", e))?;
+        if metadata.file_type().is_dir() {
+            collect_candidates(&entry.path(), &format!("{}/", relative), candidates)?;
+        } else if metadata.file_type().is_file() && metadata.nlink() == 1 && metadata.len() > 0 {
+            candidates.push(Candidate { relative, path: entry.path(), len: metadata.len(), mode: metadata.mode() });
+        }
+    }
+    Ok(())
+}
+
+/// Size of the chunks files are hashed and compared in
+#[cfg(unix)]
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Hashes a file chunk by chunk, so large assets are never held in memory at once.
+#[cfg(unix)]
+fn hash_file(path: &Path) -> Result<u64, String> {
+    let mut file = fs::File::open(path).map_err(|e| format!("Failed to open {}: {}", path.to_string_lossy(), e))?;
+    let mut hash = Fnv1a::default();
+    let mut buffer = vec![0u8; CHUNK_SIZE];
+    loop {
+        let read = file.read(&mut buffer).map_err(|e| format!("Failed to read {}: {}", path.to_string_lossy(), e))?;
+        if read == 0 {
+            return Ok(hash.0);
+        }
+        hash.feed(&buffer[..read]);
+    }
+}
+
+/// Whether two files of the same length hold the same bytes, compared chunk by chunk.
+#[cfg(unix)]
+fn files_equal(a: &Path, b: &Path, len: u64) -> Result<bool, String> {
+    let open = |path: &Path| fs::File::open(path).map_err(|e| format!("Failed to open {}: {}", path.to_string_lossy(), e));
+    let (mut file_a, mut file_b) = (open(a)?, open(b)?);
+    let (mut buffer_a, mut buffer_b) = (vec![0u8; CHUNK_SIZE], vec![0u8; CHUNK_SIZE]);
+    let mut remaining = len;
+    while remaining > 0 {
+        let chunk = remaining.min(CHUNK_SIZE as u64) as usize;
+        file_a.read_exact(&mut buffer_a[..chunk]).map_err(|e| format!("Failed to read {}: {}", a.to_string_lossy(), e))?;
+        file_b.read_exact(&mut buffer_b[..chunk]).map_err(|e| format!("Failed to read {}: {}", b.to_string_lossy(), e))?;
+        if buffer_a[..chunk] != buffer_b[..chunk] {
+            return Ok(false);
+        }
+        remaining -= chunk as u64;
+    }
+    Ok(true)
+}
+
+/// Replaces `duplicate` with a hardlink to `original`, via a temporary name so the
+/// duplicate is never missing.
+#[cfg(unix)]
+fn replace_with_hardlink(original: &Path, duplicate: &Path) -> Result<(), String> {
+    let file_name = duplicate.file_name().unwrap_or_default().to_string_lossy().to_string();
+    let temp = duplicate.with_file_name(format!(".{}.dedupe-tmp", file_name));
+    fs::hard_link(original, &temp).map_err(|e| format!("Failed to create hardlink: {}", e))?;
+    fs::rename(&temp, duplicate).map_err(|e| {
+        let _ = fs::remove_file(&temp);
+        format!("Failed to replace duplicate: {}", e)
+    })
+}
+
+/// Replaces byte-identical files within a global skill with hardlinks to the first copy
+/// in path order, reporting the replaced files and the bytes saved.
+#[cfg(unix)]
+pub fn dedupe_skill_files_with_home(skill_name: &str, home: &PathBuf) -> Result<DedupeReport, String> {
+    ensure_not_safe_mode(home)?;
+    let skill_dir = find_global_skill(&global_skill_roots_with_home(home), skill_name)
+        .ok_or_else(|| format!("Skill '{}' not found in global skills", skill_name))?;
+
+    let mut candidates = Vec::new();
+    collect_candidates(&skill_dir, "", &mut candidates)?;
+    candidates.sort_by(|a, b| a.relative.cmp(&b.relative));
+
+    // Only files with the same size and permissions can be merged, so a file with a
+    // unique size is never read. Groups keep path order.
+    let mut groups: BTreeMap<(u64, u32), Vec<&Candidate>> = BTreeMap::new();
+    for candidate in &candidates {
+        groups.entry((candidate.len, candidate.mode)).or_default().push(candidate);
+    }
+
+    let mut report = DedupeReport::default();
+    for group in groups.into_values().filter(|group| group.len() > 1) {
+        // Kept copies by content hash; a matching hash is confirmed byte by byte
+        let mut originals: HashMap<u64, Vec<&Candidate>> = HashMap::new();
+        for candidate in group {
+            let kept = originals.entry(hash_file(&candidate.path)?).or_default();
+            let mut original = None;
+            for copy in kept.iter() {
+                if files_equal(&copy.path, &candidate.path, candidate.len)? {
+                    original = Some(*copy);
+                    break;
+                }
+            }
+            match original {
+                Some(original) => {
+                    replace_with_hardlink(&original.path, &candidate.path)?;
+                    report.deduplicated.push(candidate.relative.clone());
+                    report.bytes_saved += candidate.len;
+                }
+                None => kept.push(candidate),
+            }
+        }
+    }
+    report.deduplicated.sort();
+
+    Ok(report)
+}
+
+#[tauri::command]
+pub fn dedupe_skill_files(skill_name: String) -> Result<DedupeReport, String> {
+    #[cfg(unix)]
+    {
+        let home = get_home_dir();
+        dedupe_skill_files_with_home(&skill_name, &home)
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = skill_name;
+        Err("Deduplicating skill files is only supported on Unix".to_string())
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    /// Test that two identical files become one hardlinked inode and the saved bytes are reported
+    #[test]
+    fn test_dedupe_skill_files_hardlinks_identical_files() {
+        // Arrange: Two identical assets, one different file, and a file already hardlinked elsewhere
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let skill_dir = home_path.join(".agents/skills/assets");
+        fs::create_dir_all(skill_dir.join("images")).expect("Failed to create skill directory");
+        fs::write(skill_dir.join("SKILL.md"), "---\nname: Assets\n---\n").expect("Failed to write SKILL.md");
+        fs::write(skill_dir.join("images/logo.png"), [7u8; 300]).expect("Failed to write asset");
+        fs::write(skill_dir.join("logo-copy.png"), [7u8; 300]).expect("Failed to write asset");
+        fs::write(skill_dir.join("other.png"), [8u8; 300]).expect("Failed to write asset");
+        fs::write(home_path.join("outside.png"), [7u8; 300]).expect("Failed to write outside file");
+        fs::hard_link(home_path.join("outside.png"), skill_dir.join("linked.png")).expect("Failed to create hardlink");
+
+        // Act
+        let report = dedupe_skill_files_with_home("assets", &home_path).expect("dedupe should succeed");
+
+        // Assert
+        assert_eq!(report, DedupeReport { deduplicated: vec!["logo-copy.png".to_string()], bytes_saved: 300 });
+        let inode = |path: PathBuf| fs::metadata(path).expect("Failed to read metadata").ino();
+        assert_eq!(inode(skill_dir.join("images/logo.png")), inode(skill_dir.join("logo-copy.png")));
+        assert_ne!(inode(skill_dir.join("other.png")), inode(skill_dir.join("logo-copy.png")));
+        assert_eq!(inode(skill_dir.join("linked.png")), inode(home_path.join("outside.png")));
+        assert_eq!(fs::read(skill_dir.join("logo-copy.png")).unwrap(), vec![7u8; 300]);
+    }
+}
@@ -0,0 +1,134 @@
+//! Error Module
+//!
+//! Most commands report failures as plain strings. Commands whose failures the
+//! frontend reacts to differently return a [`SkillError`] instead, serialized as
+//! `{ "kind": "agent_not_found", "message": "Agent 'x' not found" }` so the UI can
+//! branch on `kind` without matching message text. It converts into a `String` so
+//! string-returning callers can still use `?`.
+
+use crate::SAFE_MODE_ERROR;
+
+/// What was attempted on a local skill, so errors can say what to do instead
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LocalSkillAction {
+    Delete,
+    Upload,
+    Copy,
+}
+
+/// A typed command failure
+#[derive(Clone, Debug, PartialEq)]
+pub enum SkillError {
+    /// No agent with this ID
+    AgentNotFound(String),
+    /// No skill with this name where one was expected
+    SkillNotFound(String),
+    /// A global skill with this name already exists
+    AlreadyExists(String),
+    /// The agent already has a skill with this name
+    AlreadyExistsForAgent { skill_name: String, agent_id: String },
+    /// The skill name is empty, hidden or contains a path separator
+    InvalidName(String),
+    /// The skill path is a symlink where a local directory was expected
+    IsSymlink(String, LocalSkillAction),
+    /// The skill path is a local directory where a symlink was expected
+    NotASymlink(String),
+    /// The skill path exists but is not a directory
+    NotADirectory(String),
+    /// The link would point at its own ancestor or descendant
+    InvalidLink(String),
+    /// The path is not one of the configured global roots
+    RootNotFound(String),
+    /// Safe mode refuses every change to the filesystem
+    SafeMode,
+    /// A filesystem operation failed; the message says which
+    Io(String),
+}
+
+impl SkillError {
+    /// Stable snake_case name of the variant, sent to the frontend as `kind`
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::AgentNotFound(_) => "agent_not_found",
+            Self::SkillNotFound(_) => "skill_not_found",
+            Self::AlreadyExists(_) | Self::AlreadyExistsForAgent { .. } => "already_exists",
+            Self::InvalidName(_) => "invalid_name",
+            Self::IsSymlink(..) => "is_symlink",
+            Self::NotASymlink(_) => "not_a_symlink",
+            Self::NotADirectory(_) => "not_a_directory",
+            Self::InvalidLink(_) => "invalid_link",
+            Self::RootNotFound(_) => "root_not_found",
+            Self::SafeMode => "safe_mode",
+            Self::Io(_) => "io",
+        }
+    }
+}
+
+impl serde::Serialize for SkillError {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SkillError", 2)?;
+        state.serialize_field("kind", self.kind())?;
+        state.serialize_field("message", &self.to_string())?;
+        state.end()
+    }
+}
+
+impl std::fmt::Display for SkillError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AgentNotFound(agent_id) => write!(f, "Agent '{}' not found", agent_id),
+            Self::SkillNotFound(skill_name) => write!(f, "Skill '{}' not found", skill_name),
+            Self::AlreadyExists(skill_name) => write!(f, "Skill '{}' already exists in global skills", skill_name),
+            Self::AlreadyExistsForAgent { skill_name, agent_id } => {
+                write!(f, "Skill '{}' already exists for agent '{}'", skill_name, agent_id)
+            }
+            Self::InvalidName(skill_name) => write!(f, "Invalid skill name '{}'", skill_name),
+            Self::IsSymlink(skill_name, action) => match action {
+                LocalSkillAction::Delete => write!(f, "'{}' is a symlink, use unlink instead", skill_name),
+                LocalSkillAction::Upload => write!(f, "'{}' is a symlink, it is already in global skills", skill_name),
+                LocalSkillAction::Copy => write!(f, "'{}' is a symlink, link the global skill instead", skill_name),
+            },
+            Self::NotASymlink(skill_name) => write!(f, "'{}' is a local skill, not a symlink; delete it instead", skill_name),
+            Self::NotADirectory(skill_name) => write!(f, "'{}' is not a directory", skill_name),
+            Self::InvalidLink(message) | Self::Io(message) => write!(f, "{}", message),
+            Self::RootNotFound(root) => write!(f, "'{}' is not a configured global skills directory", root),
+            Self::SafeMode => write!(f, "{}", SAFE_MODE_ERROR),
+        }
+    }
+}
+
+impl std::error::Error for SkillError {}
+
+impl From<SkillError> for String {
+    fn from(error: SkillError) -> Self {
+        error.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test that an error serializes to its kind and its display message
+    #[test]
+    fn test_skill_error_serializes_kind_and_message() {
+        let error = SkillError::AgentNotFound("nope".to_string());
+
+        let json = serde_json::to_value(&error).expect("Failed to serialize error");
+
+        assert_eq!(json, serde_json::json!({ "kind": "agent_not_found", "message": "Agent 'nope' not found" }));
+        assert_eq!(String::from(SkillError::SafeMode), crate::SAFE_MODE_ERROR);
+    }
+
+    /// Test that a symlink error tells the user what to do for the attempted action
+    #[test]
+    fn test_is_symlink_message_depends_on_action() {
+        let delete = SkillError::IsSymlink("review".to_string(), LocalSkillAction::Delete);
+        let upload = SkillError::IsSymlink("review".to_string(), LocalSkillAction::Upload);
+
+        assert_eq!(delete.to_string(), "'review' is a symlink, use unlink instead");
+        assert_eq!(upload.to_string(), "'review' is a symlink, it is already in global skills");
+        assert_eq!(upload.kind(), "is_symlink");
+    }
+}
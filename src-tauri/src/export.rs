@@ -0,0 +1,319 @@
+//! Skill Export Module
+//!
+//! This module renders a global skill as a single self-describing markdown document
+//! that can be pasted into issues or docs. The document contains the SKILL.md,
+//! a manifest of every other file in the skill (names and sizes), and the contents
+//! of the other text files. Binary files are listed in the manifest but never inlined.
+//!
+//! It also exports the agent/skill link map as JSON or CSV for reporting, and a JSON
+//! metadata index of the whole library for external tooling.
+
+use crate::{
+    detect_agents_with_home, find_global_skill, get_agent_detail_with_home, get_app_data_with_home, get_home_dir,
+    global_skill_roots_with_home, list_skill_files, AgentSkillStatus, SkillMetadata,
+};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Returns the file contents as text, or `None` if the file looks binary.
+fn read_text(bytes: &[u8]) -> Option<&str> {
+    if bytes.contains(&0) {
+        return None;
+    }
+    std::str::from_utf8(bytes).ok()
+}
+
+/// Picks a code fence longer than any backtick run in the content.
+fn fence_for(content: &str) -> String {
+    let mut fence = "```".to_string();
+    while content.contains(&fence) {
+        fence.push('`');
+    }
+    fence
+}
+
+/// Exports a global skill as a markdown bundle.
+pub fn export_skill_markdown_with_home(skill_name: &str, home: &PathBuf) -> Result<String, String> {
+    let roots = global_skill_roots_with_home(home);
+    let skill_dir = find_global_skill(&roots, skill_name)
+        .ok_or_else(|| format!("Skill '{}' not found in global skills", skill_name))?;
+
+    let mut skill_md = String::new();
+    let mut manifest = Vec::new();
+    let mut inlined = Vec::new();
+
+    for (relative, path) in list_skill_files(&skill_dir)? {
+        let bytes = fs::read(&path).map_err(|e| format!("Failed to read {}: {}", relative, e))?;
+        if relative == "SKILL.md" {
+            skill_md = String::from_utf8_lossy(&bytes).to_string();
+            continue;
+        }
+        match read_text(&bytes) {
+            Some(text) => {
+                manifest.push(format!("| `{}` | {} bytes | text |", relative, bytes.len()));
+                inlined.push((relative, text.to_string()));
+            }
+            None => manifest.push(format!("| `{}` | {} bytes | binary (not inlined) |", relative, bytes.len())),
+        }
+    }
+
+    let mut output = format!("# Skill bundle: {}\n\n## SKILL.md\n\n", skill_name);
+    let fence = fence_for(&skill_md);
+    output.push_str(&format!("{}markdown\n{}", fence, skill_md));
+    if !skill_md.ends_with('\n') {
+        output.push('\n');
+    }
+    output.push_str(&format!("{}\n\n## Files\n\n", fence));
+
+    if manifest.is_empty() {
+        output.push_str("_No other files._\n");
+    } else {
+        output.push_str("| File | Size | Type |\n| --- | --- | --- |\n");
+        for line in &manifest {
+            output.push_str(line);
+            output.push('\n');
+        }
+    }
+
+    for (relative, text) in inlined {
+        let fence = fence_for(&text);
+        output.push_str(&format!("\n### {}\n\n{}\n{}", relative, fence, text));
+        if !text.ends_with('\n') {
+            output.push('\n');
+        }
+        output.push_str(&format!("{}\n", fence));
+    }
+
+    Ok(output)
+}
+
+#[tauri::command]
+pub fn export_skill_markdown(skill_name: String) -> Result<String, String> {
+    let home = get_home_dir();
+    export_skill_markdown_with_home(&skill_name, &home)
+}
+
+/// Output format for tabular exports
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    Json,
+    Csv,
+}
+
+/// One row of the link map: a skill's status for one agent
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct LinkMapRow {
+    pub agent_id: String,
+    pub skill_name: String,
+    pub status: AgentSkillStatus,
+}
+
+/// Quotes a CSV field if it contains a comma, quote, or newline.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Exports the status of every skill for every detected agent, ordered by agent
+/// definition order, then skill name.
+pub fn export_link_map_with_home(home: &PathBuf, format: ExportFormat) -> Result<String, String> {
+    let mut rows: Vec<LinkMapRow> = Vec::new();
+    for agent in detect_agents_with_home(home).into_iter().filter(|a| a.detected) {
+        let detail = get_agent_detail_with_home(&agent.id, home)?;
+        rows.extend(detail.skills.into_iter().map(|skill| LinkMapRow {
+            agent_id: agent.id.clone(),
+            skill_name: skill.name,
+            status: skill.status,
+        }));
+    }
+
+    match format {
+        ExportFormat::Json => {
+            serde_json::to_string_pretty(&rows).map_err(|e| format!("Failed to serialize link map: {}", e))
+        }
+        ExportFormat::Csv => {
+            let mut output = "agent_id,skill_name,status\n".to_string();
+            for row in &rows {
+                let status = match row.status {
+                    AgentSkillStatus::Symlink => "symlink",
+                    AgentSkillStatus::Local => "local",
+                    AgentSkillStatus::NotInstalled => "not_installed",
+                    AgentSkillStatus::BrokenSymlink => "broken_symlink",
+                };
+                output.push_str(&format!("{},{},{}\n", csv_field(&row.agent_id), csv_field(&row.skill_name), status));
+            }
+            Ok(output)
+        }
+    }
+}
+
+#[tauri::command]
+pub fn export_link_map(format: ExportFormat) -> Result<String, String> {
+    let home = get_home_dir();
+    export_link_map_with_home(&home, format)
+}
+
+/// One skill in the metadata index.
+///
+/// The index file is a JSON array of these objects sorted by `name`. This is an
+/// interchange format read by external tools: fields may be added, but existing
+/// fields keep their name and meaning.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct MetadataIndexEntry {
+    pub name: String,           // Skill directory name
+    pub metadata: SkillMetadata,
+    pub size: u64,              // Bytes across all non-hidden files
+    pub file_count: usize,      // Number of non-hidden files
+    pub tags: Vec<String>,      // Same as `metadata.tags`, for tools that only need tags
+}
+
+/// Writes the metadata index of every global skill to `dest_path` as JSON.
+pub fn export_metadata_index_with_home(home: &PathBuf, dest_path: &Path) -> Result<(), String> {
+    let mut entries: Vec<MetadataIndexEntry> = get_app_data_with_home(home)
+        .skills
+        .into_iter()
+        .map(|skill| {
+            let files = list_skill_files(&PathBuf::from(&skill.root).join(&skill.name)).unwrap_or_default();
+            MetadataIndexEntry {
+                size: files.iter().filter_map(|(_, path)| fs::metadata(path).ok()).map(|m| m.len()).sum(),
+                file_count: files.len(),
+                tags: skill.metadata.tags.clone(),
+                metadata: skill.metadata,
+                name: skill.name,
+            }
+        })
+        .collect();
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let content = serde_json::to_string_pretty(&entries).map_err(|e| format!("Failed to serialize index: {}", e))?;
+    fs::write(dest_path, content).map_err(|e| format!("Failed to write index: {}", e))
+}
+
+#[tauri::command]
+pub fn export_metadata_index(dest_path: String) -> Result<(), String> {
+    let home = get_home_dir();
+    export_metadata_index_with_home(&home, Path::new(&dest_path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    /// Test that the manifest lists every non-SKILL.md file and binaries are not inlined
+    #[test]
+    fn test_export_manifest_lists_all_other_files() {
+        // Arrange: A skill with a nested text file and a binary file
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let skill_dir = home_path.join(".agents/skills/my-skill");
+        fs::create_dir_all(skill_dir.join("scripts")).expect("Failed to create skill directory");
+        fs::write(skill_dir.join("SKILL.md"), "---\nname: My Skill\ndescription: Does things\n---\n")
+            .expect("Failed to write SKILL.md");
+        fs::write(skill_dir.join("scripts/run.sh"), "echo hi\n").expect("Failed to write script");
+        fs::write(skill_dir.join("logo.png"), [0x89, b'P', b'N', b'G', 0x00, 0x01]).expect("Failed to write binary");
+
+        // Act
+        let bundle = export_skill_markdown_with_home("my-skill", &home_path).expect("export should succeed");
+
+        // Assert
+        assert!(bundle.contains("name: My Skill"), "SKILL.md should be included");
+        assert!(bundle.contains("| `logo.png` | 6 bytes | binary (not inlined) |"));
+        assert!(bundle.contains("| `scripts/run.sh` | 8 bytes | text |"));
+        assert!(!bundle.contains("| `SKILL.md`"), "SKILL.md should not be in the manifest");
+        assert!(bundle.contains("### scripts/run.sh\n\n```\necho hi\n```\n"), "Text files should be inlined");
+        assert!(!bundle.contains("### logo.png"), "Binary files should not be inlined");
+    }
+
+    /// Test that both link map formats contain a known link, and CSV quotes fields with commas
+    #[test]
+    fn test_export_link_map_formats() {
+        // Arrange: A global skill linked into cursor, plus a local skill whose name contains a comma
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let skill_dir = home_path.join(".agents/skills/my-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        let cursor_dir = home_path.join(".cursor/skills");
+        fs::create_dir_all(cursor_dir.join("a,b")).expect("Failed to create local skill");
+        crate::create_symlink(&skill_dir, cursor_dir.join("my-skill")).expect("Failed to create symlink");
+
+        // Act
+        let json = export_link_map_with_home(&home_path, ExportFormat::Json).expect("JSON export should succeed");
+        let csv = export_link_map_with_home(&home_path, ExportFormat::Csv).expect("CSV export should succeed");
+
+        // Assert
+        let rows: Vec<LinkMapRow> = serde_json::from_str(&json).expect("JSON should parse");
+        assert!(rows.contains(&LinkMapRow {
+            agent_id: "cursor".to_string(),
+            skill_name: "my-skill".to_string(),
+            status: AgentSkillStatus::Symlink,
+        }));
+        assert!(csv.starts_with("agent_id,skill_name,status\n"));
+        assert!(csv.contains("cursor,my-skill,symlink\n"), "CSV missing link row: {}", csv);
+        assert!(csv.contains("cursor,\"a,b\",local\n"), "CSV should quote commas: {}", csv);
+    }
+
+    /// Test that the metadata index contains a known skill's parsed fields, sorted by name
+    #[test]
+    fn test_export_metadata_index_contains_parsed_fields() {
+        // Arrange
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        fs::create_dir_all(global_dir.join("zeta")).expect("Failed to create skill directory");
+        fs::create_dir_all(global_dir.join("review")).expect("Failed to create skill directory");
+        let skill_md = "---\nname: Review\ndescription: Reviews code\nallowed-tools:\n  - Read\ntags:\n  - git\n---\n";
+        fs::write(global_dir.join("review/SKILL.md"), skill_md).expect("Failed to write SKILL.md");
+        fs::write(global_dir.join("review/notes.txt"), "12345").expect("Failed to write notes");
+        let dest = home_path.join("index.json");
+
+        // Act
+        export_metadata_index_with_home(&home_path, &dest).expect("export should succeed");
+
+        // Assert
+        let index: Vec<MetadataIndexEntry> =
+            serde_json::from_str(&fs::read_to_string(&dest).expect("Failed to read index")).expect("Index should parse");
+        assert_eq!(index.iter().map(|e| e.name.as_str()).collect::<Vec<_>>(), vec!["review", "zeta"]);
+        let review = &index[0];
+        assert_eq!(review.metadata.name, "Review");
+        assert_eq!(review.metadata.description, "Reviews code");
+        assert_eq!(review.metadata.allowed_tools, vec!["Read"]);
+        assert_eq!(review.tags, vec!["git"]);
+        assert_eq!(review.file_count, 2);
+        assert_eq!(review.size, skill_md.len() as u64 + 5);
+    }
+
+    /// Test that a symlink loop inside a skill is not followed
+    #[cfg(unix)]
+    #[test]
+    fn test_export_does_not_follow_directory_symlinks() {
+        // Arrange: A skill containing a link back to itself
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let skill_dir = home_path.join(".agents/skills/looped");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        fs::write(skill_dir.join("SKILL.md"), "---\nname: Looped\n---\n").expect("Failed to write SKILL.md");
+        fs::write(skill_dir.join("notes.txt"), "hi\n").expect("Failed to write notes");
+        crate::create_symlink(&skill_dir, skill_dir.join("self")).expect("Failed to create symlink");
+
+        // Act
+        let bundle = export_skill_markdown_with_home("looped", &home_path).expect("export should succeed");
+
+        // Assert
+        assert!(bundle.contains("| `notes.txt` | 3 bytes | text |"));
+        assert!(!bundle.contains("self/"), "The linked directory should not be walked");
+    }
+
+    /// Test that exporting a missing skill fails
+    #[test]
+    fn test_export_missing_skill() {
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+
+        assert!(export_skill_markdown_with_home("nope", &home_path).is_err());
+    }
+}
@@ -0,0 +1,229 @@
+//! Git Import Module
+//!
+//! This module imports a single skill from a git repository. The repository is
+//! shallow-cloned with the system `git` into a temporary directory, and the chosen
+//! subdirectory (or the repository root) is copied into the highest-priority global
+//! root. The clone is always removed afterwards; the `.git` directory is never copied.
+
+use crate::{
+    copy_dir_recursive, ensure_not_safe_mode, find_global_skill, get_home_dir, global_skill_roots_with_home,
+};
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Creates an empty, uniquely named directory for a temporary clone.
+fn temp_clone_dir() -> Result<PathBuf, String> {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    let dir = std::env::temp_dir().join(format!("skills-manager-clone-{}-{}", std::process::id(), nanos));
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create temporary directory: {}", e))?;
+    Ok(dir)
+}
+
+/// Fails if `dir` contains a symlink anywhere below it, without following any.
+/// A symlink in an untrusted repository could otherwise pull files from outside it.
+fn reject_symlinks(dir: &std::path::Path) -> Result<(), String> {
+    let entries = fs::read_dir(dir).map_err(|e| format!("Failed to read directory: {}", e))?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let metadata = fs::symlink_metadata(&path).map_err(|e| format!("Failed to read entry: {}", e))?;
+        if metadata.file_type().is_symlink() {
+            let relative = path.strip_prefix(dir).unwrap_or(&path).to_string_lossy().to_string();
+            return Err(format!("Repository contains a symlink ('{}'); refusing to import", relative));
+        }
+        if metadata.is_dir() && entry.file_name() != ".git" {
+            reject_symlinks(&path)?;
+        }
+    }
+    Ok(())
+}
+
+/// Copies the skill out of a finished clone into `skill_dir`.
+fn copy_from_clone(clone_dir: &PathBuf, subdir: Option<&str>, skill_dir: &PathBuf) -> Result<(), String> {
+    let sub = subdir.map(|s| s.trim_matches('/')).filter(|s| !s.is_empty());
+    let not_found = || format!("Subdirectory '{}' not found in repository", subdir.unwrap_or_default());
+    let clone_root = fs::canonicalize(clone_dir).map_err(|e| format!("Failed to resolve clone directory: {}", e))?;
+    // Resolved, so a symlinked component cannot lead out of the clone
+    let source = fs::canonicalize(clone_root.join(sub.unwrap_or_default())).map_err(|_| not_found())?;
+    if !source.starts_with(&clone_root) {
+        return Err(format!("Invalid subdirectory '{}'", sub.unwrap_or_default()));
+    }
+    if !source.is_dir() {
+        return Err(not_found());
+    }
+    if !source.join("SKILL.md").is_file() {
+        return Err("No SKILL.md found in the imported directory".to_string());
+    }
+    reject_symlinks(&source)?;
+
+    copy_dir_recursive(&source, skill_dir)?;
+    let git_dir = skill_dir.join(".git");
+    if git_dir.exists() {
+        fs::remove_dir_all(&git_dir).map_err(|e| format!("Failed to remove .git directory: {}", e))?;
+    }
+    Ok(())
+}
+
+/// Imports a skill from a git repository into the global library as `dest_name`.
+/// Refuses if a global skill named `dest_name` already exists.
+pub fn import_skill_from_git_with_home(
+    repo_url: &str,
+    subdir: Option<String>,
+    dest_name: String,
+    home: &PathBuf,
+) -> Result<(), String> {
+    ensure_not_safe_mode(home)?;
+    // A leading dash would be read by git as an option, e.g. `--upload-pack=...`
+    if repo_url.trim().is_empty() || repo_url.starts_with('-') {
+        return Err(format!("Invalid repository URL '{}'", repo_url));
+    }
+    if dest_name.is_empty() || dest_name.starts_with('.') || dest_name.contains(['/', '\\']) {
+        return Err(format!("Invalid skill name '{}'", dest_name));
+    }
+    let roots = global_skill_roots_with_home(home);
+    if find_global_skill(&roots, &dest_name).is_some() {
+        return Err(format!("Skill '{}' already exists in global skills", dest_name));
+    }
+
+    let clone_dir = temp_clone_dir()?;
+    let output = Command::new("git")
+        .args(["clone", "--depth", "1", "--quiet", "--", repo_url])
+        .arg(&clone_dir)
+        .output();
+    let result = match output {
+        Ok(output) if output.status.success() => {
+            // New skills are imported into the highest-priority root
+            let skill_dir = roots[0].join(&dest_name);
+            let copied = copy_from_clone(&clone_dir, subdir.as_deref(), &skill_dir);
+            if copied.is_err() && skill_dir.exists() {
+                let _ = fs::remove_dir_all(&skill_dir);
+            }
+            copied
+        }
+        Ok(output) => Err(format!("git clone failed: {}", String::from_utf8_lossy(&output.stderr).trim())),
+        Err(e) => Err(format!("Failed to run git: {}", e)),
+    };
+    let _ = fs::remove_dir_all(&clone_dir);
+
+    result
+}
+
+#[tauri::command]
+pub fn import_skill_from_git(repo_url: String, subdir: Option<String>, dest_name: String) -> Result<(), String> {
+    let home = get_home_dir();
+    import_skill_from_git_with_home(&repo_url, subdir, dest_name, &home)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    /// Runs git in `dir`, panicking on failure
+    fn git(dir: &std::path::Path, args: &[&str]) {
+        let status = Command::new("git")
+            .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .expect("Failed to run git");
+        assert!(status.status.success(), "git {:?} failed: {}", args, String::from_utf8_lossy(&status.stderr));
+    }
+
+    /// Creates a bare repository holding a skill in `skills/my-skill`, returning its file:// URL
+    fn create_bare_repo(root: &std::path::Path) -> String {
+        let work = root.join("work");
+        fs::create_dir_all(work.join("skills/my-skill")).expect("Failed to create repo directory");
+        fs::write(work.join("skills/my-skill/SKILL.md"), "---\nname: My Skill\n---\n").expect("Failed to write SKILL.md");
+        fs::write(work.join("README.md"), "repo readme").expect("Failed to write README");
+        git(&work, &["init", "--quiet"]);
+        git(&work, &["add", "-A"]);
+        git(&work, &["commit", "--quiet", "-m", "Add skill"]);
+        git(root, &["clone", "--bare", "--quiet", "work", "repo.git"]);
+        format!("file://{}", root.join("repo.git").to_string_lossy())
+    }
+
+    /// Test that a subdirectory of a git repository is imported as a global skill
+    #[test]
+    fn test_import_skill_from_git_subdirectory() {
+        // Arrange
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let repo_root = TempDir::new().expect("Failed to create temp directory");
+        let url = create_bare_repo(repo_root.path());
+
+        // Act
+        import_skill_from_git_with_home(&url, Some("skills/my-skill".to_string()), "imported".to_string(), &home_path)
+            .expect("import should succeed");
+
+        // Assert
+        let skill_dir = home_path.join(".agents/skills/imported");
+        assert!(skill_dir.join("SKILL.md").exists(), "SKILL.md should be imported");
+        assert!(!skill_dir.join("README.md").exists(), "Only the subdirectory should be imported");
+
+        // Assert: Importing again under the same name is refused
+        let error = import_skill_from_git_with_home(&url, Some("skills/my-skill".to_string()), "imported".to_string(), &home_path)
+            .expect_err("existing destination should error");
+        assert!(error.contains("already exists"), "Unexpected error: {}", error);
+    }
+
+    /// Test that importing a directory without a SKILL.md fails and leaves nothing behind
+    #[test]
+    fn test_import_skill_from_git_requires_skill_md() {
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let repo_root = TempDir::new().expect("Failed to create temp directory");
+        let url = create_bare_repo(repo_root.path());
+
+        // The repository root has no SKILL.md
+        let error = import_skill_from_git_with_home(&url, None, "imported".to_string(), &home_path)
+            .expect_err("missing SKILL.md should error");
+
+        assert!(error.contains("No SKILL.md"), "Unexpected error: {}", error);
+        assert!(!home_path.join(".agents/skills/imported").exists());
+    }
+
+    /// Test that a URL git would read as an option is refused before running git
+    #[test]
+    fn test_import_skill_from_git_rejects_option_url() {
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+
+        let error = import_skill_from_git_with_home("--upload-pack=touch /tmp/pwned", None, "imported".to_string(), &home_path)
+            .expect_err("option-like URL should error");
+
+        assert!(error.contains("Invalid repository URL"), "Unexpected error: {}", error);
+    }
+
+    /// Test that a skill holding a symlink is refused, so files outside the clone are never copied
+    #[cfg(unix)]
+    #[test]
+    fn test_import_skill_from_git_refuses_symlinks() {
+        // Arrange: A repository whose skill links to a secret outside the repository
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let repo_root = TempDir::new().expect("Failed to create temp directory");
+        let secret_dir = repo_root.path().join("secret");
+        fs::create_dir_all(&secret_dir).expect("Failed to create secret directory");
+        fs::write(secret_dir.join("id_rsa"), "private key").expect("Failed to write secret");
+        let work = repo_root.path().join("work");
+        fs::create_dir_all(work.join("skill")).expect("Failed to create repo directory");
+        fs::write(work.join("skill/SKILL.md"), "---\nname: Sneaky\n---\n").expect("Failed to write SKILL.md");
+        std::os::unix::fs::symlink(&secret_dir, work.join("skill/keys")).expect("Failed to create symlink");
+        git(&work, &["init", "--quiet"]);
+        git(&work, &["add", "-A"]);
+        git(&work, &["commit", "--quiet", "-m", "Add skill"]);
+        let url = format!("file://{}", work.to_string_lossy());
+
+        // Act
+        let error = import_skill_from_git_with_home(&url, Some("skill".to_string()), "imported".to_string(), &home_path)
+            .expect_err("symlink should be refused");
+
+        // Assert
+        assert!(error.contains("symlink"), "Unexpected error: {}", error);
+        assert!(!home_path.join(".agents/skills/imported").exists());
+    }
+}
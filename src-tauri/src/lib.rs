@@ -2,19 +2,41 @@ use std::env;
 use std::fs;
 use std::path::PathBuf;
 
-#[cfg(unix)]
+/// Creates a link to a skill or skill file through [`link::create_skill_link`].
 fn create_symlink<P: AsRef<std::path::Path>, Q: AsRef<std::path::Path>>(original: P, link: Q) -> std::io::Result<()> {
-    std::os::unix::fs::symlink(original, link)
-}
-
-#[cfg(windows)]
-fn create_symlink<P: AsRef<std::path::Path>, Q: AsRef<std::path::Path>>(original: P, link: Q) -> std::io::Result<()> {
-    std::os::windows::fs::symlink_dir(original, link)
+    link::create_skill_link(original.as_ref(), link.as_ref())
 }
 
+pub mod agent_version;
+pub mod audit;
+pub mod backup;
+pub mod config;
+pub mod confirm;
+pub mod dedupe;
+pub mod error;
+pub mod export;
+pub mod git_import;
+pub mod link;
+pub mod link_manifest;
+pub mod link_meta;
+pub mod manifest;
+pub mod permissions;
+pub mod quarantine;
+pub mod recommended;
+pub mod repair;
+pub mod skill_files;
 pub mod skill_parser;
+pub mod snapshot;
+pub mod sync;
+pub mod templates;
+pub mod watcher;
 
-pub use skill_parser::{parse_skill_md, SkillMetadata};
+pub use error::{LocalSkillAction, SkillError};
+pub use config::{global_skill_roots_with_home, AppConfig};
+pub use skill_parser::{
+    decode_skill_md, parse_skill_md, parse_skill_md_with_source, update_frontmatter, ParseSource,
+    PartialSkillMetadata, SkillMdEncoding, SkillMetadata, ValidationWarning,
+};
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct Agent {
@@ -22,6 +44,14 @@ pub struct Agent {
     pub name: String,
     pub path: String, // Relative to home, e.g., ".cursor/skills"
     pub detected: bool,
+    #[serde(default)]
+    pub discovered: bool, // Found by directory discovery rather than the known agent list
+    #[serde(default)]
+    pub link_layout: LinkLayout, // How global skills are linked into this agent's directory
+    #[serde(default)]
+    pub nested_scan: bool, // Also list skills inside plugin subdirectories, as `<plugin>/<skill>`
+    #[serde(default)]
+    pub version: Option<String>, // Installed agent version, when it can be detected
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
@@ -30,6 +60,32 @@ pub struct Skill {
     pub metadata: SkillMetadata,
     pub linked_agents: Vec<String>, // List of agent IDs with this skill installed (symlink OR local)
     pub symlinked_agents: Vec<String>, // List of agent IDs with this skill linked via symlink only
+    #[serde(default)]
+    pub symlink_targets: Vec<(String, String)>, // (agent ID, symlink target) for each symlinked agent whose link could be read
+    #[serde(default)]
+    pub root: String,               // Global root the skill was found in
+    #[serde(default)]
+    pub shadowed_roots: Vec<String>, // Lower-priority roots that also contain a skill with this name
+    #[serde(default)]
+    pub disabled: bool,             // Marked not ready; batch link operations skip it
+}
+
+/// Shared frontmatter defaults at the top of a skills directory; fields a skill's SKILL.md
+/// leaves unset are filled from it when the skill is loaded. Skill files are never rewritten.
+pub const DEFAULTS_FILE: &str = ".defaults.yaml";
+
+/// Reads the [`DEFAULTS_FILE`] of a skills directory, if it exists and is valid YAML.
+fn read_skill_defaults(skills_dir: &std::path::Path) -> Option<SkillMetadata> {
+    let content = fs::read_to_string(skills_dir.join(DEFAULTS_FILE)).ok()?;
+    skill_parser::parse_defaults(&content)
+}
+
+/// Marker file inside a global skill that keeps batch link operations from linking it
+pub const DISABLED_MARKER: &str = ".disabled";
+
+/// Whether a global skill carries the [`DISABLED_MARKER`].
+pub(crate) fn is_skill_disabled(skill_dir: &std::path::Path) -> bool {
+    skill_dir.join(DISABLED_MARKER).exists()
 }
 
 /// Status of a skill in an agent's directory
@@ -42,6 +98,8 @@ pub enum AgentSkillStatus {
     Local,
     /// Skill is not installed
     NotInstalled,
+    /// Skill is a symlink whose target no longer exists
+    BrokenSymlink,
 }
 
 /// Represents a skill as seen from an agent's perspective
@@ -57,6 +115,24 @@ pub struct AgentSkill {
     pub source_path: Option<String>,
     /// Whether this skill exists in global skills directory
     pub in_global: bool,
+    /// Whether the displayed metadata comes from an agent-specific `<skill>.override/SKILL.md`
+    #[serde(default)]
+    pub has_override: bool,
+}
+
+/// The effective skill an agent uses, after following symlinks
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct ResolvedSkill {
+    /// Status: symlink or local
+    pub status: AgentSkillStatus,
+    /// Raw symlink target as stored in the link, None for local skills
+    pub link_target: Option<String>,
+    /// Canonical path of the skill directory the agent actually reads
+    pub resolved_path: String,
+    /// Whether the resolved path lies inside one of the global skill roots
+    pub in_global_library: bool,
+    /// Metadata loaded from the resolved directory
+    pub metadata: SkillMetadata,
 }
 
 /// Data for agent detail page
@@ -64,6 +140,9 @@ pub struct AgentSkill {
 pub struct AgentDetailData {
     pub agent: Agent,
     pub skills: Vec<AgentSkill>,
+    /// Whether any global skills directory exists; when none does, nothing can be `in_global`
+    #[serde(default)]
+    pub global_dir_exists: bool,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
@@ -72,10 +151,115 @@ pub struct AppData {
     pub skills: Vec<Skill>,
 }
 
+/// A global skill with only what a list view needs; details are fetched lazily
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct CompactSkill {
+    pub name: String,           // Directory name
+    pub summary: String,        // First line of the description, at most `SUMMARY_MAX_CHARS` characters
+    pub installed_count: usize, // Number of agents with the skill installed (symlink OR local)
+}
+
+/// App data with compact skills, for large libraries
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct CompactAppData {
+    pub agents: Vec<Agent>,
+    pub skills: Vec<CompactSkill>,
+}
+
+/// Longest summary in a compact skill, in characters
+pub const SUMMARY_MAX_CHARS: usize = 120;
+
+/// Number of skills installed (symlink or local) in a single agent
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct AgentSkillCount {
+    pub agent_id: String,
+    pub count: usize,
+}
+
+/// Everything the main view needs, bundled into a single payload
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct Dashboard {
+    pub app_data: AppData,
+    pub detected_agent_count: usize,
+    pub total_agent_count: usize,
+    pub agent_skill_counts: Vec<AgentSkillCount>, // Detected agents only
+    pub unused_skills: Vec<String>,               // Global skills not installed in any agent
+    pub broken_link_count: usize,
+    #[serde(default)]
+    pub config_warnings: Vec<String>,            // Problems found in the config file
+}
+
+/// Parsing outcome and field completeness of a single global skill
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct SkillQuality {
+    pub name: String,                       // Directory name
+    pub parse_source: Option<ParseSource>,  // None when there is no readable SKILL.md
+    pub has_name: bool,
+    pub has_description: bool,
+    pub has_allowed_tools: bool,
+    pub has_tags: bool,
+}
+
+/// Aggregate statistics over the whole global library
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct LibraryStats {
+    pub total_skills: usize,
+    pub total_size: u64,          // Bytes across all non-hidden files
+    pub total_files: usize,
+    pub missing_skill_md: usize,  // Skills without a SKILL.md
+    pub distinct_tools: usize,    // Distinct allowed-tools entries across skills
+    pub distinct_tags: usize,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct ToolIssue {
+    pub tool: String,               // Tool listed in the skill but missing from the registry
+    pub suggestion: Option<String>, // Closest registry tool, if the name looks like a typo
+    pub fixed: bool,                // Whether SKILL.md was rewritten to use the suggestion
+}
+
+/// How a skill is installed in an agent's skills directory
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LinkStyle {
+    Symlink, // Symlink to the global skill
+    Copy,    // Local copy of the global skill
+}
+
+/// How a global skill is linked into an agent's skills directory
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LinkLayout {
+    /// `<agent>/<skill>` is a symlink to the skill directory
+    #[default]
+    Directory,
+    /// `<agent>/<skill>` is a real directory holding a symlink to each entry of the skill
+    Contents,
+    /// `<agent>/<skill>` is a real directory holding only a symlink to the skill's SKILL.md
+    SkillMdOnly,
+}
+
+/// Line-ending style of a text file
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LineEnding {
+    /// `\n`
+    #[default]
+    Lf,
+    /// `\r\n`
+    Crlf,
+}
+
+/// Result of a batch operation. Batches over agents report agent IDs; batches over
+/// the skills of a single agent report skill names in the same fields.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default)]
 pub struct BatchResult {
     pub success: Vec<String>,     // Agent IDs that succeeded
     pub failed: Vec<FailedOperation>,
+    #[serde(default)]
+    pub skipped: Vec<FailedOperation>, // Agents intentionally left alone, with the reason
+    #[serde(default)]
+    pub skipped_disabled: Vec<FailedOperation>, // Agents left alone because the skill is disabled
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
@@ -88,8 +272,102 @@ fn get_home_dir() -> PathBuf {
     PathBuf::from(env::var("HOME").unwrap_or_else(|_| "/".to_string()))
 }
 
-fn get_global_skills_path() -> PathBuf {
-    get_home_dir().join(".agents/skills")
+/// Checks that a skill name is a single plain directory name: not empty, not hidden,
+/// and without path separators, so joining it to a directory cannot leave that directory.
+pub fn validate_skill_name(name: &str) -> Result<(), String> {
+    if name.is_empty() || name.starts_with('.') || name.contains(['/', '\\', '\0']) {
+        return Err(format!("Invalid skill name '{}'", name));
+    }
+    Ok(())
+}
+
+/// Returns the path of a skill in the first global root that contains it, matching
+/// names by their NFC form.
+pub fn find_global_skill(roots: &[PathBuf], skill_name: &str) -> Option<PathBuf> {
+    roots
+        .iter()
+        .map(|root| entry_matching_name(root, skill_name))
+        .find(|path| path.exists())
+}
+
+/// The entry of `dir` named `name`, or failing that the entry whose name has the same
+/// NFC form. Returns `dir.join(name)` when there is neither.
+fn entry_matching_name(dir: &std::path::Path, name: &str) -> PathBuf {
+    let exact = dir.join(name);
+    if fs::symlink_metadata(&exact).is_ok() {
+        return exact;
+    }
+    let normalized = normalized_skill_name(name);
+    fs::read_dir(dir)
+        .ok()
+        .and_then(|entries| {
+            entries
+                .flatten()
+                .find(|entry| normalized_skill_name(&entry.file_name().to_string_lossy()) == normalized)
+        })
+        .map(|entry| entry.path())
+        .unwrap_or(exact)
+}
+
+/// Normalizes a skill name to NFC, so names that differ only in unicode composition
+/// (macOS often stores decomposed NFD names) compare equal.
+fn normalized_skill_name(name: &str) -> String {
+    use unicode_normalization::UnicodeNormalization;
+    name.nfc().collect()
+}
+
+/// Collects global skill directory names across all roots (excluding hidden directories).
+fn global_skill_names(roots: &[PathBuf]) -> std::collections::HashSet<String> {
+    let mut names = std::collections::HashSet::new();
+    for root in roots {
+        if let Ok(entries) = fs::read_dir(root) {
+            for entry in entries.flatten() {
+                if let Ok(ft) = entry.file_type() {
+                    if ft.is_dir() {
+                        let name = entry.file_name().to_string_lossy().to_string();
+                        if !name.starts_with('.') {
+                            names.insert(name);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    names
+}
+
+/// Whether a plain directory is a plugin holding skills rather than a skill itself:
+/// it has no SKILL.md, but at least one of its subdirectories does.
+fn is_plugin_dir(path: &std::path::Path) -> bool {
+    let is_plain_dir = fs::symlink_metadata(path).map(|m| m.file_type().is_dir()).unwrap_or(false);
+    is_plain_dir
+        && !path.join("SKILL.md").exists()
+        && fs::read_dir(path)
+            .map(|entries| entries.flatten().any(|entry| entry.path().join("SKILL.md").is_file()))
+            .unwrap_or(false)
+}
+
+/// Lists the entries of an agent's skills directory as (name, path). With `nested`,
+/// plugin directories are descended into and their skills listed as `<plugin>/<skill>`.
+fn agent_skill_entries(agent_dir: &std::path::Path, nested: bool) -> Vec<(String, PathBuf)> {
+    let mut found = Vec::new();
+    let Ok(entries) = fs::read_dir(agent_dir) else {
+        return found;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if nested && !name.starts_with('.') && is_plugin_dir(&entry.path()) {
+            for child in fs::read_dir(entry.path()).into_iter().flatten().flatten() {
+                let child_name = child.file_name().to_string_lossy().to_string();
+                if !child_name.starts_with('.') {
+                    found.push((format!("{}/{}", name, child_name), child.path()));
+                }
+            }
+        } else {
+            found.push((name, entry.path()));
+        }
+    }
+    found
 }
 
 /// Returns the list of agent definitions (id, name, relative_path)
@@ -132,23 +410,234 @@ pub fn get_agent_definition_list() -> Vec<(&'static str, &'static str, &'static
 /// - 3.2: WHEN detecting agents, THE Skills_Manager SHALL check if each agent's skills directory exists
 /// - 3.3: WHEN an agent's skills directory does not exist, THE Skills_Manager SHALL mark the agent as not detected
 pub fn detect_agents_with_home(home: &PathBuf) -> Vec<Agent> {
+    let config = config::load_config_with_home(home);
+    let mut agents = detect_all_agents(home, &config);
+    // An allowlist hides every other agent from all operations, detected or not
+    if !config.enabled_agents.is_empty() {
+        agents.retain(|agent| config.enabled_agents.contains(&agent.id));
+    }
+    // The denylist hides agents permanently; they remain in the definition list
+    agents.retain(|agent| !config.hidden_agents.contains(&agent.id));
+    for agent in agents.iter_mut() {
+        if let Some(layout) = config.link_layouts.get(&agent.id) {
+            agent.link_layout = *layout;
+        }
+        agent.nested_scan = config.nested_scan_agents.contains(&agent.id);
+        if let Some(versioning) = config.agent_versions.get(&agent.id) {
+            agent_version::apply_agent_version(agent, home, versioning);
+        }
+    }
+    agents
+}
+
+/// Detects known agents, plus discovered ones if enabled, ignoring the allowlist.
+fn detect_all_agents(home: &PathBuf, config: &AppConfig) -> Vec<Agent> {
+    let mut agents = detect_known_agents_concurrent(home);
+    if config.discover_agents {
+        agents.extend(discover_agents_with_home(home));
+    }
+    agents
+}
+
+/// Returns warnings about the config, such as allowlisted agent IDs that match no agent.
+pub fn config_warnings_with_home(home: &PathBuf) -> Vec<String> {
+    let config = config::load_config_with_home(home);
+    if config.enabled_agents.is_empty() {
+        return Vec::new();
+    }
+    let agents = detect_all_agents(home, &config);
+    config
+        .enabled_agents
+        .iter()
+        .filter(|id| !agents.iter().any(|agent| &agent.id == *id))
+        .map(|id| format!("Unknown agent '{}' in enabled_agents was ignored", id))
+        .collect()
+}
+
+/// Number of threads used to check agent directories during detection
+const DETECTION_THREADS: usize = 8;
+
+/// Builds a known agent entry, checking whether its skills directory exists.
+fn detect_known_agent(home: &PathBuf, (id, name, rel_path): (&str, &str, &str)) -> Agent {
+    let full_path = home.join(rel_path);
+    Agent {
+        id: id.to_string(),
+        name: name.to_string(),
+        path: rel_path.to_string(),
+        detected: full_path.exists(),
+        discovered: false,
+        link_layout: LinkLayout::Directory,
+        nested_scan: false,
+        version: None,
+    }
+}
+
+/// Detects the known agents one at a time. Reference implementation for the concurrent version.
+#[cfg(test)]
+fn detect_known_agents_sequential(home: &PathBuf) -> Vec<Agent> {
     get_agent_definition_list()
         .into_iter()
-        .map(|(id, name, rel_path)| {
-            let full_path = home.join(rel_path);
+        .map(|definition| detect_known_agent(home, definition))
+        .collect()
+}
+
+/// Detects the known agents, spreading the existence checks over a few threads.
+/// Each thread handles a contiguous chunk of definitions, so output keeps definition order.
+fn detect_known_agents_concurrent(home: &PathBuf) -> Vec<Agent> {
+    let definitions = get_agent_definition_list();
+    let chunk_size = definitions.len().div_ceil(DETECTION_THREADS).max(1);
+
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = definitions
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|definition| detect_known_agent(home, *definition))
+                        .collect::<Vec<Agent>>()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("agent detection thread panicked"))
+            .collect()
+    })
+}
+
+/// Creates the skills directory for agents whose config directory exists but whose skills
+/// directory does not. Applies to the given agents, or all known agents when `None`.
+///
+/// Created directories are reported as successes. Agents whose skills directory already
+/// exists, or whose config directory is missing, are reported as skipped with the reason.
+pub fn ensure_agent_dirs_with_home(agent_ids: Option<Vec<String>>, home: &PathBuf) -> Result<BatchResult, String> {
+    ensure_not_safe_mode(home)?;
+    let agents = detect_agents_with_home(home);
+    let agent_ids = agent_ids.unwrap_or_else(|| agents.iter().map(|a| a.id.clone()).collect());
+
+    let mut success: Vec<String> = Vec::new();
+    let mut failed: Vec<FailedOperation> = Vec::new();
+    let mut skipped: Vec<FailedOperation> = Vec::new();
+
+    for agent_id in agent_ids {
+        let Some(agent) = agents.iter().find(|a| a.id == agent_id) else {
+            failed.push(FailedOperation { agent_id, error: "Agent not found".to_string() });
+            continue;
+        };
+        let skills_dir = home.join(&agent.path);
+        if skills_dir.is_dir() {
+            skipped.push(FailedOperation { agent_id, error: "Skills directory already exists".to_string() });
+            continue;
+        }
+        let config_dir = skills_dir.parent().map(|p| p.to_path_buf()).unwrap_or_else(|| home.clone());
+        if !config_dir.is_dir() {
+            skipped.push(FailedOperation {
+                agent_id,
+                error: format!("Config directory {} not found", config_dir.to_string_lossy()),
+            });
+            continue;
+        }
+        match fs::create_dir(&skills_dir) {
+            Ok(_) => success.push(agent_id),
+            Err(e) => failed.push(FailedOperation { agent_id, error: format!("Failed to create skills directory: {}", e) }),
+        }
+    }
+
+    Ok(BatchResult { success, failed, skipped, ..Default::default() })
+}
+
+#[tauri::command]
+fn ensure_agent_dirs(agent_ids: Option<Vec<String>>) -> Result<BatchResult, String> {
+    let home = get_home_dir();
+    ensure_agent_dirs_with_home(agent_ids, &home)
+}
+
+/// Derives an agent ID from a discovered skills path, e.g. ".config/mytool/skills" -> "config-mytool".
+fn discovered_agent_id(rel_path: &str) -> String {
+    rel_path
+        .trim_end_matches("/skills")
+        .split('/')
+        .map(|part| part.trim_start_matches('.'))
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Scans `~/*/skills` and `~/*/*/skills` for skills directories not covered by a known
+/// agent or a global skill root, and returns them as detected agents with `discovered` set.
+pub fn discover_agents_with_home(home: &PathBuf) -> Vec<Agent> {
+    let known_paths: Vec<&str> = get_agent_definition_list().into_iter().map(|(_, _, path)| path).collect();
+    let known_ids: Vec<&str> = get_agent_definition_list().into_iter().map(|(id, _, _)| id).collect();
+    let roots = global_skill_roots_with_home(home);
+
+    // Candidate parents: every directory one or two levels below home
+    let subdirs = |dir: &std::path::Path| -> Vec<String> {
+        fs::read_dir(dir)
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .filter(|entry| entry.file_type().map(|ft| ft.is_dir()).unwrap_or(false))
+                    .map(|entry| entry.file_name().to_string_lossy().to_string())
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    let mut candidates: Vec<String> = Vec::new();
+    for first in subdirs(home) {
+        for second in subdirs(&home.join(&first)) {
+            if second == "skills" {
+                candidates.push(format!("{}/skills", first));
+            } else if home.join(&first).join(&second).join("skills").is_dir() {
+                candidates.push(format!("{}/{}/skills", first, second));
+            }
+        }
+    }
+    candidates.sort();
+
+    candidates
+        .into_iter()
+        .filter(|rel_path| !known_paths.contains(&rel_path.as_str()))
+        .filter(|rel_path| !roots.contains(&home.join(rel_path)))
+        .map(|rel_path| {
+            let mut id = discovered_agent_id(&rel_path);
+            if known_ids.contains(&id.as_str()) {
+                id = format!("discovered-{}", id);
+            }
             Agent {
-                id: id.to_string(),
-                name: name.to_string(),
-                path: rel_path.to_string(),
-                detected: full_path.exists(),
+                name: id.clone(),
+                id,
+                path: rel_path,
+                detected: true,
+                discovered: true,
+                link_layout: LinkLayout::Directory,
+                nested_scan: false,
+                version: None,
             }
         })
         .collect()
 }
 
-fn get_agent_definitions() -> Vec<Agent> {
-    let home = get_home_dir();
-    detect_agents_with_home(&home)
+/// Error returned by every mutating operation while safe mode is on
+pub const SAFE_MODE_ERROR: &str = "safe mode: operation disabled";
+
+/// Fails if safe mode is on. Every operation that changes the filesystem calls this first.
+pub(crate) fn ensure_not_safe_mode(home: &PathBuf) -> Result<(), String> {
+    if config::safe_mode_enabled_with_home(home) {
+        return Err(SAFE_MODE_ERROR.to_string());
+    }
+    Ok(())
+}
+
+/// Reads a SKILL.md file, decoding UTF-16 and Latin-1 content as well as UTF-8.
+/// Returns the text and a warning if the file was not valid UTF-8.
+fn read_skill_md(path: &std::path::Path) -> std::io::Result<(String, Option<ValidationWarning>)> {
+    fs::read(path).map(|bytes| decode_skill_md(&bytes))
+}
+
+#[cfg(test)]
+thread_local! {
+    /// Number of metadata loads on this thread, so tests can observe manifest cache hits
+    static SKILL_MD_PARSES: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
 }
 
 /// Loads skill metadata from a skill directory.
@@ -157,13 +646,18 @@ fn get_agent_definitions() -> Vec<Agent> {
 /// - 2.1: WHEN reading a skill directory, THE Skills_Manager SHALL look for a SKILL.md file in the skill's root directory
 /// - 1.6: IF parsing SKILL.md fails, THEN THE Skills_Manager SHALL display the skill name from the directory name and show "No description available"
 pub fn load_skill_metadata(skill_dir: &std::path::Path, dir_name: &str) -> SkillMetadata {
+    #[cfg(test)]
+    SKILL_MD_PARSES.with(|count| count.set(count.get() + 1));
     let skill_md_path = skill_dir.join("SKILL.md");
     
     if skill_md_path.exists() {
         // Try to read and parse the SKILL.md file
-        match fs::read_to_string(&skill_md_path) {
-            Ok(content) => {
+        match read_skill_md(&skill_md_path) {
+            Ok((content, _warning)) => {
                 let mut parsed = parse_skill_md(&content);
+                if let Some(defaults) = skill_dir.parent().and_then(read_skill_defaults) {
+                    parsed.fill_missing(&defaults);
+                }
                 // If name is empty after parsing, use directory name as fallback
                 if parsed.name.is_empty() {
                     parsed.name = dir_name.to_string();
@@ -179,7 +673,7 @@ pub fn load_skill_metadata(skill_dir: &std::path::Path, dir_name: &str) -> Skill
                 SkillMetadata {
                     name: dir_name.to_string(),
                     description: "No description available".to_string(),
-                    allowed_tools: Vec::new(),
+                    ..Default::default()
                 }
             }
         }
@@ -188,7 +682,7 @@ pub fn load_skill_metadata(skill_dir: &std::path::Path, dir_name: &str) -> Skill
         SkillMetadata {
             name: dir_name.to_string(),
             description: "No description available".to_string(),
-            allowed_tools: Vec::new(),
+            ..Default::default()
         }
     }
 }
@@ -199,57 +693,143 @@ pub fn load_skill_metadata(skill_dir: &std::path::Path, dir_name: &str) -> Skill
 /// - 2.1: WHEN reading a skill directory, THE Skills_Manager SHALL look for a SKILL.md file in the skill's root directory
 /// - 1.6: IF parsing SKILL.md fails, THEN THE Skills_Manager SHALL display the skill name from the directory name and show "No description available"
 pub fn get_app_data_with_home(home: &PathBuf) -> AppData {
-    let agents = detect_agents_with_home(home);
-    let global_skills_path = home.join(".agents/skills");
+    get_app_data_with_roots(home, &global_skill_roots_with_home(home))
+}
 
-    let mut skills = Vec::new();
+/// Gets app data merging skills from several global roots.
+/// Roots are searched in order; when a name exists in more than one root the
+/// first root wins and the others are recorded in `shadowed_roots`.
+pub fn get_app_data_with_roots(home: &PathBuf, roots: &[PathBuf]) -> AppData {
+    scan_app_data_with_roots(home, roots, |_| {})
+}
 
-    if let Ok(entries) = fs::read_dir(&global_skills_path) {
-        for entry in entries.flatten() {
-            if let Ok(file_type) = entry.file_type() {
-                if file_type.is_dir() {
-                    let name = entry.file_name().to_string_lossy().to_string();
-                    
-                    // Skip hidden directories (starting with ".")
-                    if name.starts_with('.') {
-                        continue;
-                    }
-                    
-                    let mut linked_agents = Vec::new();
-                    let mut symlinked_agents = Vec::new();
+/// Progress of a scan, reported as each item becomes available
+#[derive(Clone, Copy, Debug)]
+pub enum ScanEvent<'a> {
+    AgentDetected(&'a Agent),
+    SkillLoaded(&'a Skill),
+    ScanComplete,
+}
+
+/// Gets app data like [`get_app_data_with_roots`], reporting every agent and skill to
+/// `on_event` as soon as it is complete, then `ScanComplete`.
+///
+/// Directory listings are read up front so each skill is reported with its final
+/// `shadowed_roots`; the slow part, checking agents and parsing SKILL.md, is incremental.
+pub fn scan_app_data_with_roots<F: FnMut(ScanEvent)>(home: &PathBuf, roots: &[PathBuf], mut on_event: F) -> AppData {
+    let agents = detect_agents_with_home(home);
+    for agent in &agents {
+        on_event(ScanEvent::AgentDetected(agent));
+    }
+
+    // Each detected agent's entry names by NFC form, so NFD spellings still match
+    let agent_entries: std::collections::HashMap<&str, std::collections::HashMap<String, String>> = agents
+        .iter()
+        .filter(|agent| agent.detected)
+        .map(|agent| {
+            let names = fs::read_dir(home.join(&agent.path))
+                .map(|entries| {
+                    entries
+                        .flatten()
+                        .map(|entry| entry.file_name().to_string_lossy().to_string())
+                        .map(|name| (normalized_skill_name(&name), name))
+                        .collect()
+                })
+                .unwrap_or_default();
+            (agent.id.as_str(), names)
+        })
+        .collect();
 
-                    // Check which agents have this skill installed (symlink OR local)
-                    for agent in &agents {
-                        if !agent.detected {
+    // Skill directories in root order, each with the lower-priority roots it shadows
+    let mut found: Vec<(String, PathBuf, String, Vec<String>)> = Vec::new();
+    for root in roots {
+        let root_str = root.to_string_lossy().to_string();
+        if let Ok(entries) = fs::read_dir(root) {
+            for entry in entries.flatten() {
+                if let Ok(file_type) = entry.file_type() {
+                    if file_type.is_dir() {
+                        let name = entry.file_name().to_string_lossy().to_string();
+                        
+                        // Skip hidden directories (starting with ".")
+                        if name.starts_with('.') {
                             continue;
                         }
-                        let agent_skill_path = home.join(&agent.path).join(&name);
-                        
-                        // Check if it exists as symlink OR local directory
-                        if let Ok(metadata) = fs::symlink_metadata(&agent_skill_path) {
-                            let file_type = metadata.file_type();
-                            if file_type.is_symlink() {
-                                linked_agents.push(agent.id.clone());
-                                symlinked_agents.push(agent.id.clone());
-                            } else if file_type.is_dir() {
-                                linked_agents.push(agent.id.clone());
-                            }
+
+                        // A higher-priority root already provided this skill
+                        let normalized_name = normalized_skill_name(&name);
+                        if let Some((_, _, _, shadowed_roots)) =
+                            found.iter_mut().find(|(n, _, _, _)| normalized_skill_name(n) == normalized_name)
+                        {
+                            shadowed_roots.push(root_str.clone());
+                            continue;
                         }
+                        found.push((name, entry.path(), root_str.clone(), Vec::new()));
                     }
+                }
+            }
+        }
+    }
 
-                    // Parse SKILL.md file for metadata
-                    let metadata = load_skill_metadata(&entry.path(), &name);
+    let mut skills: Vec<Skill> = Vec::new();
+    let mut manifests: std::collections::HashMap<String, Option<manifest::Manifest>> = std::collections::HashMap::new();
+    for (name, skill_dir, root_str, shadowed_roots) in found {
+        let mut linked_agents = Vec::new();
+        let mut symlinked_agents = Vec::new();
+        let mut symlink_targets = Vec::new();
 
-                    skills.push(Skill {
-                        name,
-                        metadata,
-                        linked_agents,
-                        symlinked_agents,
-                    });
+        // Check which agents have this skill installed (symlink OR local)
+        let normalized_name = normalized_skill_name(&name);
+        for agent in &agents {
+            let entry_name = agent_entries.get(agent.id.as_str()).and_then(|names| names.get(&normalized_name));
+            let Some(entry_name) = entry_name else {
+                continue;
+            };
+            let agent_skill_path = home.join(&agent.path).join(entry_name);
+            
+            // Check if it exists as symlink OR local directory
+            if let Ok(metadata) = fs::symlink_metadata(&agent_skill_path) {
+                let file_type = metadata.file_type();
+                if file_type.is_symlink() {
+                    linked_agents.push(agent.id.clone());
+                    symlinked_agents.push(agent.id.clone());
+                    if let Ok(target) = fs::read_link(&agent_skill_path) {
+                        // Relative targets are resolved against the real directory holding the
+                        // link, so `..` works when the skills directory is itself a symlink
+                        let target = match agent_skill_path.parent() {
+                            Some(parent) if target.is_relative() => {
+                                let parent = fs::canonicalize(parent).unwrap_or_else(|_| parent.to_path_buf());
+                                repair::normalize_lexically(&parent.join(target))
+                            }
+                            _ => target,
+                        };
+                        symlink_targets.push((agent.id.clone(), target.to_string_lossy().to_string()));
+                    }
+                } else if file_type.is_dir() {
+                    linked_agents.push(agent.id.clone());
                 }
             }
         }
+
+        // Parse SKILL.md file for metadata, unless the manifest has it cached
+        let manifest = manifests
+            .entry(root_str.clone())
+            .or_insert_with(|| manifest::read_manifest(std::path::Path::new(&root_str)));
+        let metadata = manifest::cached_skill_metadata(manifest.as_ref(), &skill_dir, &name);
+
+        let skill = Skill {
+            disabled: is_skill_disabled(&skill_dir),
+            name,
+            metadata,
+            linked_agents,
+            symlinked_agents,
+            symlink_targets,
+            root: root_str,
+            shadowed_roots,
+        };
+        on_event(ScanEvent::SkillLoaded(&skill));
+        skills.push(skill);
     }
+    on_event(ScanEvent::ScanComplete);
 
     AppData { agents, skills }
 }
@@ -260,1312 +840,5944 @@ fn get_app_data() -> AppData {
     get_app_data_with_home(&home)
 }
 
+/// Shortens a description to its first line, cut to `SUMMARY_MAX_CHARS` characters.
+fn summarize_description(description: &str) -> String {
+    let first_line = description.trim().lines().next().unwrap_or_default().trim();
+    if first_line.chars().count() <= SUMMARY_MAX_CHARS {
+        return first_line.to_string();
+    }
+    let cut: String = first_line.chars().take(SUMMARY_MAX_CHARS - 3).collect();
+    format!("{}...", cut.trim_end())
+}
+
+/// Gets app data with each skill reduced to its name, a summary and its installed count.
+pub fn get_app_data_compact_with_home(home: &PathBuf) -> CompactAppData {
+    let app_data = get_app_data_with_home(home);
+    CompactAppData {
+        agents: app_data.agents,
+        skills: app_data
+            .skills
+            .into_iter()
+            .map(|skill| CompactSkill {
+                summary: summarize_description(&skill.metadata.description),
+                installed_count: skill.linked_agents.len(),
+                name: skill.name,
+            })
+            .collect(),
+    }
+}
+
 #[tauri::command]
-fn toggle_skill(agent_id: String, skill_name: String, enable: bool) -> Result<(), String> {
-    let agents = get_agent_definitions();
-    let agent = agents.iter().find(|a| a.id == agent_id).ok_or("Agent not found")?;
-    
+fn get_app_data_compact() -> CompactAppData {
     let home = get_home_dir();
-    let global_skill_path = get_global_skills_path().join(&skill_name);
-    let agent_skill_path = home.join(&agent.path).join(&skill_name);
-
-    if enable {
-        if !global_skill_path.exists() {
-            return Err("Global skill does not exist".to_string());
-        }
-        
-        // Create parent dir if needed
-        if let Some(parent) = agent_skill_path.parent() {
-            let _ = fs::create_dir_all(parent);
-        }
+    get_app_data_compact_with_home(&home)
+}
 
-        // Create symlink
-        // Note: For VS Code extensions, specific structure might be needed, but sticking to direct link for now
-        create_symlink(&global_skill_path, &agent_skill_path)
-            .map_err(|e| format!("Failed to link: {}", e))?;
-    } else {
-        // Remove symlink
-        if agent_skill_path.exists() || fs::symlink_metadata(&agent_skill_path).is_ok() {
-             fs::remove_file(&agent_skill_path)
-                .map_err(|e| format!("Failed to unlink: {}", e))?;
-        }
-    }
+/// Scans like `get_app_data`, emitting `agent-detected` and `skill-loaded` events as
+/// items become available and `scan-complete` at the end, so the UI can render
+/// progressively on slow home directories. Returns the same data as `get_app_data`.
+/// Runs off the main thread so the window stays responsive while the scan walks.
+#[tauri::command(async)]
+fn get_app_data_streamed(app: tauri::AppHandle) -> AppData {
+    use tauri::Emitter;
+    let home = get_home_dir();
+    scan_app_data_with_roots(&home, &global_skill_roots_with_home(&home), |event| {
+        let _ = match event {
+            ScanEvent::AgentDetected(agent) => app.emit("agent-detected", agent),
+            ScanEvent::SkillLoaded(skill) => app.emit("skill-loaded", skill),
+            ScanEvent::ScanComplete => app.emit("scan-complete", ()),
+        };
+    })
+}
 
-    Ok(())
+/// Counts the installed skills (symlinks and local directories) of every detected agent.
+pub fn count_agent_skills_with_home(home: &PathBuf) -> Vec<AgentSkillCount> {
+    detect_agents_with_home(home)
+        .into_iter()
+        .filter(|agent| agent.detected)
+        .map(|agent| {
+            let mut count = 0;
+            if let Ok(entries) = fs::read_dir(home.join(&agent.path)) {
+                for entry in entries.flatten() {
+                    let name = entry.file_name().to_string_lossy().to_string();
+                    if name.starts_with('.') {
+                        continue;
+                    }
+                    if let Ok(metadata) = fs::symlink_metadata(entry.path()) {
+                        let file_type = metadata.file_type();
+                        if file_type.is_symlink() || file_type.is_dir() {
+                            count += 1;
+                        }
+                    }
+                }
+            }
+            AgentSkillCount { agent_id: agent.id, count }
+        })
+        .collect()
 }
 
-/// Links a skill to all detected agents by creating symlinks.
-/// 
-/// Requirements: 1.4, 6.1, 6.3
-/// - 1.4: WHEN the user clicks "Link to All" on a skill card, THE Skills_Manager SHALL create symlinks for that skill in all detected agents' skills directories
-/// - 6.1: WHEN the user clicks "Link to All Agents" for a skill, THE Skills_Manager SHALL create symlinks in all detected agents' skills directories
-/// - 6.3: WHEN performing batch operations, THE Skills_Manager SHALL skip agents that are not detected
-pub fn link_skill_to_all_with_home(skill_name: &str, home: &PathBuf) -> Result<BatchResult, String> {
-    let agents = detect_agents_with_home(home);
-    let global_skill_path = home.join(".agents/skills").join(skill_name);
-    
-    // Verify the global skill exists
-    if !global_skill_path.exists() {
-        return Err(format!("Global skill '{}' does not exist", skill_name));
-    }
-    
-    let mut success: Vec<String> = Vec::new();
-    let mut failed: Vec<FailedOperation> = Vec::new();
-    
-    for agent in agents {
-        // Skip non-detected agents (Requirement 6.3)
-        if !agent.detected {
-            continue;
-        }
-        
-        let agent_skill_path = home.join(&agent.path).join(skill_name);
-        
-        // Check if symlink already exists
-        if let Ok(metadata) = fs::symlink_metadata(&agent_skill_path) {
-            if metadata.file_type().is_symlink() {
-                // Already linked, count as success
-                success.push(agent.id);
-                continue;
-            } else {
-                // A file or directory exists at the target path that is not a symlink
-                failed.push(FailedOperation {
-                    agent_id: agent.id,
-                    error: "A file or directory already exists at the target path".to_string(),
-                });
+/// Lists every installed skill of every detected agent as (agent_id, skill_name, status),
+/// including local-only skills and broken symlinks, sorted by agent then skill name.
+pub fn all_installed_skills_with_home(home: &PathBuf) -> Vec<(String, String, AgentSkillStatus)> {
+    let mut installed = Vec::new();
+    for agent in detect_agents_with_home(home).into_iter().filter(|agent| agent.detected) {
+        for (name, path) in agent_skill_entries(&home.join(&agent.path), agent.nested_scan) {
+            if name.starts_with('.') || name.ends_with(OVERRIDE_SUFFIX) {
                 continue;
             }
-        }
-        
-        // Create parent directory if needed (Requirement 5.7)
-        if let Some(parent) = agent_skill_path.parent() {
-            if let Err(e) = fs::create_dir_all(parent) {
-                failed.push(FailedOperation {
-                    agent_id: agent.id,
-                    error: format!("Failed to create parent directory: {}", e),
-                });
+            let Ok(metadata) = fs::symlink_metadata(&path) else {
                 continue;
-            }
-        }
-        
-        // Create symlink
-        match create_symlink(&global_skill_path, &agent_skill_path) {
-            Ok(_) => {
-                success.push(agent.id);
-            }
-            Err(e) => {
-                failed.push(FailedOperation {
-                    agent_id: agent.id,
-                    error: format!("Failed to create symlink: {}", e),
-                });
-            }
+            };
+            let status = if metadata.file_type().is_symlink() && fs::metadata(&path).is_err() {
+                AgentSkillStatus::BrokenSymlink
+            } else if metadata.file_type().is_symlink()
+                || (agent.link_layout != LinkLayout::Directory && is_contents_link(&path))
+            {
+                AgentSkillStatus::Symlink
+            } else if metadata.file_type().is_dir() {
+                AgentSkillStatus::Local
+            } else {
+                continue;
+            };
+            installed.push((agent.id.clone(), name, status));
         }
     }
-    
-    Ok(BatchResult { success, failed })
+    installed.sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+    installed
 }
 
 #[tauri::command]
-fn link_skill_to_all(skill_name: String) -> Result<BatchResult, String> {
+fn all_installed_skills() -> Vec<(String, String, AgentSkillStatus)> {
     let home = get_home_dir();
-    link_skill_to_all_with_home(&skill_name, &home)
+    all_installed_skills_with_home(&home)
 }
 
-/// Unlinks a skill from all agents by removing symlinks.
-/// Unlike link_skill_to_all, this attempts to remove symlinks from ALL agents
-/// (not just detected ones) to ensure cleanup.
-/// 
-/// Requirements: 1.5, 6.2
-/// - 1.5: WHEN the user clicks "Unlink from All" on a skill card, THE Skills_Manager SHALL remove symlinks for that skill from all agents' skills directories
-/// - 6.2: WHEN the user clicks "Unlink from All Agents" for a skill, THE Skills_Manager SHALL remove symlinks from all agents' skills directories
-pub fn unlink_skill_from_all_with_home(skill_name: &str, home: &PathBuf) -> Result<BatchResult, String> {
-    let agent_definitions = get_agent_definition_list();
-    
-    let mut success: Vec<String> = Vec::new();
-    let mut failed: Vec<FailedOperation> = Vec::new();
-    
-    for (id, _name, rel_path) in agent_definitions {
-        let agent_skill_path = home.join(rel_path).join(skill_name);
-        
-        // Check if symlink exists at agent's skills directory
-        match fs::symlink_metadata(&agent_skill_path) {
-            Ok(metadata) => {
-                if metadata.file_type().is_symlink() {
-                    // Symlink exists, try to remove it
-                    match fs::remove_file(&agent_skill_path) {
-                        Ok(_) => {
-                            success.push(id.to_string());
-                        }
-                        Err(e) => {
-                            failed.push(FailedOperation {
-                                agent_id: id.to_string(),
-                                error: format!("Failed to remove symlink: {}", e),
-                            });
-                        }
-                    }
-                }
-                // If it exists but is not a symlink, we don't touch it (not our symlink)
-            }
-            Err(_) => {
-                // Path doesn't exist or can't be accessed - nothing to unlink
-                // This is not a failure, just means there's no symlink to remove
-            }
+/// Returns the names of skills that are not installed in any agent.
+fn unused_skill_names(skills: &[Skill]) -> Vec<String> {
+    skills
+        .iter()
+        .filter(|skill| skill.linked_agents.is_empty())
+        .map(|skill| skill.name.clone())
+        .collect()
+}
+
+/// Lists global skills that are not installed (neither symlinked nor local) in any detected agent.
+pub fn find_unused_skills_with_home(home: &PathBuf) -> Vec<String> {
+    unused_skill_names(&get_app_data_with_home(home).skills)
+}
+
+/// Groups global skills whose directory contents are byte-identical.
+/// Only groups with two or more skills are returned; names within and across groups are sorted.
+pub fn find_duplicate_skills_with_home(home: &PathBuf) -> Vec<Vec<String>> {
+    let mut by_hash: std::collections::HashMap<u64, Vec<String>> = std::collections::HashMap::new();
+    for skill in get_app_data_with_home(home).skills {
+        // Skills that cannot be read are not reported as duplicates
+        if let Ok(hash) = skill_content_hash(&PathBuf::from(&skill.root).join(&skill.name)) {
+            by_hash.entry(hash).or_default().push(skill.name);
         }
     }
-    
-    Ok(BatchResult { success, failed })
+
+    let mut groups: Vec<Vec<String>> = by_hash
+        .into_values()
+        .filter(|names| names.len() >= 2)
+        .map(|mut names| {
+            names.sort();
+            names
+        })
+        .collect();
+    groups.sort();
+    groups
 }
 
 #[tauri::command]
-fn unlink_skill_from_all(skill_name: String) -> Result<BatchResult, String> {
+fn find_duplicate_skills() -> Vec<Vec<String>> {
     let home = get_home_dir();
-    unlink_skill_from_all_with_home(&skill_name, &home)
+    find_duplicate_skills_with_home(&home)
 }
 
-/// Gets detailed skill information for a specific agent.
-/// This includes both global skills and local-only skills in the agent's directory.
-pub fn get_agent_detail_with_home(agent_id: &str, home: &PathBuf) -> Result<AgentDetailData, String> {
-    let agents = detect_agents_with_home(home);
-    let agent = agents.into_iter()
-        .find(|a| a.id == agent_id)
-        .ok_or_else(|| format!("Agent '{}' not found", agent_id))?;
-    
-    let global_skills_path = home.join(".agents/skills");
-    let agent_skills_path = home.join(&agent.path);
-    
-    // Collect global skill names (excluding hidden directories)
-    let mut global_skill_names: std::collections::HashSet<String> = std::collections::HashSet::new();
-    if let Ok(entries) = fs::read_dir(&global_skills_path) {
-        for entry in entries.flatten() {
-            if let Ok(ft) = entry.file_type() {
-                if ft.is_dir() {
-                    let name = entry.file_name().to_string_lossy().to_string();
-                    if !name.starts_with('.') {
-                        global_skill_names.insert(name);
+/// Groups global skills whose SKILL.md declares the same `name`, which agents that key
+/// on the display name cannot tell apart. Only names shared by two or more skills are
+/// returned, sorted by name, each with its sorted directory names.
+pub fn find_metadata_name_collisions_with_home(home: &PathBuf) -> Vec<(String, Vec<String>)> {
+    let mut by_name: std::collections::BTreeMap<String, Vec<String>> = std::collections::BTreeMap::new();
+    for skill in get_app_data_with_home(home).skills {
+        by_name.entry(skill.metadata.name.trim().to_string()).or_default().push(skill.name);
+    }
+
+    by_name
+        .into_iter()
+        .filter(|(_, names)| names.len() >= 2)
+        .map(|(name, mut names)| {
+            names.sort();
+            (name, names)
+        })
+        .collect()
+}
+
+#[tauri::command]
+fn find_metadata_name_collisions() -> Vec<(String, Vec<String>)> {
+    let home = get_home_dir();
+    find_metadata_name_collisions_with_home(&home)
+}
+
+/// Reports, for every global skill, which SKILL.md format it parsed from and which
+/// metadata fields are present. Fallback values (directory name, placeholder
+/// description) do not count as present. Sorted by skill name.
+pub fn library_quality_report_with_home(home: &PathBuf) -> Vec<SkillQuality> {
+    let mut skills = get_app_data_with_home(home).skills;
+    skills.sort_by(|a, b| a.name.cmp(&b.name));
+    skills
+        .into_iter()
+        .map(|skill| {
+            let skill_md_path = PathBuf::from(&skill.root).join(&skill.name).join("SKILL.md");
+            match read_skill_md(&skill_md_path) {
+                Ok((content, _warning)) => {
+                    let (metadata, source) = parse_skill_md_with_source(&content);
+                    SkillQuality {
+                        name: skill.name,
+                        parse_source: Some(source),
+                        has_name: !metadata.name.trim().is_empty(),
+                        has_description: !metadata.description.trim().is_empty(),
+                        has_allowed_tools: !metadata.allowed_tools.is_empty(),
+                        has_tags: !metadata.tags.is_empty(),
                     }
                 }
+                Err(_) => SkillQuality {
+                    name: skill.name,
+                    parse_source: None,
+                    has_name: false,
+                    has_description: false,
+                    has_allowed_tools: false,
+                    has_tags: false,
+                },
+            }
+        })
+        .collect()
+}
+
+#[tauri::command]
+fn library_quality_report() -> Vec<SkillQuality> {
+    let home = get_home_dir();
+    library_quality_report_with_home(&home)
+}
+
+/// Aggregates size, file count and metadata statistics over every global skill. Each
+/// global root is listed once and each skill directory walked once; agent directories
+/// are not read. Files are counted as in [`list_skill_files`].
+pub fn library_stats_with_home(home: &PathBuf) -> LibraryStats {
+    /// Adds the files under `dir` to the stats, stat-ing each entry once
+    fn walk(dir: &std::path::Path, stats: &mut LibraryStats) {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            if entry.file_name().to_string_lossy().starts_with('.') {
+                continue;
+            }
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            if file_type.is_dir() {
+                walk(&entry.path(), stats);
+                continue;
+            }
+            // Symlinks to files count with the size of their target
+            let metadata = if file_type.is_symlink() { fs::metadata(entry.path()) } else { entry.metadata() };
+            if let Some(metadata) = metadata.ok().filter(|m| m.is_file()) {
+                stats.total_files += 1;
+                stats.total_size += metadata.len();
             }
         }
     }
-    
-    let mut skills: Vec<AgentSkill> = Vec::new();
-    let mut seen_skills: std::collections::HashSet<String> = std::collections::HashSet::new();
-    
-    // First, scan agent's skills directory for installed skills (symlinks and local)
-    if agent.detected {
-        if let Ok(entries) = fs::read_dir(&agent_skills_path) {
+
+    let mut stats = LibraryStats::default();
+    let mut seen: std::collections::HashSet<String> = std::collections::HashSet::new();
+    let mut tools: std::collections::HashSet<String> = std::collections::HashSet::new();
+    let mut tags: std::collections::HashSet<String> = std::collections::HashSet::new();
+
+    for root in global_skill_roots_with_home(home) {
+        let Ok(entries) = fs::read_dir(&root) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.starts_with('.') || !entry.file_type().is_ok_and(|t| t.is_dir()) {
+                continue;
+            }
+            // A higher-priority root already provided this skill
+            if !seen.insert(normalized_skill_name(&name)) {
+                continue;
+            }
+            let skill_dir = entry.path();
+            stats.total_skills += 1;
+            walk(&skill_dir, &mut stats);
+            if !skill_dir.join("SKILL.md").is_file() {
+                stats.missing_skill_md += 1;
+            }
+            let metadata = load_skill_metadata(&skill_dir, &name);
+            tools.extend(metadata.allowed_tools);
+            tags.extend(metadata.tags);
+        }
+    }
+
+    stats.distinct_tools = tools.len();
+    stats.distinct_tags = tags.len();
+    stats
+}
+
+#[tauri::command]
+fn library_stats() -> LibraryStats {
+    let home = get_home_dir();
+    library_stats_with_home(&home)
+}
+
+/// Finds symlinks in agent skills directories whose target no longer exists.
+/// Returns (agent_id, skill_name) pairs.
+pub fn find_broken_symlinks_with_home(home: &PathBuf) -> Vec<(String, String)> {
+    let mut broken = Vec::new();
+
+    for agent in detect_agents_with_home(home) {
+        if !agent.detected {
+            continue;
+        }
+        if let Ok(entries) = fs::read_dir(home.join(&agent.path)) {
             for entry in entries.flatten() {
                 let name = entry.file_name().to_string_lossy().to_string();
-                
-                // Skip hidden directories
                 if name.starts_with('.') {
                     continue;
                 }
-                
-                if seen_skills.contains(&name) {
-                    continue;
-                }
-                
-                if let Ok(metadata) = fs::symlink_metadata(entry.path()) {
-                    let file_type = metadata.file_type();
-                    
-                    if file_type.is_symlink() {
-                        // It's a symlink - get the target
-                        let target = fs::read_link(entry.path())
-                            .map(|p| p.to_string_lossy().to_string())
-                            .unwrap_or_else(|_| "unknown".to_string());
-                        
-                        // Load metadata from the symlink target
-                        let skill_metadata = if let Ok(resolved) = fs::canonicalize(entry.path()) {
-                            load_skill_metadata(&resolved, &name)
-                        } else {
-                            load_skill_metadata(&entry.path(), &name)
-                        };
-                        
-                        skills.push(AgentSkill {
-                            name: name.clone(),
-                            metadata: skill_metadata,
-                            status: AgentSkillStatus::Symlink,
-                            source_path: Some(target),
-                            in_global: global_skill_names.contains(&name),
-                        });
-                        seen_skills.insert(name);
-                    } else if file_type.is_dir() {
-                        // It's a local directory (not a symlink)
-                        let skill_metadata = load_skill_metadata(&entry.path(), &name);
-                        let local_path = entry.path().to_string_lossy().to_string();
-                        
-                        skills.push(AgentSkill {
-                            name: name.clone(),
-                            metadata: skill_metadata,
-                            status: AgentSkillStatus::Local,
-                            source_path: Some(local_path),
-                            in_global: global_skill_names.contains(&name),
-                        });
-                        seen_skills.insert(name);
-                    }
+                let is_symlink = fs::symlink_metadata(entry.path())
+                    .map(|m| m.file_type().is_symlink())
+                    .unwrap_or(false);
+                // A symlink whose target can't be followed is broken
+                if is_symlink && fs::metadata(entry.path()).is_err() {
+                    broken.push((agent.id.clone(), name));
                 }
             }
         }
     }
-    
-    // Then, add global skills that are not installed
-    for global_name in &global_skill_names {
-        if !seen_skills.contains(global_name) {
-            let global_skill_path = global_skills_path.join(global_name);
-            let skill_metadata = load_skill_metadata(&global_skill_path, global_name);
-            
-            skills.push(AgentSkill {
-                name: global_name.clone(),
-                metadata: skill_metadata,
-                status: AgentSkillStatus::NotInstalled,
-                source_path: None,
-                in_global: true,
-            });
-        }
+
+    broken.sort();
+    broken
+}
+
+#[tauri::command]
+fn find_broken_symlinks() -> Vec<(String, String)> {
+    let home = get_home_dir();
+    find_broken_symlinks_with_home(&home)
+}
+
+/// Builds the main view payload in one pass so the frontend needs a single invocation.
+pub fn get_dashboard_with_home(home: &PathBuf) -> Dashboard {
+    let app_data = get_app_data_with_home(home);
+    let total_agent_count = app_data.agents.len();
+    let detected_agent_count = app_data.agents.iter().filter(|a| a.detected).count();
+    let unused_skills = unused_skill_names(&app_data.skills);
+
+    Dashboard {
+        detected_agent_count,
+        total_agent_count,
+        agent_skill_counts: count_agent_skills_with_home(home),
+        unused_skills,
+        broken_link_count: find_broken_symlinks_with_home(home).len(),
+        config_warnings: config_warnings_with_home(home),
+        app_data,
     }
-    
-    // Sort skills by name
+}
+
+#[tauri::command]
+fn get_dashboard() -> Dashboard {
+    let home = get_home_dir();
+    get_dashboard_with_home(&home)
+}
+
+/// Returns the latest modification time of any file under a directory.
+/// Unreadable files and subdirectories are skipped, and symlinked directories are not
+/// followed; falls back to the directory's own mtime when it contains no readable files.
+fn latest_mtime(dir: &std::path::Path) -> Option<std::time::SystemTime> {
+    fn walk(dir: &std::path::Path) -> Option<std::time::SystemTime> {
+        let entries = fs::read_dir(dir).ok()?;
+        entries
+            .flatten()
+            .filter_map(|entry| {
+                let path = entry.path();
+                if fs::symlink_metadata(&path).ok()?.is_dir() {
+                    walk(&path)
+                } else {
+                    fs::metadata(&path).ok().filter(|m| m.is_file()).and_then(|m| m.modified().ok())
+                }
+            })
+            .max()
+    }
+
+    walk(dir).or_else(|| fs::metadata(dir).and_then(|m| m.modified()).ok())
+}
+
+/// Returns up to `limit` global skills, most recently modified first.
+pub fn recently_modified_skills_with_home(home: &PathBuf, limit: usize) -> Vec<Skill> {
+    let mut skills: Vec<(Option<std::time::SystemTime>, Skill)> = get_app_data_with_home(home)
+        .skills
+        .into_iter()
+        .map(|skill| (latest_mtime(&PathBuf::from(&skill.root).join(&skill.name)), skill))
+        .collect();
+    // Newest first; skills without a readable mtime sort last
+    skills.sort_by(|(a, _), (b, _)| b.cmp(a));
+    skills.into_iter().take(limit).map(|(_, skill)| skill).collect()
+}
+
+#[tauri::command]
+fn recently_modified_skills(limit: usize) -> Vec<Skill> {
+    let home = get_home_dir();
+    recently_modified_skills_with_home(&home, limit)
+}
+
+/// Returns the global skills matching a search query, sorted by name. A skill matches when
+/// every whitespace-separated term appears, case-insensitively, in its metadata name or
+/// description. An empty query matches every skill.
+pub fn search_skills_with_home(query: &str, home: &PathBuf) -> Vec<Skill> {
+    let terms: Vec<String> = query.split_whitespace().map(|term| term.to_lowercase()).collect();
+    let mut skills: Vec<Skill> = get_app_data_with_home(home)
+        .skills
+        .into_iter()
+        .filter(|skill| {
+            let name = skill.metadata.name.to_lowercase();
+            let description = skill.metadata.description.to_lowercase();
+            terms.iter().all(|term| name.contains(term.as_str()) || description.contains(term.as_str()))
+        })
+        .collect();
     skills.sort_by(|a, b| a.name.cmp(&b.name));
-    
-    Ok(AgentDetailData { agent, skills })
+    skills
 }
 
 #[tauri::command]
-fn get_agent_detail(agent_id: String) -> Result<AgentDetailData, String> {
+fn search_skills(query: String) -> Vec<Skill> {
     let home = get_home_dir();
-    get_agent_detail_with_home(&agent_id, &home)
+    search_skills_with_home(&query, &home)
+}
+
+/// Returns the global skills an agent does not have yet, neither symlinked nor local.
+pub fn available_skills_for_agent_with_home(agent_id: &str, home: &PathBuf) -> Vec<Skill> {
+    get_app_data_with_home(home)
+        .skills
+        .into_iter()
+        .filter(|skill| !skill.linked_agents.iter().any(|id| id == agent_id))
+        .collect()
 }
 
-/// Deletes a local skill directory (not a symlink) from an agent's skills directory.
 #[tauri::command]
-fn delete_local_skill(agent_id: String, skill_name: String) -> Result<(), String> {
-    let agents = get_agent_definitions();
-    let agent = agents.iter().find(|a| a.id == agent_id).ok_or("Agent not found")?;
-    
+fn available_skills_for_agent(agent_id: String) -> Vec<Skill> {
     let home = get_home_dir();
-    let skill_path = home.join(&agent.path).join(&skill_name);
-    
-    // Check if it exists and is NOT a symlink
-    match fs::symlink_metadata(&skill_path) {
-        Ok(metadata) => {
-            if metadata.file_type().is_symlink() {
-                return Err("Cannot delete: this is a symlink, use unlink instead".to_string());
-            }
-            if metadata.file_type().is_dir() {
-                fs::remove_dir_all(&skill_path)
-                    .map_err(|e| format!("Failed to delete directory: {}", e))?;
-                Ok(())
-            } else {
-                Err("Path is not a directory".to_string())
-            }
-        }
-        Err(_) => Err("Skill directory not found".to_string()),
-    }
+    available_skills_for_agent_with_home(&agent_id, &home)
 }
 
-/// Recursively copies a directory and its contents
-fn copy_dir_recursive(src: &PathBuf, dst: &PathBuf) -> Result<(), String> {
-    fs::create_dir_all(dst).map_err(|e| format!("Failed to create directory: {}", e))?;
-    
-    let entries = fs::read_dir(src).map_err(|e| format!("Failed to read directory: {}", e))?;
+/// Links or unlinks one skill for one agent. Explicit, so it also links disabled skills.
+pub fn toggle_skill_with_home(agent_id: &str, skill_name: &str, enable: bool, home: &PathBuf) -> Result<(), SkillError> {
+    ensure_not_safe_mode(home).map_err(|_| SkillError::SafeMode)?;
+    let agents = detect_agents_with_home(home);
+    let agent = agents
+        .iter()
+        .find(|a| a.id == agent_id)
+        .ok_or_else(|| SkillError::AgentNotFound(agent_id.to_string()))?;
     
-    for entry in entries {
-        let entry = entry.map_err(|e| format!("Failed to read entry: {}", e))?;
-        let src_path = entry.path();
-        let dst_path = dst.join(entry.file_name());
+    // An installed entry spelled in another unicode normalization form is the same skill
+    let agent_skill_path = entry_matching_name(&home.join(&agent.path), skill_name);
+
+    if enable {
+        let global_skill_path = find_global_skill(&global_skill_roots_with_home(home), skill_name)
+            .ok_or_else(|| SkillError::SkillNotFound(skill_name.to_string()))?;
         
-        if src_path.is_dir() {
-            copy_dir_recursive(&src_path, &dst_path)?;
-        } else {
-            fs::copy(&src_path, &dst_path).map_err(|e| format!("Failed to copy file: {}", e))?;
+        // Create parent dir if needed
+        if let Some(parent) = agent_skill_path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+
+        check_link_not_cyclic(&global_skill_path, &agent_skill_path).map_err(SkillError::InvalidLink)?;
+
+        // Create symlink, or a directory of symlinks for agents expecting that layout
+        link_with_layout(&global_skill_path, &agent_skill_path, agent.link_layout)
+            .map_err(|e| SkillError::Io(format!("Failed to link: {}", e)))?;
+    } else if agent.link_layout != LinkLayout::Directory && is_contents_link(&agent_skill_path) {
+        remove_contents_link(&agent_skill_path).map_err(|e| SkillError::Io(format!("Failed to unlink: {}", e)))?;
+    } else if let Ok(metadata) = fs::symlink_metadata(&agent_skill_path) {
+        // Only a link can be toggled off; a local skill has to be deleted explicitly
+        if !metadata.file_type().is_symlink() {
+            return Err(SkillError::NotASymlink(skill_name.to_string()));
         }
+        link::remove_skill_link(&agent_skill_path).map_err(|e| SkillError::Io(format!("Failed to unlink: {}", e)))?;
     }
-    
+
     Ok(())
 }
 
-/// Uploads a local skill from an agent's directory to the global skills directory.
 #[tauri::command]
-fn upload_to_global(agent_id: String, skill_name: String) -> Result<(), String> {
-    let agents = get_agent_definitions();
-    let agent = agents.iter().find(|a| a.id == agent_id).ok_or("Agent not found")?;
-    
+fn toggle_skill(agent_id: String, skill_name: String, enable: bool) -> Result<(), SkillError> {
     let home = get_home_dir();
-    let local_skill_path = home.join(&agent.path).join(&skill_name);
-    let global_skill_path = get_global_skills_path().join(&skill_name);
-    
-    // Check if local skill exists and is NOT a symlink
-    match fs::symlink_metadata(&local_skill_path) {
-        Ok(metadata) => {
-            if metadata.file_type().is_symlink() {
-                return Err("Cannot upload: this is already a symlink".to_string());
+    toggle_skill_with_home(&agent_id, &skill_name, enable, &home)
+}
+
+/// Links a global skill at `link` using the given layout.
+fn link_with_layout(target: &PathBuf, link: &PathBuf, layout: LinkLayout) -> std::io::Result<()> {
+    match layout {
+        LinkLayout::Directory => create_symlink(target, link),
+        LinkLayout::Contents => {
+            fs::create_dir(link)?;
+            let result = fs::read_dir(target).and_then(|entries| {
+                for entry in entries.flatten() {
+                    if !entry.file_name().to_string_lossy().starts_with('.') {
+                        create_symlink(entry.path(), link.join(entry.file_name()))?;
+                    }
+                }
+                Ok(())
+            });
+            if result.is_err() {
+                let _ = remove_contents_link(link);
             }
-            if !metadata.file_type().is_dir() {
-                return Err("Path is not a directory".to_string());
+            result
+        }
+        LinkLayout::SkillMdOnly => {
+            fs::create_dir(link)?;
+            let result = create_symlink(target.join("SKILL.md"), link.join("SKILL.md"));
+            if result.is_err() {
+                let _ = fs::remove_dir(link);
             }
+            result
         }
-        Err(_) => return Err("Local skill directory not found".to_string()),
-    }
-    
-    // Check if global skill already exists
-    if global_skill_path.exists() {
-        return Err(format!("Skill '{}' already exists in global skills", skill_name));
     }
-    
-    // Create global skills directory if it doesn't exist
-    let global_skills_dir = get_global_skills_path();
-    if !global_skills_dir.exists() {
-        fs::create_dir_all(&global_skills_dir)
-            .map_err(|e| format!("Failed to create global skills directory: {}", e))?;
+}
+
+/// Whether a freshly created link resolves to `target`. For layouts that create a real
+/// directory, every symlink inside it must resolve to the matching entry of `target`.
+fn link_resolves_to(link: &PathBuf, target: &PathBuf, layout: LinkLayout) -> bool {
+    let resolves = |link: &std::path::Path, target: &std::path::Path| match (fs::canonicalize(link), fs::canonicalize(target)) {
+        (Ok(resolved), Ok(expected)) => resolved == expected,
+        _ => false,
+    };
+    match layout {
+        LinkLayout::Directory => resolves(link, target),
+        LinkLayout::Contents | LinkLayout::SkillMdOnly => fs::read_dir(link)
+            .map(|entries| entries.flatten().all(|entry| resolves(&entry.path(), &target.join(entry.file_name()))))
+            .unwrap_or(false),
     }
-    
-    // Copy the skill directory to global
-    copy_dir_recursive(&local_skill_path, &global_skill_path)?;
-    
-    Ok(())
 }
 
-#[cfg_attr(mobile, tauri::mobile_entry_point)]
-pub fn run() {
-    tauri::Builder::default()
-        .plugin(tauri_plugin_opener::init())
-        .invoke_handler(tauri::generate_handler![
-            get_app_data, 
-            toggle_skill, 
-            link_skill_to_all, 
-            unlink_skill_from_all,
-            get_agent_detail,
-            delete_local_skill,
-            upload_to_global
-        ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+/// Whether `path` is a real directory containing only symlinks, as created by the
+/// [`LinkLayout::Contents`] and [`LinkLayout::SkillMdOnly`] layouts.
+fn is_contents_link(path: &PathBuf) -> bool {
+    let is_dir = fs::symlink_metadata(path).map(|m| m.file_type().is_dir()).unwrap_or(false);
+    let Ok(entries) = fs::read_dir(path).map(|e| e.flatten().collect::<Vec<_>>()) else {
+        return false;
+    };
+    is_dir
+        && !entries.is_empty()
+        && entries.iter().all(|entry| entry.file_type().map(|t| t.is_symlink()).unwrap_or(false))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::fs;
-    use tempfile::TempDir;
+/// Removes a [`LinkLayout::Contents`] or [`LinkLayout::SkillMdOnly`] link: its symlinks,
+/// then the directory itself.
+/// Anything else inside the directory is left alone and makes the removal fail.
+fn remove_contents_link(path: &PathBuf) -> std::io::Result<()> {
+    for entry in fs::read_dir(path)?.flatten() {
+        if entry.file_type().map(|t| t.is_symlink()).unwrap_or(false) {
+            link::remove_skill_link(&entry.path())?;
+        }
+    }
+    fs::remove_dir(path)
+}
 
-    /// Helper function to create a temporary home directory for testing
-    fn create_temp_home() -> TempDir {
-        TempDir::new().expect("Failed to create temp directory")
+/// The skill directory a contents link points into, if the agent uses a contents
+/// layout and all of its symlinks point into the same directory.
+fn contents_link_source(path: &PathBuf, layout: LinkLayout) -> Option<PathBuf> {
+    if layout == LinkLayout::Directory || !is_contents_link(path) {
+        return None;
+    }
+    let mut sources = fs::read_dir(path).ok()?.flatten().map(|entry| {
+        let target = fs::read_link(entry.path()).ok()?;
+        // Relative targets are relative to the directory holding the link
+        let target = if target.is_absolute() { target } else { path.join(target) };
+        target.parent().map(|parent| parent.to_path_buf())
+    });
+    let first = sources.next()??;
+    sources.all(|source| source.as_ref() == Some(&first)).then_some(first)
+}
+
+/// Refuses a symlink at `link` pointing at `target` when one contains the other once
+/// canonicalized, which happens when a global root is configured inside an agent's
+/// skills directory. Such a link would create a symlink loop.
+fn check_link_not_cyclic(target: &PathBuf, link: &PathBuf) -> Result<(), String> {
+    let target = fs::canonicalize(target).map_err(|e| format!("Failed to resolve skill path: {}", e))?;
+    // The link itself does not exist yet, so resolve its nearest existing ancestor
+    let mut existing = link.clone();
+    let mut rest: Vec<std::ffi::OsString> = Vec::new();
+    while !existing.exists() {
+        let (Some(parent), Some(name)) = (existing.parent(), existing.file_name()) else {
+            break;
+        };
+        rest.push(name.to_os_string());
+        existing = parent.to_path_buf();
+    }
+    let mut location = fs::canonicalize(&existing).unwrap_or(existing);
+    for name in rest.into_iter().rev() {
+        location.push(name);
+    }
+
+    if location.starts_with(&target) || target.starts_with(&location) {
+        return Err(format!(
+            "Refusing to link {} to {}: the link would be inside its own target (symlink loop)",
+            location.to_string_lossy(),
+            target.to_string_lossy()
+        ));
+    }
+    Ok(())
+}
+
+/// Links a skill to all detected agents by creating symlinks.
+/// 
+/// Requirements: 1.4, 6.1, 6.3
+/// - 1.4: WHEN the user clicks "Link to All" on a skill card, THE Skills_Manager SHALL create symlinks for that skill in all detected agents' skills directories
+/// - 6.1: WHEN the user clicks "Link to All Agents" for a skill, THE Skills_Manager SHALL create symlinks in all detected agents' skills directories
+/// - 6.3: WHEN performing batch operations, THE Skills_Manager SHALL skip agents that are not detected
+pub fn link_skill_to_all_with_home(skill_name: &str, home: &PathBuf) -> Result<BatchResult, String> {
+    link_skill_to_all_with_roots(skill_name, home, &global_skill_roots_with_home(home), LinkOptions::default())
+}
+
+/// Options of the batch link commands; all off by default
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LinkOptions {
+    /// Resolve each newly created link afterwards; agents whose link does not resolve to
+    /// the global skill are reported as failed rather than linked
+    pub verify: bool,
+    /// Link nothing when the skill has validation warnings; every detected agent is
+    /// reported as failed with the warnings
+    pub validate: bool,
+    /// Create nothing; the result reports which agents would be linked and which would
+    /// fail, classified as in a real run
+    pub dry_run: bool,
+}
+
+/// Links a skill to all detected agents, targeting the first global root that contains it,
+/// as adjusted by `options`. A disabled skill is linked nowhere and every detected agent
+/// is reported in `skipped_disabled`.
+pub fn link_skill_to_all_with_roots(
+    skill_name: &str,
+    home: &PathBuf,
+    roots: &[PathBuf],
+    options: LinkOptions,
+) -> Result<BatchResult, String> {
+    if !options.dry_run {
+        ensure_not_safe_mode(home)?;
+    }
+    let agents = detect_agents_with_home(home);
+    
+    // Verify the global skill exists
+    let global_skill_path = find_global_skill(roots, skill_name)
+        .ok_or_else(|| format!("Global skill '{}' does not exist", skill_name))?;
+    let disabled = is_skill_disabled(&global_skill_path);
+    let validation_error = if options.validate {
+        let warnings = validate_skill_with_home(skill_name, home)?;
+        (!warnings.is_empty()).then(|| {
+            let messages: Vec<String> = warnings.iter().map(|w| w.to_string()).collect();
+            format!("Skill failed validation: {}", messages.join("; "))
+        })
+    } else {
+        None
+    };
+    
+    let mut success: Vec<String> = Vec::new();
+    let mut failed: Vec<FailedOperation> = Vec::new();
+    let mut skipped_disabled: Vec<FailedOperation> = Vec::new();
+    
+    for agent in agents {
+        // Skip non-detected agents (Requirement 6.3)
+        if !agent.detected {
+            continue;
+        }
+
+        // Disabled skills are only linked explicitly, one agent at a time
+        if disabled {
+            skipped_disabled.push(FailedOperation { agent_id: agent.id, error: "Skill is disabled".to_string() });
+            continue;
+        }
+
+        if let Some(error) = &validation_error {
+            failed.push(FailedOperation { agent_id: agent.id, error: error.clone() });
+            continue;
+        }
+        
+        match link_skill_into_agent(&agent, &global_skill_path, skill_name, home, options) {
+            Ok(_) => success.push(agent.id),
+            Err(error) => failed.push(FailedOperation { agent_id: agent.id, error }),
+        }
+    }
+    
+    Ok(BatchResult { success, failed, skipped_disabled, ..Default::default() })
+}
+
+#[tauri::command]
+fn link_skill_to_all(
+    skill_name: String,
+    verify: Option<bool>,
+    validate_before_link: Option<bool>,
+    dry_run: Option<bool>,
+) -> Result<BatchResult, String> {
+    let home = get_home_dir();
+    let roots = global_skill_roots_with_home(&home);
+    let options = LinkOptions {
+        verify: verify.unwrap_or(false),
+        validate: validate_before_link.unwrap_or(false),
+        dry_run: dry_run.unwrap_or(false),
+    };
+    link_skill_to_all_with_roots(&skill_name, &home, &roots, options)
+}
+
+/// Links a global skill into one detected agent, the step shared by the batch link
+/// commands. An existing link counts as success; anything else at the path is an error.
+/// With `options.dry_run`, every check runs but nothing is created.
+fn link_skill_into_agent(
+    agent: &Agent,
+    global_skill_path: &PathBuf,
+    skill_name: &str,
+    home: &PathBuf,
+    options: LinkOptions,
+) -> Result<(), String> {
+    let agent_skill_path = home.join(&agent.path).join(skill_name);
+
+    // Check if symlink already exists
+    if let Ok(metadata) = fs::symlink_metadata(&agent_skill_path) {
+        let contents_linked = agent.link_layout != LinkLayout::Directory && is_contents_link(&agent_skill_path);
+        if metadata.file_type().is_symlink() || contents_linked {
+            // Already linked, count as success
+            return Ok(());
+        }
+        // A file or directory exists at the target path that is not a symlink
+        return Err("A file or directory already exists at the target path".to_string());
+    }
+
+    // Refuse links that would point at their own ancestor or descendant
+    check_link_not_cyclic(global_skill_path, &agent_skill_path)?;
+    if options.dry_run {
+        return Ok(());
+    }
+
+    // Create parent directory if needed (Requirement 5.7)
+    if let Some(parent) = agent_skill_path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create parent directory: {}", e))?;
+    }
+
+    // Create symlink, or a directory of symlinks for agents expecting that layout
+    link_with_layout(global_skill_path, &agent_skill_path, agent.link_layout)
+        .map_err(|e| format!("Failed to create symlink: {}", e))?;
+    if options.verify {
+        verify_new_link(&agent_skill_path, global_skill_path, agent.link_layout)?;
+    }
+    Ok(())
+}
+
+/// Checks that a just-created link resolves to `target`, removing it when it does not so
+/// no link is left behind that the caller reports as failed.
+fn verify_new_link(link: &PathBuf, target: &PathBuf, layout: LinkLayout) -> Result<(), String> {
+    if link_resolves_to(link, target, layout) {
+        return Ok(());
+    }
+    let _ = match layout {
+        LinkLayout::Directory => link::remove_skill_link(link),
+        LinkLayout::Contents | LinkLayout::SkillMdOnly => remove_contents_link(link),
+    };
+    Err("Link created but does not resolve to the global skill".to_string())
+}
+
+/// Links a global skill into the named agents only. Unknown or undetected agent IDs are
+/// reported in `failed`; disabled skills go to `skipped_disabled` as in [`link_skill_to_all_with_roots`].
+pub fn link_skill_to_agents_with_home(skill_name: &str, agent_ids: &[String], home: &PathBuf) -> Result<BatchResult, String> {
+    ensure_not_safe_mode(home)?;
+    let agents = detect_agents_with_home(home);
+    let global_skill_path = find_global_skill(&global_skill_roots_with_home(home), skill_name)
+        .ok_or_else(|| format!("Global skill '{}' does not exist", skill_name))?;
+    let disabled = is_skill_disabled(&global_skill_path);
+
+    let mut success: Vec<String> = Vec::new();
+    let mut failed: Vec<FailedOperation> = Vec::new();
+    let mut skipped_disabled: Vec<FailedOperation> = Vec::new();
+
+    for agent_id in agent_ids {
+        let Some(agent) = agents.iter().find(|a| &a.id == agent_id) else {
+            failed.push(FailedOperation { agent_id: agent_id.clone(), error: format!("Agent '{}' not found", agent_id) });
+            continue;
+        };
+        if !agent.detected {
+            failed.push(FailedOperation { agent_id: agent_id.clone(), error: "Agent is not detected".to_string() });
+            continue;
+        }
+        if disabled {
+            skipped_disabled.push(FailedOperation { agent_id: agent_id.clone(), error: "Skill is disabled".to_string() });
+            continue;
+        }
+        match link_skill_into_agent(agent, &global_skill_path, skill_name, home, LinkOptions::default()) {
+            Ok(_) => success.push(agent_id.clone()),
+            Err(error) => failed.push(FailedOperation { agent_id: agent_id.clone(), error }),
+        }
+    }
+
+    Ok(BatchResult { success, failed, skipped_disabled, ..Default::default() })
+}
+
+#[tauri::command]
+fn link_skill_to_agents(skill_name: String, agent_ids: Vec<String>) -> Result<BatchResult, String> {
+    let home = get_home_dir();
+    link_skill_to_agents_with_home(&skill_name, &agent_ids, &home)
+}
+
+/// Unlinks a skill from all agents by removing symlinks.
+/// Unlike link_skill_to_all, this attempts to remove symlinks from ALL agents
+/// (not just detected ones) to ensure cleanup.
+/// 
+/// Requirements: 1.5, 6.2
+/// - 1.5: WHEN the user clicks "Unlink from All" on a skill card, THE Skills_Manager SHALL remove symlinks for that skill from all agents' skills directories
+/// - 6.2: WHEN the user clicks "Unlink from All Agents" for a skill, THE Skills_Manager SHALL remove symlinks from all agents' skills directories
+pub fn unlink_skill_from_all_with_home(skill_name: &str, home: &PathBuf) -> Result<BatchResult, String> {
+    ensure_not_safe_mode(home)?;
+    Ok(unlink_skill_from_agents(skill_name, home, false, None))
+}
+
+/// Like [`unlink_skill_from_all_with_home`], but only removes links that resolve to the
+/// global skill. A link with the skill's name pointing anywhere else, or nowhere, is left
+/// in place and reported in `failed`.
+pub fn unlink_skill_from_all_verified_with_home(skill_name: &str, home: &PathBuf) -> Result<BatchResult, String> {
+    ensure_not_safe_mode(home)?;
+    let global_skill_path = find_global_skill(&global_skill_roots_with_home(home), skill_name)
+        .ok_or_else(|| format!("Skill '{}' not found in global skills", skill_name))?;
+    Ok(unlink_skill_from_agents(skill_name, home, false, Some(&global_skill_path)))
+}
+
+/// Reports what [`unlink_skill_from_all_with_home`] would do, with the same classification,
+/// without removing anything.
+pub fn unlink_skill_from_all_dry_run_with_home(skill_name: &str, home: &PathBuf) -> BatchResult {
+    unlink_skill_from_agents(skill_name, home, true, None)
+}
+
+/// Reports what [`unlink_skill_from_all_verified_with_home`] would do, with the same
+/// classification of foreign links, without removing anything.
+pub fn unlink_skill_from_all_verified_dry_run_with_home(
+    skill_name: &str,
+    home: &PathBuf,
+) -> Result<BatchResult, String> {
+    let global_skill_path = find_global_skill(&global_skill_roots_with_home(home), skill_name)
+        .ok_or_else(|| format!("Skill '{}' not found in global skills", skill_name))?;
+    Ok(unlink_skill_from_agents(skill_name, home, true, Some(&global_skill_path)))
+}
+
+/// Removes, or with `dry_run` only classifies, every agent's link to a skill.
+/// With `expected_target`, links that do not resolve to it are reported as failed.
+fn unlink_skill_from_agents(
+    skill_name: &str,
+    home: &PathBuf,
+    dry_run: bool,
+    expected_target: Option<&PathBuf>,
+) -> BatchResult {
+    let agents = detect_agents_with_home(home);
+    
+    let mut success: Vec<String> = Vec::new();
+    let mut failed: Vec<FailedOperation> = Vec::new();
+    let mut skipped: Vec<FailedOperation> = Vec::new();
+    
+    for agent in agents {
+        let id = &agent.id;
+        let agent_skill_path = home.join(&agent.path).join(skill_name);
+        
+        // Check if symlink exists at agent's skills directory
+        match fs::symlink_metadata(&agent_skill_path) {
+            Ok(metadata) => {
+                let is_link = metadata.file_type().is_symlink()
+                    || (agent.link_layout != LinkLayout::Directory && is_contents_link(&agent_skill_path));
+                if let Some(target) = expected_target.filter(|_| is_link) {
+                    let layout = if metadata.file_type().is_symlink() { LinkLayout::Directory } else { agent.link_layout };
+                    if !link_resolves_to(&agent_skill_path, target, layout) {
+                        let points_to = fs::read_link(&agent_skill_path)
+                            .ok()
+                            .or_else(|| contents_link_source(&agent_skill_path, agent.link_layout))
+                            .map(|path| path.to_string_lossy().to_string())
+                            .unwrap_or_default();
+                        failed.push(FailedOperation {
+                            agent_id: id.to_string(),
+                            error: format!("Link points to '{}', not the global skill; left in place", points_to),
+                        });
+                        continue;
+                    }
+                }
+                if metadata.file_type().is_symlink() {
+                    // Symlink exists, try to remove it
+                    let removed = if dry_run { Ok(()) } else { link::remove_skill_link(&agent_skill_path) };
+                    match removed {
+                        Ok(_) => {
+                            success.push(id.to_string());
+                        }
+                        Err(e) => {
+                            failed.push(FailedOperation {
+                                agent_id: id.to_string(),
+                                error: format!("Failed to remove symlink: {}", e),
+                            });
+                        }
+                    }
+                } else if agent.link_layout != LinkLayout::Directory && is_contents_link(&agent_skill_path) {
+                    let removed = if dry_run { Ok(()) } else { remove_contents_link(&agent_skill_path) };
+                    match removed {
+                        Ok(_) => success.push(id.to_string()),
+                        Err(e) => failed.push(FailedOperation {
+                            agent_id: id.to_string(),
+                            error: format!("Failed to remove linked directory: {}", e),
+                        }),
+                    }
+                } else {
+                    // If it exists but is not a symlink, we don't touch it (not our symlink)
+                    let kind = if metadata.file_type().is_dir() { "Local directory" } else { "Regular file" };
+                    skipped.push(FailedOperation {
+                        agent_id: id.to_string(),
+                        error: format!("{} at skill path was left in place", kind),
+                    });
+                }
+            }
+            Err(_) => {
+                // Path doesn't exist or can't be accessed - nothing to unlink
+                // This is not a failure, just means there's no symlink to remove
+            }
+        }
+    }
+    
+    BatchResult { success, failed, skipped, ..Default::default() }
+}
+
+/// Lists the agents `unlink_skill_from_all` would remove a link from, with a confirmation token.
+pub fn unlink_skill_from_all_preview_with_home(skill_name: &str, home: &PathBuf) -> confirm::ConfirmPreview {
+    let affected = detect_agents_with_home(home)
+        .into_iter()
+        .filter(|agent| {
+            let path = home.join(&agent.path).join(skill_name);
+            let is_symlink = fs::symlink_metadata(&path).map(|m| m.file_type().is_symlink()).unwrap_or(false);
+            is_symlink || (agent.link_layout != LinkLayout::Directory && is_contents_link(&path))
+        })
+        .map(|agent| agent.id)
+        .collect();
+    confirm::ConfirmPreview::new("unlink_skill_from_all", skill_name, affected)
+}
+
+#[tauri::command]
+fn unlink_skill_from_all_preview(skill_name: String) -> confirm::ConfirmPreview {
+    let home = get_home_dir();
+    unlink_skill_from_all_preview_with_home(&skill_name, &home)
+}
+
+/// Unlinks a skill from all agents, only if `confirm_token` matches the current preview.
+pub fn unlink_skill_from_all_confirmed_with_home(
+    skill_name: &str,
+    confirm_token: Option<&str>,
+    home: &PathBuf,
+) -> Result<BatchResult, String> {
+    unlink_skill_from_all_preview_with_home(skill_name, home).verify(confirm_token)?;
+    unlink_skill_from_all_with_home(skill_name, home)
+}
+
+#[tauri::command]
+fn unlink_skill_from_all(
+    skill_name: String,
+    confirm_token: Option<String>,
+    dry_run: Option<bool>,
+    verify_target: Option<bool>,
+) -> Result<BatchResult, String> {
+    let home = get_home_dir();
+    let verify_target = verify_target.unwrap_or(false);
+    if dry_run.unwrap_or(false) {
+        if verify_target {
+            return unlink_skill_from_all_verified_dry_run_with_home(&skill_name, &home);
+        }
+        return Ok(unlink_skill_from_all_dry_run_with_home(&skill_name, &home));
+    }
+    if verify_target {
+        unlink_skill_from_all_preview_with_home(&skill_name, &home).verify(confirm_token.as_deref())?;
+        return unlink_skill_from_all_verified_with_home(&skill_name, &home);
+    }
+    unlink_skill_from_all_confirmed_with_home(&skill_name, confirm_token.as_deref(), &home)
+}
+
+/// Order in which an agent's skills are returned
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    /// Alphabetical by directory name
+    #[default]
+    Name,
+    /// The agent's `.order` file first, then unlisted skills alphabetically
+    Custom,
+}
+
+/// File in an agent's skills directory listing skill names in display order, one per line
+pub const AGENT_ORDER_FILE: &str = ".order";
+
+/// Suffix of an agent-local directory holding a SKILL.md that overrides a symlinked skill's metadata
+const OVERRIDE_SUFFIX: &str = ".override";
+
+/// Gets detailed skill information for a specific agent.
+/// This includes both global skills and local-only skills in the agent's directory.
+pub fn get_agent_detail_with_home(agent_id: &str, home: &PathBuf) -> Result<AgentDetailData, String> {
+    get_agent_detail_with_roots(agent_id, home, &global_skill_roots_with_home(home))
+}
+
+/// Gets detailed skill information for an agent, treating every given root as global.
+pub fn get_agent_detail_with_roots(agent_id: &str, home: &PathBuf, roots: &[PathBuf]) -> Result<AgentDetailData, String> {
+    let agents = detect_agents_with_home(home);
+    let agent = agents.into_iter()
+        .find(|a| a.id == agent_id)
+        .ok_or_else(|| format!("Agent '{}' not found", agent_id))?;
+    
+    let agent_skills_path = home.join(&agent.path);
+    
+    // Collect global skill names (excluding hidden directories), keyed by their NFC form
+    let global_skill_names: std::collections::HashMap<String, String> = global_skill_names(roots)
+        .into_iter()
+        .map(|name| (normalized_skill_name(&name), name))
+        .collect();
+    
+    let mut skills: Vec<AgentSkill> = Vec::new();
+    let mut seen_skills: std::collections::HashSet<String> = std::collections::HashSet::new();
+    
+    // First, scan agent's skills directory for installed skills (symlinks and local)
+    if agent.detected {
+        for (name, entry_path) in agent_skill_entries(&agent_skills_path, agent.nested_scan) {
+            // Skip hidden directories and per-agent overrides
+            if name.starts_with('.') || name.ends_with(OVERRIDE_SUFFIX) {
+                continue;
+            }
+            
+            let normalized_name = normalized_skill_name(&name);
+            if seen_skills.contains(&normalized_name) {
+                continue;
+            }
+            
+            if let Ok(metadata) = fs::symlink_metadata(&entry_path) {
+                let file_type = metadata.file_type();
+                
+                if file_type.is_symlink() || file_type.is_dir() {
+                    let (status, source_path, skill_metadata) = match resolve_skill_entry(&entry_path, &name, agent.link_layout, roots) {
+                        // Symlinks report their raw target, local directories their own path
+                        Ok(resolved) => (
+                            resolved.status,
+                            resolved.link_target.unwrap_or_else(|| entry_path.to_string_lossy().to_string()),
+                            resolved.metadata,
+                        ),
+                        // Broken symlink - still listed, with fallback metadata
+                        Err(_) => (
+                            AgentSkillStatus::BrokenSymlink,
+                            fs::read_link(&entry_path)
+                                .map(|p| p.to_string_lossy().to_string())
+                                .unwrap_or_else(|_| "unknown".to_string()),
+                            SkillMetadata {
+                                name: name.clone(),
+                                description: "No description available".to_string(),
+                                ..Default::default()
+                            },
+                        ),
+                    };
+                    
+                    // Symlinked skills can have their displayed metadata overridden for this agent
+                    let override_dir = agent_skills_path.join(format!("{}{}", name, OVERRIDE_SUFFIX));
+                    let has_override = status == AgentSkillStatus::Symlink && override_dir.join("SKILL.md").is_file();
+                    let skill_metadata = if has_override {
+                        load_skill_metadata(&override_dir, &name)
+                    } else {
+                        skill_metadata
+                    };
+
+                    skills.push(AgentSkill {
+                        name: name.clone(),
+                        metadata: skill_metadata,
+                        status,
+                        source_path: Some(source_path),
+                        in_global: global_skill_names.contains_key(&normalized_name),
+                        has_override,
+                    });
+                    seen_skills.insert(normalized_name);
+                }
+            }
+        }
+    }
+    
+    // Then, add global skills that are not installed
+    for (normalized_name, global_name) in &global_skill_names {
+        if !seen_skills.contains(normalized_name) {
+            let global_skill_path = find_global_skill(roots, global_name).unwrap_or_default();
+            let skill_metadata = load_skill_metadata(&global_skill_path, global_name);
+            
+            skills.push(AgentSkill {
+                name: global_name.clone(),
+                metadata: skill_metadata,
+                status: AgentSkillStatus::NotInstalled,
+                source_path: None,
+                in_global: true,
+                has_override: false,
+            });
+        }
+    }
+    
+    // Sort skills by name
+    skills.sort_by(|a, b| a.name.cmp(&b.name));
+    
+    let global_dir_exists = roots.iter().any(|root| root.is_dir());
+    Ok(AgentDetailData { agent, skills, global_dir_exists })
+}
+
+/// Gets detailed skill information for an agent, with skills in the requested order.
+pub fn get_agent_detail_in_order_with_home(agent_id: &str, order: SortOrder, home: &PathBuf) -> Result<AgentDetailData, String> {
+    let mut detail = get_agent_detail_with_home(agent_id, home)?;
+    if order == SortOrder::Custom {
+        let custom = get_agent_skill_order_with_home(agent_id, home)?;
+        // Skills are already alphabetical, so a stable sort keeps unlisted ones that way
+        detail.skills.sort_by_key(|skill| custom.iter().position(|name| *name == skill.name).unwrap_or(usize::MAX));
+    }
+    Ok(detail)
+}
+
+#[tauri::command]
+fn get_agent_detail(agent_id: String, order: Option<SortOrder>) -> Result<AgentDetailData, String> {
+    let home = get_home_dir();
+    get_agent_detail_in_order_with_home(&agent_id, order.unwrap_or_default(), &home)
+}
+
+/// Reads an agent's custom skill order. A missing `.order` file is an empty order.
+pub fn get_agent_skill_order_with_home(agent_id: &str, home: &PathBuf) -> Result<Vec<String>, String> {
+    let agent = detect_agents_with_home(home)
+        .into_iter()
+        .find(|a| a.id == agent_id)
+        .ok_or_else(|| format!("Agent '{}' not found", agent_id))?;
+    let content = match fs::read_to_string(home.join(&agent.path).join(AGENT_ORDER_FILE)) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("Failed to read skill order: {}", e)),
+    };
+    Ok(content.lines().map(str::trim).filter(|line| !line.is_empty()).map(String::from).collect())
+}
+
+#[tauri::command]
+fn get_agent_skill_order(agent_id: String) -> Result<Vec<String>, String> {
+    let home = get_home_dir();
+    get_agent_skill_order_with_home(&agent_id, &home)
+}
+
+/// Writes an agent's custom skill order to its `.order` file.
+pub fn set_agent_skill_order_with_home(agent_id: &str, order: &[String], home: &PathBuf) -> Result<(), String> {
+    ensure_not_safe_mode(home)?;
+    let agent = detect_agents_with_home(home)
+        .into_iter()
+        .find(|a| a.id == agent_id)
+        .ok_or_else(|| format!("Agent '{}' not found", agent_id))?;
+    if let Some(name) = order.iter().find(|name| name.trim().is_empty() || name.contains(['\n', '\r'])) {
+        return Err(format!("Invalid skill name '{}'", name));
+    }
+    let agent_dir = home.join(&agent.path);
+    fs::create_dir_all(&agent_dir).map_err(|e| format!("Failed to create agent directory: {}", e))?;
+    let content: String = order.iter().map(|name| format!("{}\n", name)).collect();
+    fs::write(agent_dir.join(AGENT_ORDER_FILE), content).map_err(|e| format!("Failed to write skill order: {}", e))
+}
+
+#[tauri::command]
+fn set_agent_skill_order(agent_id: String, order: Vec<String>) -> Result<(), String> {
+    let home = get_home_dir();
+    set_agent_skill_order_with_home(&agent_id, &order, &home)
+}
+
+/// Lists every tool requested by a skill installed (symlink or local) in an agent, with
+/// the number of installed skills requesting it. Sorted by tool name.
+pub fn agent_tool_surface_with_home(agent_id: &str, home: &PathBuf) -> Result<Vec<(String, usize)>, String> {
+    let detail = get_agent_detail_with_home(agent_id, home)?;
+    let mut counts: std::collections::BTreeMap<String, usize> = std::collections::BTreeMap::new();
+    for skill in detail.skills.iter().filter(|s| s.status != AgentSkillStatus::NotInstalled) {
+        let tools: std::collections::BTreeSet<&String> = skill.metadata.allowed_tools.iter().collect();
+        for tool in tools {
+            *counts.entry(tool.clone()).or_default() += 1;
+        }
+    }
+    Ok(counts.into_iter().collect())
+}
+
+#[tauri::command]
+fn agent_tool_surface(agent_id: String) -> Result<Vec<(String, usize)>, String> {
+    let home = get_home_dir();
+    agent_tool_surface_with_home(&agent_id, &home)
+}
+
+/// Returns the allowed-tools of a global skill that an agent does not support, per the
+/// agent's `supported_tools` config. A scoped tool such as `Bash(git:*)` matches on
+/// the name before the parenthesis. Agents without a configured list support every tool.
+pub fn check_skill_agent_compatibility_with_home(skill_name: &str, agent_id: &str, home: &PathBuf) -> Result<Vec<String>, String> {
+    if !detect_agents_with_home(home).iter().any(|a| a.id == agent_id) {
+        return Err(format!("Agent '{}' not found", agent_id));
+    }
+    let skill_dir = find_global_skill(&global_skill_roots_with_home(home), skill_name)
+        .ok_or_else(|| format!("Skill '{}' not found in global skills", skill_name))?;
+    let config = config::load_config_with_home(home);
+    let Some(supported) = config.supported_tools.get(agent_id) else {
+        return Ok(Vec::new());
+    };
+
+    Ok(load_skill_metadata(&skill_dir, skill_name)
+        .allowed_tools
+        .into_iter()
+        .filter(|tool| {
+            let base = tool.split('(').next().unwrap_or(tool).trim();
+            !supported.iter().any(|s| s == tool || s == base)
+        })
+        .collect())
+}
+
+#[tauri::command]
+fn check_skill_agent_compatibility(skill_name: String, agent_id: String) -> Result<Vec<String>, String> {
+    let home = get_home_dir();
+    check_skill_agent_compatibility_with_home(&skill_name, &agent_id, &home)
+}
+
+/// Links a global skill into one agent after checking tool compatibility. With `refuse`,
+/// a skill using unsupported tools is not linked; otherwise it is linked and the
+/// unsupported tools are returned as warnings.
+pub fn link_skill_checked_with_home(agent_id: &str, skill_name: &str, refuse: bool, home: &PathBuf) -> Result<Vec<String>, String> {
+    let unsupported = check_skill_agent_compatibility_with_home(skill_name, agent_id, home)?;
+    if refuse && !unsupported.is_empty() {
+        return Err(format!("Agent '{}' does not support: {}", agent_id, unsupported.join(", ")));
+    }
+    toggle_skill_with_home(agent_id, skill_name, true, home)?;
+    Ok(unsupported)
+}
+
+#[tauri::command]
+fn link_skill_checked(agent_id: String, skill_name: String, refuse: Option<bool>) -> Result<Vec<String>, String> {
+    let home = get_home_dir();
+    link_skill_checked_with_home(&agent_id, &skill_name, refuse.unwrap_or(false), &home)
+}
+
+/// Resolves an installed skill entry in an agent's directory, following symlinks.
+/// Fails if the entry is missing, is not a directory, or is a broken symlink.
+/// A directory of symlinks only counts as a link for agents using a contents `layout`.
+fn resolve_skill_entry(
+    entry_path: &std::path::Path,
+    skill_name: &str,
+    layout: LinkLayout,
+    roots: &[PathBuf],
+) -> Result<ResolvedSkill, String> {
+    let metadata = fs::symlink_metadata(entry_path)
+        .map_err(|_| format!("Skill '{}' is not installed", skill_name))?;
+
+    // A directory of symlinks into one skill counts as a link to that skill
+    let contents_source = contents_link_source(&entry_path.to_path_buf(), layout);
+    let (status, link_target) = if metadata.file_type().is_symlink() {
+        let target = fs::read_link(entry_path).map_err(|e| format!("Failed to read symlink: {}", e))?;
+        (AgentSkillStatus::Symlink, Some(target.to_string_lossy().to_string()))
+    } else if let Some(source) = &contents_source {
+        (AgentSkillStatus::Symlink, Some(source.to_string_lossy().to_string()))
+    } else if metadata.file_type().is_dir() {
+        (AgentSkillStatus::Local, None)
+    } else {
+        return Err(format!("'{}' is not a skill directory", skill_name));
+    };
+
+    let resolved = fs::canonicalize(contents_source.as_deref().unwrap_or(entry_path)).map_err(|_| {
+        format!("Broken symlink: target '{}' does not exist", link_target.clone().unwrap_or_default())
+    })?;
+    let in_global_library = roots
+        .iter()
+        .filter_map(|root| fs::canonicalize(root).ok())
+        .any(|root| resolved.starts_with(root));
+
+    Ok(ResolvedSkill {
+        status,
+        link_target,
+        resolved_path: resolved.to_string_lossy().to_string(),
+        in_global_library,
+        metadata: load_skill_metadata(&resolved, skill_name),
+    })
+}
+
+/// Resolves the effective skill an agent uses for a skill name, following symlinks.
+pub fn resolve_agent_skill_with_home(agent_id: &str, skill_name: &str, home: &PathBuf) -> Result<ResolvedSkill, String> {
+    let agent = detect_agents_with_home(home)
+        .into_iter()
+        .find(|a| a.id == agent_id)
+        .ok_or_else(|| format!("Agent '{}' not found", agent_id))?;
+    let roots = global_skill_roots_with_home(home);
+    resolve_skill_entry(&home.join(&agent.path).join(skill_name), skill_name, agent.link_layout, &roots)
+}
+
+#[tauri::command]
+fn resolve_agent_skill(agent_id: String, skill_name: String) -> Result<ResolvedSkill, String> {
+    let home = get_home_dir();
+    resolve_agent_skill_with_home(&agent_id, &skill_name, &home)
+}
+
+/// Deletes a local skill directory (not a symlink) from an agent's skills directory.
+pub fn delete_local_skill_with_home(agent_id: &str, skill_name: &str, home: &PathBuf) -> Result<(), SkillError> {
+    let agents = detect_agents_with_home(home);
+    let agent = agents
+        .iter()
+        .find(|a| a.id == agent_id)
+        .ok_or_else(|| SkillError::AgentNotFound(agent_id.to_string()))?;
+    
+    ensure_not_safe_mode(home).map_err(|_| SkillError::SafeMode)?;
+    let skill_path = home.join(&agent.path).join(skill_name);
+    
+    // Check if it exists and is NOT a symlink
+    match fs::symlink_metadata(&skill_path) {
+        Ok(metadata) => {
+            if metadata.file_type().is_symlink() {
+                return Err(SkillError::IsSymlink(skill_name.to_string(), LocalSkillAction::Delete));
+            }
+            if metadata.file_type().is_dir() {
+                fs::remove_dir_all(&skill_path)
+                    .map_err(|e| SkillError::Io(format!("Failed to delete directory: {}", e)))?;
+                Ok(())
+            } else {
+                Err(SkillError::NotADirectory(skill_name.to_string()))
+            }
+        }
+        Err(_) => Err(SkillError::SkillNotFound(skill_name.to_string())),
+    }
+}
+
+#[tauri::command]
+fn delete_local_skill(agent_id: String, skill_name: String) -> Result<(), SkillError> {
+    let home = get_home_dir();
+    delete_local_skill_with_home(&agent_id, &skill_name, &home)
+}
+
+/// Recursively copies a directory and its contents
+fn copy_dir_recursive(src: &PathBuf, dst: &PathBuf) -> Result<(), String> {
+    fs::create_dir_all(dst).map_err(|e| format!("Failed to create directory: {}", e))?;
+    
+    let entries = fs::read_dir(src).map_err(|e| format!("Failed to read directory: {}", e))?;
+    
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("Failed to read entry: {}", e))?;
+        let src_path = entry.path();
+        let dst_path = dst.join(entry.file_name());
+        
+        if src_path.is_dir() {
+            copy_dir_recursive(&src_path, &dst_path)?;
+        } else {
+            fs::copy(&src_path, &dst_path).map_err(|e| format!("Failed to copy file: {}", e))?;
+        }
+    }
+    
+    Ok(())
+}
+
+/// Moves a directory, falling back to copy and delete when a rename is not possible
+/// (e.g. across filesystems).
+fn move_dir(src: &PathBuf, dst: &PathBuf) -> Result<(), String> {
+    if fs::rename(src, dst).is_ok() {
+        return Ok(());
+    }
+    copy_dir_recursive(src, dst)?;
+    fs::remove_dir_all(src).map_err(|e| format!("Failed to remove original directory: {}", e))
+}
+
+/// Lists the files in a skill directory recursively, skipping hidden entries.
+/// Symlinks to files are listed; symlinked directories are not followed, so a link
+/// loop inside a skill cannot recurse forever.
+/// Returns `(relative path, absolute path)` pairs sorted by relative path, using `/` separators.
+fn list_skill_files(dir: &std::path::Path) -> Result<Vec<(String, PathBuf)>, String> {
+    fn walk(dir: &std::path::Path, prefix: &str, files: &mut Vec<(String, PathBuf)>) -> Result<(), String> {
+        let entries = fs::read_dir(dir).map_err(|e| format!("Failed to read directory: {}", e))?;
+        for entry in entries {
+            let entry = entry.map_err(|e| format!("Failed to read entry: {}", e))?;
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            if file_name.starts_with('.') {
+                continue;
+            }
+            let relative = format!("{}{}", prefix, file_name);
+            let path = entry.path();
+            let metadata = fs::symlink_metadata(&path).map_err(|e| format!("Failed to read metadata: {}", e))?;
+            if metadata.is_dir() {
+                walk(&path, &format!("{}/", relative), files)?;
+            } else if path.is_file() {
+                files.push((relative, path));
+            }
+        }
+        Ok(())
+    }
+
+    let mut files = Vec::new();
+    walk(dir, "", &mut files)?;
+    files.sort();
+    Ok(files)
+}
+
+/// Uploads a local skill from an agent's directory to the highest-priority global root.
+pub fn upload_to_global_with_home(agent_id: &str, skill_name: &str, home: &PathBuf) -> Result<(), SkillError> {
+    upload_to_global_root_with_home(agent_id, skill_name, None, home)
+}
+
+/// Uploads a local skill from an agent's directory into `root`, which must be one of the
+/// configured global roots (as configured, e.g. `~/team/skills`). Without a root, the
+/// highest-priority root is used.
+pub fn upload_to_global_root_with_home(
+    agent_id: &str,
+    skill_name: &str,
+    root: Option<&str>,
+    home: &PathBuf,
+) -> Result<(), SkillError> {
+    let agents = detect_agents_with_home(home);
+    let agent = agents
+        .iter()
+        .find(|a| a.id == agent_id)
+        .ok_or_else(|| SkillError::AgentNotFound(agent_id.to_string()))?;
+    
+    ensure_not_safe_mode(home).map_err(|_| SkillError::SafeMode)?;
+    let local_skill_path = home.join(&agent.path).join(skill_name);
+    let roots = global_skill_roots_with_home(home);
+    let global_skills_dir = match root {
+        Some(root) => {
+            let wanted = config::resolve_path(home, root);
+            roots.iter().find(|r| **r == wanted).cloned().ok_or_else(|| SkillError::RootNotFound(root.to_string()))?
+        }
+        None => roots[0].clone(),
+    };
+    let global_skill_path = global_skills_dir.join(skill_name);
+    
+    // Check if local skill exists and is NOT a symlink
+    match fs::symlink_metadata(&local_skill_path) {
+        Ok(metadata) => {
+            if metadata.file_type().is_symlink() {
+                return Err(SkillError::IsSymlink(skill_name.to_string(), LocalSkillAction::Upload));
+            }
+            if !metadata.file_type().is_dir() {
+                return Err(SkillError::NotADirectory(skill_name.to_string()));
+            }
+        }
+        Err(_) => return Err(SkillError::SkillNotFound(skill_name.to_string())),
+    }
+    
+    // Check if global skill already exists in any root
+    if find_global_skill(&roots, skill_name).is_some() {
+        return Err(SkillError::AlreadyExists(skill_name.to_string()));
+    }
+    
+    // Create global skills directory if it doesn't exist
+    if !global_skills_dir.exists() {
+        fs::create_dir_all(&global_skills_dir)
+            .map_err(|e| SkillError::Io(format!("Failed to create global skills directory: {}", e)))?;
+    }
+    
+    // Copy the skill directory to global
+    copy_dir_recursive(&local_skill_path, &global_skill_path).map_err(SkillError::Io)?;
+    
+    Ok(())
+}
+
+#[tauri::command]
+fn upload_to_global(agent_id: String, skill_name: String, root: Option<String>) -> Result<(), SkillError> {
+    let home = get_home_dir();
+    upload_to_global_root_with_home(&agent_id, &skill_name, root.as_deref(), &home)
+}
+
+/// Copies a local skill from one agent's skills directory to another's, without
+/// promoting it to the global skills directory.
+pub fn copy_local_skill_with_home(
+    from_agent: &str,
+    to_agent: &str,
+    skill_name: &str,
+    home: &PathBuf,
+) -> Result<(), SkillError> {
+    ensure_not_safe_mode(home).map_err(|_| SkillError::SafeMode)?;
+    validate_skill_name(skill_name).map_err(|_| SkillError::InvalidName(skill_name.to_string()))?;
+    let agents = detect_agents_with_home(home);
+    let agent_dir = |agent_id: &str| {
+        agents
+            .iter()
+            .find(|a| a.id == agent_id)
+            .map(|agent| home.join(&agent.path))
+            .ok_or_else(|| SkillError::AgentNotFound(agent_id.to_string()))
+    };
+    let source = agent_dir(from_agent)?.join(skill_name);
+    let destination_dir = agent_dir(to_agent)?;
+    let destination = destination_dir.join(skill_name);
+
+    // Only a local directory can be copied; a symlink belongs to the global skill
+    match fs::symlink_metadata(&source) {
+        Ok(metadata) if metadata.file_type().is_symlink() => {
+            return Err(SkillError::IsSymlink(skill_name.to_string(), LocalSkillAction::Copy))
+        }
+        Ok(metadata) if !metadata.file_type().is_dir() => return Err(SkillError::NotADirectory(skill_name.to_string())),
+        Ok(_) => {}
+        Err(_) => return Err(SkillError::SkillNotFound(skill_name.to_string())),
+    }
+    if fs::symlink_metadata(&destination).is_ok() {
+        return Err(SkillError::AlreadyExistsForAgent { skill_name: skill_name.to_string(), agent_id: to_agent.to_string() });
+    }
+
+    fs::create_dir_all(&destination_dir)
+        .map_err(|e| SkillError::Io(format!("Failed to create skills directory: {}", e)))?;
+    copy_dir_recursive(&source, &destination).map_err(SkillError::Io)
+}
+
+#[tauri::command]
+fn copy_local_skill(from_agent: String, to_agent: String, skill_name: String) -> Result<(), SkillError> {
+    let home = get_home_dir();
+    copy_local_skill_with_home(&from_agent, &to_agent, &skill_name, &home)
+}
+
+/// Incremental 64-bit FNV-1a hash, identical on every platform and run.
+pub(crate) struct Fnv1a(pub(crate) u64);
+
+impl Fnv1a {
+    const OFFSET: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    pub(crate) fn feed(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    /// Feeds a length prefix and then the bytes, keeping field boundaries unambiguous.
+    fn feed_field(&mut self, bytes: &[u8]) {
+        self.feed(&(bytes.len() as u64).to_le_bytes());
+        self.feed(bytes);
+    }
+}
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(Self::OFFSET)
+    }
+}
+
+/// Computes a content hash of a skill directory (FNV-1a over sorted relative paths and file bytes).
+/// Two directories with identical visible files hash the same regardless of timestamps.
+fn skill_content_hash(dir: &std::path::Path) -> Result<u64, String> {
+    let mut hash = Fnv1a::default();
+    for (relative, path) in list_skill_files(dir)? {
+        let content = fs::read(&path).map_err(|e| format!("Failed to read {}: {}", relative, e))?;
+        hash.feed_field(relative.as_bytes());
+        hash.feed_field(&content);
+    }
+
+    Ok(hash.0)
+}
+
+/// Whether two directories hold exactly the same entries, hidden ones included: the same
+/// relative paths, byte-identical files, and symlinks with the same targets. Symlinks are
+/// compared, never followed.
+fn dirs_identical(a: &std::path::Path, b: &std::path::Path) -> Result<bool, String> {
+    fn entries(dir: &std::path::Path) -> Result<Vec<(std::ffi::OsString, fs::Metadata, PathBuf)>, String> {
+        let mut entries = fs::read_dir(dir)
+            .map_err(|e| format!("Failed to read directory: {}", e))?
+            .map(|entry| {
+                let entry = entry.map_err(|e| format!("Failed to read entry: {}", e))?;
+                let metadata = fs::symlink_metadata(entry.path()).map_err(|e| format!("Failed to read entry: {}", e))?;
+                Ok((entry.file_name(), metadata, entry.path()))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        entries.sort_by(|x, y| x.0.cmp(&y.0));
+        Ok(entries)
+    }
+
+    let (left, right) = (entries(a)?, entries(b)?);
+    if left.len() != right.len() {
+        return Ok(false);
+    }
+    for ((left_name, left_meta, left_path), (right_name, right_meta, right_path)) in left.iter().zip(&right) {
+        let (left_type, right_type) = (left_meta.file_type(), right_meta.file_type());
+        if left_name != right_name
+            || left_type.is_symlink() != right_type.is_symlink()
+            || left_type.is_dir() != right_type.is_dir()
+        {
+            return Ok(false);
+        }
+        let same = if left_type.is_symlink() {
+            fs::read_link(left_path).ok() == fs::read_link(right_path).ok()
+        } else if left_type.is_dir() {
+            dirs_identical(left_path, right_path)?
+        } else {
+            let read = |path: &PathBuf| fs::read(path).map_err(|e| format!("Failed to read {}: {}", path.to_string_lossy(), e));
+            left_meta.len() == right_meta.len() && read(left_path)? == read(right_path)?
+        };
+        if !same {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Replaces a local skill directory with a symlink to `target`. The directory is moved
+/// aside until the link exists, and moved back if the link cannot be created.
+fn replace_dir_with_link(dir: &PathBuf, target: &PathBuf) -> Result<(), String> {
+    let file_name = dir.file_name().unwrap_or_default().to_string_lossy().to_string();
+    let aside = dir.with_file_name(format!(".{}.replaced-{}", file_name, std::process::id()));
+    fs::rename(dir, &aside).map_err(|e| format!("Failed to move local copy aside: {}", e))?;
+    if let Err(e) = create_symlink(target, dir) {
+        let _ = fs::rename(&aside, dir);
+        return Err(format!("Failed to create symlink: {}", e));
+    }
+    fs::remove_dir_all(&aside).map_err(|e| format!("Failed to remove local copy: {}", e))
+}
+
+/// Converts every skill in an agent that matches a global skill to the given link style.
+///
+/// Symlinks to the global skill become local copies, and local copies whose content
+/// matches the global skill become symlinks. Only copies this function created (as
+/// recorded in the agent's link metadata) are turned back into symlinks; a user's own
+/// local skills, copies that diverge from global and symlinks pointing elsewhere are
+/// reported as skipped so no local work is lost. Results are keyed by skill name.
+pub fn normalize_agent_links_with_home(agent_id: &str, target_style: LinkStyle, home: &PathBuf) -> Result<BatchResult, String> {
+    ensure_not_safe_mode(home)?;
+    let agent = detect_agents_with_home(home)
+        .into_iter()
+        .find(|a| a.id == agent_id)
+        .ok_or("Agent not found")?;
+    let agent_skills_dir = home.join(&agent.path);
+    let roots = global_skill_roots_with_home(home);
+    let mut link_meta = link_meta::read_link_meta(&agent_skills_dir);
+
+    let mut success: Vec<String> = Vec::new();
+    let mut failed: Vec<FailedOperation> = Vec::new();
+    let mut skipped: Vec<FailedOperation> = Vec::new();
+
+    let mut entries: Vec<(String, PathBuf)> = match fs::read_dir(&agent_skills_dir) {
+        Ok(entries) => entries
+            .flatten()
+            .map(|entry| (entry.file_name().to_string_lossy().to_string(), entry.path()))
+            .filter(|(name, _)| !name.starts_with('.'))
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    entries.sort();
+
+    for (skill_name, skill_path) in entries {
+        let Some(global_path) = find_global_skill(&roots, &skill_name) else {
+            continue;
+        };
+        let Ok(metadata) = fs::symlink_metadata(&skill_path) else {
+            continue;
+        };
+        let skip = |reason: &str| FailedOperation { agent_id: skill_name.clone(), error: reason.to_string() };
+        let fail = |e: String| FailedOperation { agent_id: skill_name.clone(), error: e };
+
+        if metadata.file_type().is_symlink() {
+            if target_style == LinkStyle::Symlink {
+                continue;
+            }
+            let points_to_global = match (fs::canonicalize(&skill_path), fs::canonicalize(&global_path)) {
+                (Ok(target), Ok(global)) => target == global,
+                _ => false,
+            };
+            if !points_to_global {
+                skipped.push(skip("Symlink does not point to the global skill"));
+                continue;
+            }
+            let result = link::remove_skill_link(&skill_path)
+                .map_err(|e| format!("Failed to remove symlink: {}", e))
+                .and_then(|_| copy_dir_recursive(&global_path, &skill_path));
+            match result {
+                Ok(_) => {
+                    link_meta.copies.insert(skill_name.clone());
+                    success.push(skill_name.clone());
+                }
+                Err(e) => failed.push(fail(e)),
+            }
+        } else if metadata.file_type().is_dir() {
+            if target_style == LinkStyle::Copy {
+                continue;
+            }
+            if !link_meta.copies.contains(&skill_name) {
+                skipped.push(skip("Local skill was not created by the manager"));
+                continue;
+            }
+            // Every file counts, hidden ones included: a local-only `.env` must not be lost
+            match dirs_identical(&skill_path, &global_path) {
+                Ok(true) => {}
+                Ok(false) => {
+                    skipped.push(skip("Local content differs from the global skill"));
+                    continue;
+                }
+                Err(e) => {
+                    failed.push(fail(e));
+                    continue;
+                }
+            }
+            match replace_dir_with_link(&skill_path, &global_path) {
+                Ok(_) => {
+                    link_meta.copies.remove(&skill_name);
+                    success.push(skill_name.clone());
+                }
+                Err(e) => failed.push(fail(e)),
+            }
+        }
+    }
+    link_meta::write_link_meta(&agent_skills_dir, &link_meta)?;
+
+    Ok(BatchResult { success, failed, skipped, ..Default::default() })
+}
+
+#[tauri::command]
+fn normalize_agent_links(agent_id: String, target_style: LinkStyle) -> Result<BatchResult, String> {
+    let home = get_home_dir();
+    normalize_agent_links_with_home(&agent_id, target_style, &home)
+}
+
+/// Finds agent symlinks that resolve into a skill directory.
+/// Returns `(agent_id, link_path, path_inside_skill)` for each, so links can be recreated
+/// after the skill moves.
+fn links_into_skill(skill_dir: &PathBuf, home: &PathBuf) -> Result<Vec<(String, PathBuf, PathBuf)>, String> {
+    let canonical = fs::canonicalize(skill_dir).map_err(|e| format!("Failed to resolve skill path: {}", e))?;
+    let mut links: Vec<(String, PathBuf, PathBuf)> = Vec::new();
+    for agent in detect_agents_with_home(home).into_iter().filter(|a| a.detected) {
+        let Ok(entries) = fs::read_dir(home.join(&agent.path)) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let is_symlink = fs::symlink_metadata(entry.path()).map(|m| m.file_type().is_symlink()).unwrap_or(false);
+            if !is_symlink {
+                continue;
+            }
+            if let Ok(resolved) = fs::canonicalize(entry.path()) {
+                if let Ok(inner) = resolved.strip_prefix(&canonical) {
+                    links.push((agent.id.clone(), entry.path(), inner.to_path_buf()));
+                }
+            }
+        }
+    }
+    Ok(links)
+}
+
+/// Moves a skill from one global root to another and repoints agent symlinks that
+/// pointed into the old location. Both roots must be configured global roots; the move
+/// is refused if `to_root` already contains a skill with the same name.
+/// Results are keyed by agent ID, one entry per relinked agent.
+pub fn move_skill_between_roots_with_home(
+    skill_name: &str,
+    from_root: &str,
+    to_root: &str,
+    home: &PathBuf,
+) -> Result<BatchResult, String> {
+    ensure_not_safe_mode(home)?;
+    let roots = global_skill_roots_with_home(home);
+    let from_root = config::resolve_path(home, from_root);
+    let to_root = config::resolve_path(home, to_root);
+    for root in [&from_root, &to_root] {
+        if !roots.contains(root) {
+            return Err(format!("'{}' is not a configured global root", root.to_string_lossy()));
+        }
+    }
+
+    let src = from_root.join(skill_name);
+    let dst = to_root.join(skill_name);
+    if !src.is_dir() {
+        return Err(format!("Skill '{}' not found in {}", skill_name, from_root.to_string_lossy()));
+    }
+    if fs::symlink_metadata(&dst).is_ok() {
+        return Err(format!("Skill '{}' already exists in {}", skill_name, to_root.to_string_lossy()));
+    }
+
+    // Find links into the skill before it moves
+    let links = links_into_skill(&src, home)?;
+
+    fs::create_dir_all(&to_root).map_err(|e| format!("Failed to create global skills directory: {}", e))?;
+    move_dir(&src, &dst)?;
+
+    let mut success: Vec<String> = Vec::new();
+    let mut failed: Vec<FailedOperation> = Vec::new();
+    for (agent_id, link, inner) in links {
+        let result = link::remove_skill_link(&link)
+            .map_err(|e| format!("Failed to remove symlink: {}", e))
+            .and_then(|_| create_symlink(dst.join(&inner), &link).map_err(|e| format!("Failed to create symlink: {}", e)));
+        match result {
+            Ok(_) => success.push(agent_id),
+            Err(e) => failed.push(FailedOperation { agent_id, error: e }),
+        }
+    }
+
+    Ok(BatchResult { success, failed, ..Default::default() })
+}
+
+#[tauri::command]
+fn move_skill_between_roots(skill_name: String, from_root: String, to_root: String) -> Result<BatchResult, String> {
+    let home = get_home_dir();
+    move_skill_between_roots_with_home(&skill_name, &from_root, &to_root, &home)
+}
+
+/// Swaps the directory names of two global skills and renames agent symlinks to match,
+/// so every agent keeps the same content under the corrected names.
+///
+/// The swap goes through a hidden temporary directory. If a step fails, completed
+/// renames are rolled back so both original directories are present again.
+/// Results are keyed by agent ID, one entry per agent whose links were updated.
+pub fn swap_skill_names_with_home(name_a: &str, name_b: &str, home: &PathBuf) -> Result<BatchResult, String> {
+    ensure_not_safe_mode(home)?;
+    if name_a == name_b {
+        return Err("Cannot swap a skill with itself".to_string());
+    }
+    let roots = global_skill_roots_with_home(home);
+    let path_a = find_global_skill(&roots, name_a).ok_or_else(|| format!("Skill '{}' not found in global skills", name_a))?;
+    let path_b = find_global_skill(&roots, name_b).ok_or_else(|| format!("Skill '{}' not found in global skills", name_b))?;
+    let root_a = path_a.parent().map(|p| p.to_path_buf()).unwrap_or_default();
+    let root_b = path_b.parent().map(|p| p.to_path_buf()).unwrap_or_default();
+    // Each skill keeps its root and takes the other's name
+    let new_a = root_a.join(name_b);
+    let new_b = root_b.join(name_a);
+    for target in [&new_a, &new_b] {
+        if *target != path_a && *target != path_b && fs::symlink_metadata(target).is_ok() {
+            return Err(format!("Cannot swap: {} already exists", target.to_string_lossy()));
+        }
+    }
+
+    // Only links named after either skill are renamed; other links just follow the content
+    let links_a = links_into_skill(&path_a, home)?;
+    let links_b = links_into_skill(&path_b, home)?;
+
+    let temp = root_a.join(format!(".swap-{}-{}", name_a, name_b));
+    move_dir(&path_a, &temp)?;
+    if let Err(e) = move_dir(&path_b, &new_b) {
+        let _ = move_dir(&temp, &path_a);
+        return Err(e);
+    }
+    if let Err(e) = move_dir(&temp, &new_a) {
+        let _ = move_dir(&new_b, &path_b);
+        let _ = move_dir(&temp, &path_a);
+        return Err(e);
+    }
+
+    let mut success: Vec<String> = Vec::new();
+    let mut failed: Vec<FailedOperation> = Vec::new();
+
+    // Each replacement is created under a temporary name first, so a failure leaves the old link in place
+    let mut staged: Vec<(String, PathBuf, PathBuf, PathBuf)> = Vec::new();
+    for (links, new_dir, old_name, new_name) in [(links_a, &new_a, name_a, name_b), (links_b, &new_b, name_b, name_a)] {
+        for (agent_id, link, inner) in links {
+            let renamed = link.file_name().map(|n| n == old_name).unwrap_or(false);
+            let new_link = if renamed { link.with_file_name(new_name) } else { link.clone() };
+            let link_name = link.file_name().unwrap_or_default().to_string_lossy().to_string();
+            let temp_link = link.with_file_name(format!(".{}.swap-{}", link_name, std::process::id()));
+            match create_symlink(new_dir.join(inner), &temp_link) {
+                Ok(_) => staged.push((agent_id, link, new_link, temp_link)),
+                Err(e) => failed.push(FailedOperation { agent_id, error: format!("Failed to create symlink: {}", e) }),
+            }
+        }
+    }
+
+    // Old links are removed only after every replacement exists, so new names never collide with old ones
+    let mut replaced: Vec<(String, PathBuf, PathBuf, PathBuf, PathBuf)> = Vec::new();
+    for (agent_id, link, new_link, temp_link) in staged {
+        let old_target = match fs::read_link(&link) {
+            Ok(target) => target,
+            Err(e) => {
+                let _ = link::remove_skill_link(&temp_link);
+                failed.push(FailedOperation { agent_id, error: format!("Failed to read symlink: {}", e) });
+                continue;
+            }
+        };
+        match link::remove_skill_link(&link) {
+            Ok(_) => replaced.push((agent_id, link, old_target, new_link, temp_link)),
+            Err(e) => {
+                let _ = link::remove_skill_link(&temp_link);
+                failed.push(FailedOperation { agent_id, error: format!("Failed to remove old symlink: {}", e) });
+            }
+        }
+    }
+
+    for (agent_id, link, old_target, new_link, temp_link) in replaced {
+        // A name still taken belongs to a link that could not be removed; it is not overwritten
+        let result = if fs::symlink_metadata(&new_link).is_ok() {
+            Err(format!("{} already exists", new_link.to_string_lossy()))
+        } else {
+            fs::rename(&temp_link, &new_link).map_err(|e| format!("Failed to rename symlink: {}", e))
+        };
+        match result {
+            Ok(_) => {
+                if !success.contains(&agent_id) {
+                    success.push(agent_id);
+                }
+            }
+            Err(error) => {
+                let _ = link::remove_skill_link(&temp_link);
+                let _ = create_symlink(&old_target, &link);
+                failed.push(FailedOperation { agent_id, error });
+            }
+        }
+    }
+    success.retain(|id| !failed.iter().any(|f| &f.agent_id == id));
+
+    Ok(BatchResult { success, failed, ..Default::default() })
+}
+
+#[tauri::command]
+fn swap_skill_names(name_a: String, name_b: String) -> Result<BatchResult, String> {
+    let home = get_home_dir();
+    swap_skill_names_with_home(&name_a, &name_b, &home)
+}
+
+/// Lists the agents whose symlinks would be removed if a global skill were deleted.
+/// Only links resolving into this skill count; same-named local directories or links
+/// to other copies are ignored.
+pub fn delete_global_skill_preview_with_home(skill_name: &str, home: &PathBuf) -> Vec<String> {
+    let Some(skill_dir) = find_global_skill(&global_skill_roots_with_home(home), skill_name) else {
+        return Vec::new();
+    };
+    let mut agents: Vec<String> = Vec::new();
+    for (agent_id, _, _) in links_into_skill(&skill_dir, home).unwrap_or_default() {
+        if !agents.contains(&agent_id) {
+            agents.push(agent_id);
+        }
+    }
+    agents
+}
+
+#[tauri::command]
+fn delete_global_skill_preview(skill_name: String) -> Vec<String> {
+    let home = get_home_dir();
+    delete_global_skill_preview_with_home(&skill_name, &home)
+}
+
+/// Folds content-identical duplicates into a canonical global skill: agent symlinks into
+/// each duplicate are repointed at the canonical skill, then the duplicate is deleted.
+///
+/// A duplicate that is not byte-identical to the canonical skill, hidden files included,
+/// or whose links could not all be moved, is refused and left alone. Relinks are keyed
+/// by `agent_id/link_name` and deletions by skill name.
+pub fn consolidate_duplicates_with_home(
+    canonical_name: &str,
+    duplicate_names: Vec<String>,
+    home: &PathBuf,
+) -> Result<BatchResult, String> {
+    ensure_not_safe_mode(home)?;
+    let roots = global_skill_roots_with_home(home);
+    let canonical_dir = find_global_skill(&roots, canonical_name)
+        .ok_or_else(|| format!("Skill '{}' not found in global skills", canonical_name))?;
+
+    let mut success: Vec<String> = Vec::new();
+    let mut failed: Vec<FailedOperation> = Vec::new();
+    for duplicate in duplicate_names {
+        let fail = |error: String| FailedOperation { agent_id: duplicate.clone(), error };
+        if duplicate == canonical_name {
+            failed.push(fail("Cannot consolidate a skill into itself".to_string()));
+            continue;
+        }
+        let Some(duplicate_dir) = find_global_skill(&roots, &duplicate) else {
+            failed.push(fail(format!("Skill '{}' not found in global skills", duplicate)));
+            continue;
+        };
+        match dirs_identical(&duplicate_dir, &canonical_dir) {
+            Ok(true) => {}
+            Ok(false) => {
+                failed.push(fail(format!("Skill '{}' is not identical to '{}'", duplicate, canonical_name)));
+                continue;
+            }
+            Err(e) => {
+                failed.push(fail(e));
+                continue;
+            }
+        }
+        let links = match links_into_skill(&duplicate_dir, home) {
+            Ok(links) => links,
+            Err(e) => {
+                failed.push(fail(e));
+                continue;
+            }
+        };
+
+        let mut all_relinked = true;
+        for (agent_id, link, inner) in links {
+            let key = format!("{}/{}", agent_id, link.file_name().unwrap_or_default().to_string_lossy());
+            // The link is replaced in one step, so it never dangles or disappears
+            let result = link::replace_skill_link(&canonical_dir.join(&inner), &link)
+                .map_err(|e| format!("Failed to relink: {}", e));
+            match result {
+                Ok(_) => success.push(key),
+                Err(e) => {
+                    all_relinked = false;
+                    failed.push(FailedOperation { agent_id: key, error: e });
+                }
+            }
+        }
+
+        // Never delete a duplicate that agents may still point at
+        if !all_relinked {
+            failed.push(fail("Not deleted because some links could not be moved".to_string()));
+            continue;
+        }
+        match fs::remove_dir_all(&duplicate_dir) {
+            Ok(_) => success.push(duplicate),
+            Err(e) => failed.push(fail(format!("Failed to delete duplicate: {}", e))),
+        }
+    }
+
+    Ok(BatchResult { success, failed, ..Default::default() })
+}
+
+#[tauri::command]
+fn consolidate_duplicates(
+    canonical_name: String,
+    duplicate_names: Vec<String>,
+    auto_backup: Option<bool>,
+) -> Result<BatchResult, String> {
+    let home = get_home_dir();
+    backup::backup_if_requested(auto_backup, &home)?;
+    consolidate_duplicates_with_home(&canonical_name, duplicate_names, &home)
+}
+
+/// Whether a directory contains no files at any depth. Hidden files count as files.
+fn dir_has_no_files(dir: &std::path::Path) -> bool {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return false;
+    };
+    entries.flatten().all(|entry| match entry.file_type() {
+        Ok(file_type) if file_type.is_dir() => dir_has_no_files(&entry.path()),
+        _ => false,
+    })
+}
+
+/// Lists global skills whose directory contains no files, even in subdirectories. Sorted.
+pub fn find_empty_skills_with_home(home: &PathBuf) -> Vec<String> {
+    let roots = global_skill_roots_with_home(home);
+    let mut names: Vec<String> = global_skill_names(&roots)
+        .into_iter()
+        .filter(|name| find_global_skill(&roots, name).map(|dir| dir_has_no_files(&dir)).unwrap_or(false))
+        .collect();
+    names.sort();
+    names
+}
+
+#[tauri::command]
+fn find_empty_skills() -> Vec<String> {
+    let home = get_home_dir();
+    find_empty_skills_with_home(&home)
+}
+
+/// Deletes every empty global skill and the agent symlinks pointing at it.
+/// Removed links are keyed by `agent_id/link_name` and deleted skills by skill name.
+pub fn remove_empty_skills_with_home(home: &PathBuf) -> Result<BatchResult, String> {
+    ensure_not_safe_mode(home)?;
+    let roots = global_skill_roots_with_home(home);
+
+    let mut success: Vec<String> = Vec::new();
+    let mut failed: Vec<FailedOperation> = Vec::new();
+    for name in find_empty_skills_with_home(home) {
+        let Some(skill_dir) = find_global_skill(&roots, &name) else {
+            continue;
+        };
+        for (agent_id, link, _) in links_into_skill(&skill_dir, home).unwrap_or_default() {
+            let key = format!("{}/{}", agent_id, link.file_name().unwrap_or_default().to_string_lossy());
+            match link::remove_skill_link(&link) {
+                Ok(_) => success.push(key),
+                Err(e) => failed.push(FailedOperation { agent_id: key, error: format!("Failed to remove symlink: {}", e) }),
+            }
+        }
+        match fs::remove_dir_all(&skill_dir) {
+            Ok(_) => success.push(name),
+            Err(e) => failed.push(FailedOperation { agent_id: name, error: format!("Failed to delete skill: {}", e) }),
+        }
+    }
+
+    Ok(BatchResult { success, failed, ..Default::default() })
+}
+
+#[tauri::command]
+fn remove_empty_skills() -> Result<BatchResult, String> {
+    let home = get_home_dir();
+    remove_empty_skills_with_home(&home)
+}
+
+/// Applies an edit to the frontmatter of a skill's SKILL.md, preserving the markdown body
+/// and the file's encoding.
+/// The file is only rewritten if the edit changed the metadata; returns whether it did.
+fn edit_skill_md<F: FnOnce(&mut SkillMetadata)>(skill_dir: &std::path::Path, edit: F) -> Result<bool, String> {
+    let skill_md_path = skill_dir.join("SKILL.md");
+    let bytes = fs::read(&skill_md_path).map_err(|e| format!("Failed to read SKILL.md: {}", e))?;
+    let (content, _warning) = decode_skill_md(&bytes);
+
+    // Edit the raw parsed metadata so display fallbacks are never written back
+    let original = parse_skill_md(&content);
+    let mut metadata = original.clone();
+    edit(&mut metadata);
+    if metadata == original {
+        return Ok(false);
+    }
+
+    let updated = update_frontmatter(&content, &metadata)?;
+    write_skill_md_atomically(&skill_md_path, &updated, SkillMdEncoding::detect(&bytes))?;
+    Ok(true)
+}
+
+/// Replaces a SKILL.md through a temporary file in the same directory, so a reader never
+/// sees a partially written file. The content is written in the given encoding.
+fn write_skill_md_atomically(
+    skill_md_path: &std::path::Path,
+    content: &str,
+    encoding: SkillMdEncoding,
+) -> Result<(), String> {
+    let bytes = encoding.encode(content)?;
+    let temp_path = skill_md_path.with_file_name(format!(".SKILL.md.tmp-{}", std::process::id()));
+    fs::write(&temp_path, bytes).map_err(|e| format!("Failed to write SKILL.md: {}", e))?;
+    fs::rename(&temp_path, skill_md_path).map_err(|e| {
+        let _ = fs::remove_file(&temp_path);
+        format!("Failed to replace SKILL.md: {}", e)
+    })
+}
+
+/// Saves metadata edited in the UI to a global skill's SKILL.md, preserving the markdown
+/// body. A heading-format file gains a frontmatter block above its content.
+///
+/// `metadata` is the displayed metadata with the user's edits, so only fields that differ
+/// from what [`load_skill_metadata`] shows are written; display fallbacks and values
+/// inherited from `.defaults.yaml` are never persisted.
+pub fn update_skill_metadata_with_home(skill_name: &str, metadata: &SkillMetadata, home: &PathBuf) -> Result<(), String> {
+    ensure_not_safe_mode(home)?;
+    let roots = global_skill_roots_with_home(home);
+    let skill_dir = find_global_skill(&roots, skill_name)
+        .ok_or_else(|| format!("Skill '{}' not found in global skills", skill_name))?;
+    let displayed = load_skill_metadata(&skill_dir, skill_name);
+    let patch = PartialSkillMetadata::changes(&displayed, metadata);
+    edit_skill_md(&skill_dir, |current| current.apply_patch(&patch))?;
+    Ok(())
+}
+
+#[tauri::command]
+fn update_skill_metadata(skill_name: String, metadata: SkillMetadata) -> Result<(), String> {
+    let home = get_home_dir();
+    update_skill_metadata_with_home(&skill_name, &metadata, &home)
+}
+
+/// Updates only the metadata fields present in `patch`, preserving every other field
+/// and the markdown body of the skill's SKILL.md.
+pub fn patch_skill_metadata_with_home(skill_name: &str, patch: &PartialSkillMetadata, home: &PathBuf) -> Result<(), String> {
+    ensure_not_safe_mode(home)?;
+    let roots = global_skill_roots_with_home(home);
+    let skill_dir = find_global_skill(&roots, skill_name)
+        .ok_or_else(|| format!("Skill '{}' not found in global skills", skill_name))?;
+    edit_skill_md(&skill_dir, |metadata| metadata.apply_patch(patch))?;
+    Ok(())
+}
+
+#[tauri::command]
+fn patch_skill_metadata(skill_name: String, patch: PartialSkillMetadata) -> Result<(), String> {
+    let home = get_home_dir();
+    patch_skill_metadata_with_home(&skill_name, &patch, &home)
+}
+
+/// Returns a global skill's `repository` URL. Only http(s) URLs are accepted, so a
+/// SKILL.md cannot make the app open local files or other URL schemes.
+pub fn skill_repository_url_with_home(skill_name: &str, home: &PathBuf) -> Result<String, String> {
+    let roots = global_skill_roots_with_home(home);
+    let skill_dir = find_global_skill(&roots, skill_name)
+        .ok_or_else(|| format!("Skill '{}' not found in global skills", skill_name))?;
+    let (content, _warning) = read_skill_md(&skill_dir.join("SKILL.md"))
+        .map_err(|e| format!("Failed to read SKILL.md: {}", e))?;
+    let repository = parse_skill_md(&content)
+        .repository
+        .ok_or_else(|| format!("Skill '{}' has no repository", skill_name))?;
+
+    let lower = repository.to_ascii_lowercase();
+    let host = lower.strip_prefix("https://").or_else(|| lower.strip_prefix("http://")).unwrap_or_default();
+    if host.is_empty() || host.starts_with('/') || repository.chars().any(char::is_whitespace) {
+        return Err(format!("Repository '{}' is not an http(s) URL", repository));
+    }
+    Ok(repository)
+}
+
+#[tauri::command]
+fn open_skill_repository(skill_name: String) -> Result<(), String> {
+    let home = get_home_dir();
+    let url = skill_repository_url_with_home(&skill_name, &home)?;
+    tauri_plugin_opener::open_url(&url, None::<&str>).map_err(|e| format!("Failed to open repository: {}", e))
+}
+
+/// Writes a minimal SKILL.md for every global skill that lacks one, using the directory
+/// name as `name` and a placeholder description. Skills that already have a SKILL.md are
+/// reported as skipped. Results are keyed by skill name.
+pub fn scaffold_missing_skill_md_with_home(home: &PathBuf) -> Result<BatchResult, String> {
+    ensure_not_safe_mode(home)?;
+    let roots = global_skill_roots_with_home(home);
+    let mut skill_names: Vec<String> = global_skill_names(&roots).into_iter().collect();
+    skill_names.sort();
+
+    let mut success: Vec<String> = Vec::new();
+    let mut failed: Vec<FailedOperation> = Vec::new();
+    let mut skipped: Vec<FailedOperation> = Vec::new();
+
+    for skill_name in skill_names {
+        let Some(skill_dir) = find_global_skill(&roots, &skill_name) else {
+            continue;
+        };
+        let skill_md_path = skill_dir.join("SKILL.md");
+        if skill_md_path.exists() {
+            skipped.push(FailedOperation { agent_id: skill_name, error: "SKILL.md already exists".to_string() });
+            continue;
+        }
+        let metadata = SkillMetadata {
+            name: skill_name.clone(),
+            description: "No description available".to_string(),
+            ..Default::default()
+        };
+        match fs::write(&skill_md_path, skill_parser::format_skill_md(&metadata)) {
+            Ok(_) => success.push(skill_name),
+            Err(e) => failed.push(FailedOperation { agent_id: skill_name, error: format!("Failed to write SKILL.md: {}", e) }),
+        }
+    }
+
+    Ok(BatchResult { success, failed, skipped, ..Default::default() })
+}
+
+#[tauri::command]
+fn scaffold_missing_skill_md() -> Result<BatchResult, String> {
+    let home = get_home_dir();
+    scaffold_missing_skill_md_with_home(&home)
+}
+
+/// Sets a frontmatter field on many global skills at once, preserving bodies and other fields.
+///
+/// Applies to the named skills, or every global skill when `skill_names` is `None`.
+/// Results are keyed by skill name. Unknown field names are rejected before any file is touched.
+pub fn bulk_update_field_with_home(
+    field: &str,
+    value: &str,
+    skill_names: Option<Vec<String>>,
+    home: &PathBuf,
+) -> Result<BatchResult, String> {
+    ensure_not_safe_mode(home)?;
+    SkillMetadata::default().set_field(field, value)?;
+
+    let roots = global_skill_roots_with_home(home);
+    let skill_names = skill_names.unwrap_or_else(|| {
+        let mut names: Vec<String> = global_skill_names(&roots).into_iter().collect();
+        names.sort();
+        names
+    });
+
+    let mut success: Vec<String> = Vec::new();
+    let mut failed: Vec<FailedOperation> = Vec::new();
+
+    for skill_name in skill_names {
+        let result = find_global_skill(&roots, &skill_name)
+            .ok_or_else(|| "Skill not found in global skills".to_string())
+            .and_then(|skill_dir| {
+                edit_skill_md(&skill_dir, |metadata| {
+                    // Field name was validated above
+                    let _ = metadata.set_field(field, value);
+                })
+            });
+        match result {
+            Ok(_) => success.push(skill_name),
+            Err(e) => failed.push(FailedOperation { agent_id: skill_name, error: e }),
+        }
+    }
+
+    Ok(BatchResult { success, failed, ..Default::default() })
+}
+
+#[tauri::command]
+fn bulk_update_field(field: String, value: String, skill_names: Option<Vec<String>>) -> Result<BatchResult, String> {
+    let home = get_home_dir();
+    bulk_update_field_with_home(&field, &value, skill_names, &home)
+}
+
+/// Renames a tool in the `allowed-tools` of every global skill, preserving bodies.
+/// If a skill already lists `new`, the renamed entry is dropped rather than duplicated.
+/// Results are keyed by skill name and only include skills that referenced `old`.
+pub fn rename_tool_with_home(old: &str, new: &str, home: &PathBuf) -> Result<BatchResult, String> {
+    ensure_not_safe_mode(home)?;
+    let roots = global_skill_roots_with_home(home);
+    let mut skill_names: Vec<String> = global_skill_names(&roots).into_iter().collect();
+    skill_names.sort();
+
+    let mut success: Vec<String> = Vec::new();
+    let mut failed: Vec<FailedOperation> = Vec::new();
+
+    for skill_name in skill_names {
+        let Some(skill_dir) = find_global_skill(&roots, &skill_name) else {
+            continue;
+        };
+        if old == new || !skill_dir.join("SKILL.md").exists() {
+            continue;
+        }
+        let result = edit_skill_md(&skill_dir, |metadata| {
+            let mut tools: Vec<String> = Vec::new();
+            for tool in metadata.allowed_tools.drain(..) {
+                let tool = if tool == old { new.to_string() } else { tool };
+                if tool != new || !tools.contains(&tool) {
+                    tools.push(tool);
+                }
+            }
+            metadata.allowed_tools = tools;
+        });
+        match result {
+            Ok(true) => success.push(skill_name),
+            Ok(false) => {}
+            Err(e) => failed.push(FailedOperation { agent_id: skill_name, error: e }),
+        }
+    }
+
+    Ok(BatchResult { success, failed, ..Default::default() })
+}
+
+#[tauri::command]
+fn rename_tool(old: String, new: String) -> Result<BatchResult, String> {
+    let home = get_home_dir();
+    rename_tool_with_home(&old, &new, &home)
+}
+
+/// Converts every line break in `content` to the given style. Lone `\r` characters are kept.
+fn convert_line_endings(content: &str, style: LineEnding) -> String {
+    let mut converted = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                if style == LineEnding::Crlf {
+                    converted.push('\r');
+                }
+                converted.push('\n');
+            }
+            _ => converted.push(c),
+        }
+    }
+    converted
+}
+
+/// Rewrites the SKILL.md of every global skill to use the given line endings.
+/// Files already in that style, and skills without a SKILL.md, are reported as skipped
+/// and left untouched. Results are keyed by skill name.
+pub fn normalize_line_endings_with_home(home: &PathBuf, style: LineEnding) -> Result<BatchResult, String> {
+    ensure_not_safe_mode(home)?;
+    let roots = global_skill_roots_with_home(home);
+    let mut skill_names: Vec<String> = global_skill_names(&roots).into_iter().collect();
+    skill_names.sort();
+
+    let mut success: Vec<String> = Vec::new();
+    let mut failed: Vec<FailedOperation> = Vec::new();
+    let mut skipped: Vec<FailedOperation> = Vec::new();
+
+    for skill_name in skill_names {
+        let Some(skill_dir) = find_global_skill(&roots, &skill_name) else {
+            continue;
+        };
+        let skill_md_path = skill_dir.join("SKILL.md");
+        if !skill_md_path.is_file() {
+            skipped.push(FailedOperation { agent_id: skill_name, error: "No SKILL.md".to_string() });
+            continue;
+        }
+        let bytes = match fs::read(&skill_md_path) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                failed.push(FailedOperation { agent_id: skill_name, error: format!("Failed to read SKILL.md: {}", e) });
+                continue;
+            }
+        };
+        // Line breaks are converted in the decoded text, then written in the file's own encoding
+        let encoding = SkillMdEncoding::detect(&bytes);
+        let (content, _warning) = decode_skill_md(&bytes);
+        let converted = convert_line_endings(&content, style);
+        if converted == content {
+            skipped.push(FailedOperation { agent_id: skill_name, error: "Already uses the requested line endings".to_string() });
+            continue;
+        }
+        match encoding.encode(&converted).and_then(|encoded| {
+            fs::write(&skill_md_path, encoded).map_err(|e| format!("Failed to write SKILL.md: {}", e))
+        }) {
+            Ok(_) => success.push(skill_name),
+            Err(error) => failed.push(FailedOperation { agent_id: skill_name, error }),
+        }
+    }
+
+    Ok(BatchResult { success, failed, skipped, ..Default::default() })
+}
+
+#[tauri::command]
+fn normalize_line_endings(style: Option<LineEnding>) -> Result<BatchResult, String> {
+    let home = get_home_dir();
+    normalize_line_endings_with_home(&home, style.unwrap_or_default())
+}
+
+/// Checks a global skill for non-fatal problems.
+pub fn validate_skill_with_home(skill_name: &str, home: &PathBuf) -> Result<Vec<ValidationWarning>, String> {
+    let roots = global_skill_roots_with_home(home);
+    let skill_dir = find_global_skill(&roots, skill_name)
+        .ok_or_else(|| format!("Skill '{}' not found in global skills", skill_name))?;
+
+    let max_description_len = config::load_config_with_home(home)
+        .max_description_len
+        .unwrap_or(skill_parser::DEFAULT_MAX_DESCRIPTION_LEN);
+
+    let mut warnings = Vec::new();
+    let skill_md_path = skill_dir.join("SKILL.md");
+    if skill_md_path.exists() {
+        let (content, warning) = read_skill_md(&skill_md_path)
+            .map_err(|e| format!("Failed to read SKILL.md: {}", e))?;
+        warnings.extend(warning);
+        let metadata = parse_skill_md(&content);
+        warnings.extend(skill_parser::validate_skill_md(&content));
+        warnings.extend(skill_parser::check_description_length(&metadata.description, max_description_len));
+        warnings.extend(skill_parser::check_tool_disclaimers(&content, &metadata.allowed_tools));
+    } else {
+        warnings.push(ValidationWarning::MissingSkillMd);
+    }
+
+    Ok(warnings)
+}
+
+#[tauri::command]
+fn validate_skill(skill_name: String) -> Result<Vec<ValidationWarning>, String> {
+    let home = get_home_dir();
+    validate_skill_with_home(&skill_name, &home)
+}
+
+/// Validation results of every global skill
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct LibraryValidation {
+    pub skills: std::collections::BTreeMap<String, Vec<ValidationWarning>>, // Warnings by skill name, empty when clean
+    pub is_clean: bool, // No skill has any warning
+}
+
+/// Validates every global skill. A skill that cannot be read is reported with a
+/// `ReadError` warning instead of stopping the run.
+pub fn validate_library_with_home(home: &PathBuf) -> LibraryValidation {
+    let skills: std::collections::BTreeMap<String, Vec<ValidationWarning>> = global_skill_names(&global_skill_roots_with_home(home))
+        .into_iter()
+        .map(|name| {
+            let warnings = validate_skill_with_home(&name, home)
+                .unwrap_or_else(|message| vec![ValidationWarning::ReadError { message }]);
+            (name, warnings)
+        })
+        .collect();
+    let is_clean = skills.values().all(|warnings| warnings.is_empty());
+    LibraryValidation { skills, is_clean }
+}
+
+#[tauri::command]
+fn validate_library() -> LibraryValidation {
+    let home = get_home_dir();
+    validate_library_with_home(&home)
+}
+
+/// Lists global skills, sorted, whose SKILL.md would change if its frontmatter were
+/// rewritten from the parsed metadata: parsing and reformatting with the body preserved
+/// does not reproduce the file. Heading-format skills are always listed, since rewriting
+/// adds a frontmatter block. Skills without a readable SKILL.md are ignored.
+pub fn check_roundtrip_stability_with_home(home: &PathBuf) -> Vec<String> {
+    let roots = global_skill_roots_with_home(home);
+    let mut unstable: Vec<String> = global_skill_names(&roots)
+        .into_iter()
+        .filter(|name| {
+            let Some(skill_dir) = find_global_skill(&roots, name) else {
+                return false;
+            };
+            let Ok((content, _warning)) = read_skill_md(&skill_dir.join("SKILL.md")) else {
+                return false;
+            };
+            // Frontmatter that does not parse is never rewritten, so it cannot change
+            update_frontmatter(&content, &parse_skill_md(&content)).is_ok_and(|updated| updated != content)
+        })
+        .collect();
+    unstable.sort();
+    unstable
+}
+
+#[tauri::command]
+fn check_roundtrip_stability() -> Vec<String> {
+    let home = get_home_dir();
+    check_roundtrip_stability_with_home(&home)
+}
+
+/// Rewrites one global skill's SKILL.md with canonical frontmatter built from its parsed
+/// metadata, so a heading-format skill gains a frontmatter block. The markdown body is
+/// preserved, and the file is only written if the result differs; returns whether it was.
+pub fn convert_skill_to_frontmatter_with_home(skill_name: &str, home: &PathBuf) -> Result<bool, String> {
+    ensure_not_safe_mode(home)?;
+    let skill_dir = find_global_skill(&global_skill_roots_with_home(home), skill_name)
+        .ok_or_else(|| format!("Skill '{}' not found in global skills", skill_name))?;
+    let skill_md_path = skill_dir.join("SKILL.md");
+    let bytes = fs::read(&skill_md_path).map_err(|e| format!("Failed to read SKILL.md: {}", e))?;
+    let (content, _warning) = decode_skill_md(&bytes);
+
+    // A file that already has frontmatter is left as written
+    if skill_parser::split_frontmatter(&content).is_some() {
+        return Ok(false);
+    }
+    let converted = update_frontmatter(&content, &parse_skill_md(&content))?;
+    write_skill_md_atomically(&skill_md_path, &converted, SkillMdEncoding::detect(&bytes))?;
+    Ok(true)
+}
+
+#[tauri::command]
+fn convert_skill_to_frontmatter(skill_name: String) -> Result<bool, String> {
+    let home = get_home_dir();
+    convert_skill_to_frontmatter_with_home(&skill_name, &home)
+}
+
+/// Maximum edit distance at which an unknown tool is treated as a typo of a known one
+const TOOL_TYPO_DISTANCE: usize = 2;
+
+/// Computes the Levenshtein edit distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b_chars.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b_chars.len() + 1];
+        for (j, b_char) in b_chars.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b_chars.len()]
+}
+
+/// Finds the known tool closest to `tool`, if it is within the typo distance.
+/// Comparison is case-insensitive so `bash` suggests `Bash`.
+fn suggest_tool(tool: &str, known_tools: &[String]) -> Option<String> {
+    known_tools
+        .iter()
+        .map(|known| (edit_distance(&tool.to_lowercase(), &known.to_lowercase()), known))
+        .filter(|(distance, _)| *distance <= TOOL_TYPO_DISTANCE)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, known)| known.clone())
+}
+
+/// Checks a global skill's allowed tools against a registry of known tool names.
+///
+/// Returns one issue per tool that is not in the registry, with the closest known
+/// tool as a suggestion when the name looks like a typo. With `fix` set, tools that
+/// have a suggestion are replaced in SKILL.md (the markdown body is left untouched).
+pub fn validate_tools_with_home(
+    skill_name: &str,
+    known_tools: &[String],
+    fix: bool,
+    home: &PathBuf,
+) -> Result<Vec<ToolIssue>, String> {
+    if fix {
+        ensure_not_safe_mode(home)?;
+    }
+    let roots = global_skill_roots_with_home(home);
+    let skill_dir = find_global_skill(&roots, skill_name)
+        .ok_or_else(|| format!("Skill '{}' not found in global skills", skill_name))?;
+    // Only tools written in this SKILL.md can be fixed here, so inherited defaults are not checked
+    let (content, _warning) = read_skill_md(&skill_dir.join("SKILL.md"))
+        .map_err(|e| format!("Failed to read SKILL.md: {}", e))?;
+    let metadata = parse_skill_md(&content);
+
+    let mut issues: Vec<ToolIssue> = metadata
+        .allowed_tools
+        .iter()
+        .filter(|tool| !known_tools.contains(tool))
+        .map(|tool| ToolIssue {
+            tool: tool.clone(),
+            suggestion: suggest_tool(tool, known_tools),
+            fixed: false,
+        })
+        .collect();
+
+    if fix && issues.iter().any(|issue| issue.suggestion.is_some()) {
+        edit_skill_md(&skill_dir, |metadata| {
+            let mut tools: Vec<String> = Vec::new();
+            for tool in &metadata.allowed_tools {
+                let replacement = issues
+                    .iter()
+                    .find(|issue| &issue.tool == tool)
+                    .and_then(|issue| issue.suggestion.clone())
+                    .unwrap_or_else(|| tool.clone());
+                // A corrected typo may duplicate a tool that was already listed
+                if !tools.contains(&replacement) {
+                    tools.push(replacement);
+                }
+            }
+            metadata.allowed_tools = tools;
+        })?;
+        for issue in issues.iter_mut() {
+            issue.fixed = issue.suggestion.is_some();
+        }
+    }
+
+    Ok(issues)
+}
+
+#[tauri::command]
+fn validate_tools(skill_name: String, known_tools: Vec<String>, fix: bool) -> Result<Vec<ToolIssue>, String> {
+    let home = get_home_dir();
+    validate_tools_with_home(&skill_name, &known_tools, fix, &home)
+}
+
+/// Matches `text` against a glob where `*` matches any run of characters and `?` any
+/// single character. Matching ignores ASCII case.
+fn glob_matches(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.to_ascii_lowercase().chars().collect();
+    let text: Vec<char> = text.to_ascii_lowercase().chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // Position after the last `*` and the text position it was tried against
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p + 1, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = backtrack {
+            // Let the last `*` absorb one more character
+            p = star_p;
+            t = star_t + 1;
+            backtrack = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/// Finds global skills whose allowed-tools match any of the deny patterns (simple globs,
+/// see [`glob_matches`]). Returns each flagged skill with its denied tools, sorted by skill name.
+pub fn scan_skills_for_denied_tools_with_home(deny_patterns: &[String], home: &PathBuf) -> Vec<(String, Vec<String>)> {
+    let mut flagged: Vec<(String, Vec<String>)> = get_app_data_with_home(home)
+        .skills
+        .into_iter()
+        .filter_map(|skill| {
+            let denied: Vec<String> = skill
+                .metadata
+                .allowed_tools
+                .into_iter()
+                .filter(|tool| deny_patterns.iter().any(|pattern| glob_matches(pattern, tool)))
+                .collect();
+            (!denied.is_empty()).then_some((skill.name, denied))
+        })
+        .collect();
+    flagged.sort_by(|a, b| a.0.cmp(&b.0));
+    flagged
+}
+
+#[tauri::command]
+fn scan_skills_for_denied_tools(deny_patterns: Vec<String>) -> Vec<(String, Vec<String>)> {
+    let home = get_home_dir();
+    scan_skills_for_denied_tools_with_home(&deny_patterns, &home)
+}
+
+#[cfg_attr(mobile, tauri::mobile_entry_point)]
+pub fn run() {
+    // Resolve the environment override before any command runs
+    config::global_path_from_env();
+    tauri::Builder::default()
+        .plugin(tauri_plugin_opener::init())
+        .manage(watcher::AgentWatchers::default())
+        .invoke_handler(tauri::generate_handler![
+            get_app_data, 
+            get_app_data_streamed,
+            get_app_data_compact,
+            get_dashboard,
+            all_installed_skills,
+            recently_modified_skills,
+            available_skills_for_agent,
+            search_skills,
+            find_duplicate_skills,
+            find_metadata_name_collisions,
+            library_quality_report,
+            library_stats,
+            find_broken_symlinks,
+            toggle_skill, 
+            link_skill_to_all, 
+            link_skill_to_agents,
+            unlink_skill_from_all,
+            unlink_skill_from_all_preview,
+            get_agent_detail,
+            get_agent_skill_order,
+            set_agent_skill_order,
+            agent_tool_surface,
+            check_skill_agent_compatibility,
+            link_skill_checked,
+            ensure_agent_dirs,
+            resolve_agent_skill,
+            delete_local_skill,
+            upload_to_global,
+            copy_local_skill,
+            validate_skill,
+            validate_library,
+            check_roundtrip_stability,
+            convert_skill_to_frontmatter,
+            validate_tools,
+            scan_skills_for_denied_tools,
+            patch_skill_metadata,
+            update_skill_metadata,
+            open_skill_repository,
+            bulk_update_field,
+            rename_tool,
+            normalize_line_endings,
+            scaffold_missing_skill_md,
+            normalize_agent_links,
+            move_skill_between_roots,
+            swap_skill_names,
+            delete_global_skill_preview,
+            consolidate_duplicates,
+            find_empty_skills,
+            remove_empty_skills,
+            audit::audit_symlinks,
+            audit::find_dangling_skill_references,
+            backup::backup_library,
+            backup::restore_library,
+            dedupe::dedupe_skill_files,
+            export::export_skill_markdown,
+            export::export_link_map,
+            export::export_metadata_index,
+            git_import::import_skill_from_git,
+            link_manifest::apply_manifest,
+            link_manifest::apply_manifest_preview,
+            link_manifest::rollback_apply,
+            link_meta::list_manager_copies,
+            manifest::build_manifest,
+            permissions::fix_permissions,
+            quarantine::quarantine_skill,
+            quarantine::unquarantine_skill,
+            quarantine::list_quarantined,
+            recommended::missing_recommended_skills,
+            recommended::install_recommended,
+            repair::find_stale_absolute_links,
+            repair::fix_stale_absolute_links,
+            repair::flatten_symlink_chains,
+            repair::prune_links_to_path,
+            repair::relink_all_after_home_change,
+            skill_files::list_skill_files,
+            skill_files::read_skill_file,
+            snapshot::snapshot_library,
+            snapshot::diff_snapshot,
+            snapshot::library_fingerprint,
+            sync::sync_agent_to_global_preview,
+            sync::sync_all_agents_preview,
+            sync::sync_group_preview,
+            templates::list_templates,
+            templates::create_skill_from_template,
+            watcher::watch_agent,
+            watcher::unwatch_agent
+        ])
+        .run(tauri::generate_context!())
+        .expect("error while running tauri application");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    /// Helper function to create a temporary home directory for testing
+    fn create_temp_home() -> TempDir {
+        TempDir::new().expect("Failed to create temp directory")
     }
 
     /// Test that agent is marked as detected when directory exists
     /// 
-    /// **Validates: Requirements 3.2**
-    /// - 3.2: WHEN detecting agents, THE Skills_Manager SHALL check if each agent's skills directory exists
+    /// **Validates: Requirements 3.2**
+    /// - 3.2: WHEN detecting agents, THE Skills_Manager SHALL check if each agent's skills directory exists
+    #[test]
+    fn test_agent_detected_when_directory_exists() {
+        // Arrange: Create a temp home directory with a cursor skills directory
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        
+        // Create the cursor skills directory
+        let cursor_skills_path = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_skills_path).expect("Failed to create cursor skills directory");
+        
+        // Act: Detect agents
+        let agents = detect_agents_with_home(&home_path);
+        
+        // Assert: Cursor agent should be detected
+        let cursor_agent = agents.iter().find(|a| a.id == "cursor").expect("Cursor agent not found");
+        assert!(cursor_agent.detected, "Cursor agent should be detected when directory exists");
+        assert_eq!(cursor_agent.path, ".cursor/skills");
+        assert_eq!(cursor_agent.name, "Cursor");
+    }
+
+    /// Test that agent is marked as not detected when directory doesn't exist
+    /// 
+    /// **Validates: Requirements 3.3**
+    /// - 3.3: WHEN an agent's skills directory does not exist, THE Skills_Manager SHALL mark the agent as not detected
+    #[test]
+    fn test_agent_not_detected_when_directory_does_not_exist() {
+        // Arrange: Create an empty temp home directory (no agent directories)
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        
+        // Act: Detect agents
+        let agents = detect_agents_with_home(&home_path);
+        
+        // Assert: All agents should be not detected
+        for agent in &agents {
+            assert!(!agent.detected, "Agent {} should not be detected when directory doesn't exist", agent.id);
+        }
+        
+        // Verify we have all 27 agents
+        assert_eq!(agents.len(), 27, "Should have 27 agent definitions");
+    }
+
+    /// Test detection for multiple agents with mixed existence states
+    /// 
+    /// **Validates: Requirements 3.2, 3.3**
+    /// - 3.2: WHEN detecting agents, THE Skills_Manager SHALL check if each agent's skills directory exists
+    /// - 3.3: WHEN an agent's skills directory does not exist, THE Skills_Manager SHALL mark the agent as not detected
+    #[test]
+    fn test_mixed_agent_detection_states() {
+        // Arrange: Create a temp home directory with some agent directories
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        
+        // Create directories for specific agents
+        let agents_to_create = vec![
+            ".cursor/skills",           // cursor
+            ".claude/skills",           // claude-code
+            ".config/agents/skills",    // amp
+            ".gemini/skills",           // gemini-cli
+        ];
+        
+        for path in &agents_to_create {
+            let full_path = home_path.join(path);
+            fs::create_dir_all(&full_path).expect(&format!("Failed to create directory: {}", path));
+        }
+        
+        // Act: Detect agents
+        let agents = detect_agents_with_home(&home_path);
+        
+        // Assert: Check specific agents are detected
+        let detected_ids = vec!["cursor", "claude-code", "amp", "gemini-cli"];
+        let not_detected_ids = vec!["cline", "codex", "neovate", "windsurf"];
+        
+        for id in detected_ids {
+            let agent = agents.iter().find(|a| a.id == id).expect(&format!("Agent {} not found", id));
+            assert!(agent.detected, "Agent {} should be detected", id);
+        }
+        
+        for id in not_detected_ids {
+            let agent = agents.iter().find(|a| a.id == id).expect(&format!("Agent {} not found", id));
+            assert!(!agent.detected, "Agent {} should not be detected", id);
+        }
+        
+        // Verify total count
+        assert_eq!(agents.len(), 27, "Should have 27 agent definitions");
+        
+        // Count detected vs not detected
+        let detected_count = agents.iter().filter(|a| a.detected).count();
+        let not_detected_count = agents.iter().filter(|a| !a.detected).count();
+        
+        assert_eq!(detected_count, 4, "Should have 4 detected agents");
+        assert_eq!(not_detected_count, 23, "Should have 23 not detected agents");
+    }
+
+    /// Test that agent definitions contain correct data
+    #[test]
+    fn test_agent_definitions_are_complete() {
+        let definitions = get_agent_definition_list();
+        
+        // Verify we have all 27 agents
+        assert_eq!(definitions.len(), 27, "Should have 27 agent definitions");
+        
+        // Verify each definition has non-empty values
+        for (id, name, path) in &definitions {
+            assert!(!id.is_empty(), "Agent ID should not be empty");
+            assert!(!name.is_empty(), "Agent name should not be empty");
+            assert!(!path.is_empty(), "Agent path should not be empty");
+        }
+        
+        // Verify specific agents exist with correct paths
+        let expected_agents = vec![
+            ("cursor", "Cursor", ".cursor/skills"),
+            ("claude-code", "Claude Code", ".claude/skills"),
+            ("windsurf", "Windsurf", ".codeium/windsurf/skills"),
+            ("pi", "Pi", ".pi/agent/skills"),
+        ];
+        
+        for (expected_id, expected_name, expected_path) in expected_agents {
+            let found = definitions.iter().find(|(id, _, _)| *id == expected_id);
+            assert!(found.is_some(), "Agent {} should exist", expected_id);
+            let (_, name, path) = found.unwrap();
+            assert_eq!(*name, expected_name, "Agent {} should have correct name", expected_id);
+            assert_eq!(*path, expected_path, "Agent {} should have correct path", expected_id);
+        }
+    }
+
+    /// Test detection with nested directory paths
+    /// 
+    /// **Validates: Requirements 3.2**
+    #[test]
+    fn test_agent_detection_with_nested_paths() {
+        // Arrange: Create a temp home directory
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        
+        // Create agents with deeply nested paths
+        let nested_agents = vec![
+            (".gemini/antigravity/global_skills", "antigravity"),  // 3 levels deep
+            (".codeium/windsurf/skills", "windsurf"),              // 3 levels deep
+            (".pi/agent/skills", "pi"),                            // 3 levels deep
+        ];
+        
+        for (path, _) in &nested_agents {
+            let full_path = home_path.join(path);
+            fs::create_dir_all(&full_path).expect(&format!("Failed to create directory: {}", path));
+        }
+        
+        // Act: Detect agents
+        let agents = detect_agents_with_home(&home_path);
+        
+        // Assert: Nested path agents should be detected
+        for (_, id) in &nested_agents {
+            let agent = agents.iter().find(|a| a.id == *id).expect(&format!("Agent {} not found", id));
+            assert!(agent.detected, "Agent {} with nested path should be detected", id);
+        }
+    }
+
+    /// Test that concurrent detection returns the same agents, in the same order, as sequential detection
+    #[test]
+    fn test_concurrent_detection_matches_sequential() {
+        // Arrange: A mix of detected and missing agents
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        for path in [".cursor/skills", ".pi/agent/skills", ".neovate/skills", ".config/goose/skills"] {
+            fs::create_dir_all(home_path.join(path)).expect("Failed to create agent directory");
+        }
+
+        // Act
+        let concurrent = detect_known_agents_concurrent(&home_path);
+        let sequential = detect_known_agents_sequential(&home_path);
+
+        // Assert
+        assert_eq!(concurrent, sequential);
+        assert_eq!(detect_agents_with_home(&home_path), sequential);
+        assert_eq!(concurrent.iter().filter(|a| a.detected).count(), 4);
+    }
+
+    /// Test that a missing skills directory is created only for agents whose config directory exists
+    #[test]
+    fn test_ensure_agent_dirs_creates_missing_skills_dir() {
+        // Arrange: cursor has a config dir but no skills dir, claude is fully set up, cline has nothing
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        fs::create_dir_all(home_path.join(".cursor")).expect("Failed to create cursor config directory");
+        fs::create_dir_all(home_path.join(".claude/skills")).expect("Failed to create claude skills directory");
+        let agent_ids = vec!["cursor".to_string(), "claude-code".to_string(), "cline".to_string()];
+
+        // Act
+        let result = ensure_agent_dirs_with_home(Some(agent_ids), &home_path).expect("ensure should succeed");
+
+        // Assert
+        assert_eq!(result.success, vec!["cursor".to_string()]);
+        assert!(home_path.join(".cursor/skills").is_dir(), "Skills directory should be created");
+        let skipped: Vec<&str> = result.skipped.iter().map(|s| s.agent_id.as_str()).collect();
+        assert_eq!(skipped, vec!["claude-code", "cline"]);
+        assert!(result.skipped[0].error.contains("already exists"));
+        assert!(result.skipped[1].error.contains("not found"));
+        assert!(!home_path.join(".cline").exists(), "Config directory should not be created");
+    }
+
+    /// Test that the enabled_agents allowlist limits detection and batch operations
+    #[test]
+    fn test_enabled_agents_allowlist_limits_operations() {
+        // Arrange: Three detected agents, two of them allowlisted plus an unknown ID
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        for path in [".cursor/skills", ".claude/skills", ".cline/skills"] {
+            fs::create_dir_all(home_path.join(path)).expect("Failed to create agent directory");
+        }
+        fs::create_dir_all(home_path.join(".agents/skills/test-skill")).expect("Failed to create skill directory");
+        let config = AppConfig {
+            enabled_agents: vec!["cursor".to_string(), "claude-code".to_string(), "no-such-agent".to_string()],
+            ..Default::default()
+        };
+        config::save_config_with_home(&config, &home_path).expect("Failed to save config");
+
+        // Act
+        let agents = detect_agents_with_home(&home_path);
+        let result = link_skill_to_all_with_home("test-skill", &home_path).expect("link should succeed");
+
+        // Assert: Only allowlisted agents are seen and linked
+        let ids: Vec<&str> = agents.iter().map(|a| a.id.as_str()).collect();
+        assert_eq!(ids, vec!["claude-code", "cursor"]);
+        assert_eq!(result.success.len(), 2);
+        assert!(fs::symlink_metadata(home_path.join(".cline/skills/test-skill")).is_err(), "cline should be untouched");
+        assert_eq!(
+            get_dashboard_with_home(&home_path).config_warnings,
+            vec!["Unknown agent 'no-such-agent' in enabled_agents was ignored".to_string()]
+        );
+    }
+
+    /// Test that a hidden agent is left out of detection and batch operations but stays defined
+    #[test]
+    fn test_hidden_agents_excluded_from_detection() {
+        // Arrange: Two detected agents, one of them hidden
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        for path in [".cursor/skills", ".copilot/skills"] {
+            fs::create_dir_all(home_path.join(path)).expect("Failed to create agent directory");
+        }
+        fs::create_dir_all(home_path.join(".agents/skills/test-skill")).expect("Failed to create skill directory");
+        let config = AppConfig { hidden_agents: vec!["github-copilot".to_string()], ..Default::default() };
+        config::save_config_with_home(&config, &home_path).expect("Failed to save config");
+
+        // Act
+        let agents = detect_agents_with_home(&home_path);
+        let result = link_skill_to_all_with_home("test-skill", &home_path).expect("link should succeed");
+
+        // Assert
+        assert!(agents.iter().all(|a| a.id != "github-copilot"), "Hidden agent should not be detected");
+        assert!(agents.iter().any(|a| a.id == "cursor" && a.detected));
+        assert_eq!(result.success, vec!["cursor".to_string()]);
+        assert!(fs::symlink_metadata(home_path.join(".copilot/skills/test-skill")).is_err(), "copilot should be untouched");
+        assert!(get_agent_definition_list().iter().any(|(id, _, _)| *id == "github-copilot"));
+    }
+
+    /// Test that discovery mode surfaces an unknown skills directory as an agent
+    #[test]
+    fn test_discover_agents_finds_unknown_skills_directory() {
+        // Arrange: An unknown tool, a known agent and the global library
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        fs::create_dir_all(home_path.join(".mytool/skills")).expect("Failed to create mytool directory");
+        fs::create_dir_all(home_path.join(".config/othertool/skills")).expect("Failed to create othertool directory");
+        fs::create_dir_all(home_path.join(".cursor/skills")).expect("Failed to create cursor directory");
+        fs::create_dir_all(home_path.join(".agents/skills")).expect("Failed to create global directory");
+
+        // Act & Assert: Discovery is off by default
+        assert_eq!(detect_agents_with_home(&home_path).len(), 27, "Discovery should be opt-in");
+
+        // Act: Enable discovery
+        let config = AppConfig { discover_agents: true, ..Default::default() };
+        config::save_config_with_home(&config, &home_path).expect("Failed to save config");
+        let agents = detect_agents_with_home(&home_path);
+
+        // Assert: Only the unknown directories are discovered
+        let discovered: Vec<&Agent> = agents.iter().filter(|a| a.discovered).collect();
+        assert_eq!(discovered.len(), 2, "Known agents and global roots should not be rediscovered");
+        let mytool = discovered.iter().find(|a| a.path == ".mytool/skills").expect("mytool should be discovered");
+        assert_eq!(mytool.id, "mytool");
+        assert!(mytool.detected);
+        assert!(discovered.iter().any(|a| a.id == "config-othertool"));
+    }
+
+    // ==================== SKILL.md Parsing Tests ====================
+
+    /// Test that get_app_data parses SKILL.md files with frontmatter format
+    /// 
+    /// **Validates: Requirements 2.1**
+    /// - 2.1: WHEN reading a skill directory, THE Skills_Manager SHALL look for a SKILL.md file in the skill's root directory
+    #[test]
+    fn test_get_app_data_parses_skill_md_frontmatter() {
+        // Arrange: Create a temp home directory with a skill containing SKILL.md
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        
+        // Create global skills directory with a skill
+        let skill_dir = home_path.join(".agents/skills/my-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        
+        // Create SKILL.md with frontmatter
+        let skill_md_content = r#"---
+name: My Awesome Skill
+description: This skill does amazing things
+allowed-tools:
+  - tool1
+  - tool2
+---
+
+# My Awesome Skill
+
+Detailed documentation here.
+"#;
+        fs::write(skill_dir.join("SKILL.md"), skill_md_content).expect("Failed to write SKILL.md");
+        
+        // Act: Get app data
+        let app_data = get_app_data_with_home(&home_path);
+        
+        // Assert: Skill should have parsed metadata
+        assert_eq!(app_data.skills.len(), 1, "Should have 1 skill");
+        let skill = &app_data.skills[0];
+        assert_eq!(skill.name, "my-skill", "Skill directory name should be preserved");
+        assert_eq!(skill.metadata.name, "My Awesome Skill", "Skill name should be parsed from frontmatter");
+        assert_eq!(skill.metadata.description, "This skill does amazing things", "Description should be parsed from frontmatter");
+        assert_eq!(skill.metadata.allowed_tools, vec!["tool1", "tool2"], "Allowed tools should be parsed from frontmatter");
+    }
+
+    /// Test that get_app_data parses SKILL.md files with heading format
+    /// 
+    /// **Validates: Requirements 2.1**
+    #[test]
+    fn test_get_app_data_parses_skill_md_heading_format() {
+        // Arrange: Create a temp home directory with a skill containing SKILL.md
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        
+        // Create global skills directory with a skill
+        let skill_dir = home_path.join(".agents/skills/heading-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        
+        // Create SKILL.md with heading format
+        let skill_md_content = r#"# Heading Based Skill
+
+This is a skill using the heading format.
+
+## Allowed Tools
+- read_file
+- write_file
+"#;
+        fs::write(skill_dir.join("SKILL.md"), skill_md_content).expect("Failed to write SKILL.md");
+        
+        // Act: Get app data
+        let app_data = get_app_data_with_home(&home_path);
+        
+        // Assert: Skill should have parsed metadata
+        assert_eq!(app_data.skills.len(), 1, "Should have 1 skill");
+        let skill = &app_data.skills[0];
+        assert_eq!(skill.metadata.name, "Heading Based Skill", "Skill name should be parsed from heading");
+        assert_eq!(skill.metadata.description, "This is a skill using the heading format.", "Description should be parsed from first paragraph");
+        assert_eq!(skill.metadata.allowed_tools, vec!["read_file", "write_file"], "Allowed tools should be parsed");
+    }
+
+    /// Test that get_app_data uses fallback values when SKILL.md is missing
+    /// 
+    /// **Validates: Requirements 1.6**
+    /// - 1.6: IF parsing SKILL.md fails, THEN THE Skills_Manager SHALL display the skill name from the directory name and show "No description available"
+    #[test]
+    fn test_get_app_data_fallback_when_skill_md_missing() {
+        // Arrange: Create a temp home directory with a skill without SKILL.md
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        
+        // Create global skills directory with a skill (no SKILL.md)
+        let skill_dir = home_path.join(".agents/skills/no-skill-md");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        
+        // Act: Get app data
+        let app_data = get_app_data_with_home(&home_path);
+        
+        // Assert: Skill should have fallback metadata
+        assert_eq!(app_data.skills.len(), 1, "Should have 1 skill");
+        let skill = &app_data.skills[0];
+        assert_eq!(skill.name, "no-skill-md", "Skill directory name should be preserved");
+        assert_eq!(skill.metadata.name, "no-skill-md", "Skill name should fallback to directory name");
+        assert_eq!(skill.metadata.description, "No description available", "Description should be fallback message");
+        assert!(skill.metadata.allowed_tools.is_empty(), "Allowed tools should be empty");
+    }
+
+    /// Test that get_app_data uses fallback values when SKILL.md has empty name
+    /// 
+    /// **Validates: Requirements 1.6**
+    #[test]
+    fn test_get_app_data_fallback_when_skill_md_has_empty_name() {
+        // Arrange: Create a temp home directory with a skill with empty name in SKILL.md
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        
+        // Create global skills directory with a skill
+        let skill_dir = home_path.join(".agents/skills/empty-name-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        
+        // Create SKILL.md with empty name
+        let skill_md_content = r#"---
+description: Has description but no name
+---
+"#;
+        fs::write(skill_dir.join("SKILL.md"), skill_md_content).expect("Failed to write SKILL.md");
+        
+        // Act: Get app data
+        let app_data = get_app_data_with_home(&home_path);
+        
+        // Assert: Skill should have directory name as fallback
+        assert_eq!(app_data.skills.len(), 1, "Should have 1 skill");
+        let skill = &app_data.skills[0];
+        assert_eq!(skill.metadata.name, "empty-name-skill", "Skill name should fallback to directory name");
+        assert_eq!(skill.metadata.description, "Has description but no name", "Description should be parsed");
+    }
+
+    /// Test that get_app_data uses fallback description when SKILL.md has empty description
+    /// 
+    /// **Validates: Requirements 1.6**
+    #[test]
+    fn test_get_app_data_fallback_when_skill_md_has_empty_description() {
+        // Arrange: Create a temp home directory with a skill with empty description in SKILL.md
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        
+        // Create global skills directory with a skill
+        let skill_dir = home_path.join(".agents/skills/empty-desc-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        
+        // Create SKILL.md with name but no description
+        let skill_md_content = r#"---
+name: Named Skill
+---
+"#;
+        fs::write(skill_dir.join("SKILL.md"), skill_md_content).expect("Failed to write SKILL.md");
+        
+        // Act: Get app data
+        let app_data = get_app_data_with_home(&home_path);
+        
+        // Assert: Skill should have fallback description
+        assert_eq!(app_data.skills.len(), 1, "Should have 1 skill");
+        let skill = &app_data.skills[0];
+        assert_eq!(skill.metadata.name, "Named Skill", "Skill name should be parsed");
+        assert_eq!(skill.metadata.description, "No description available", "Description should be fallback message");
+    }
+
+    /// Test that get_app_data reports where each agent's symlink points, resolving relative targets
+    #[test]
+    fn test_get_app_data_reports_symlink_targets() {
+        // Arrange: cursor links the global skill, amp links an unrelated directory by a relative path
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let skill_dir = home_path.join(".agents/skills/test-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        fs::create_dir_all(home_path.join(".config/agents/other")).expect("Failed to create unrelated directory");
+        fs::create_dir_all(home_path.join(".cursor/skills")).expect("Failed to create cursor directory");
+        fs::create_dir_all(home_path.join(".config/agents/skills")).expect("Failed to create amp directory");
+        create_symlink(&skill_dir, home_path.join(".cursor/skills/test-skill")).expect("Failed to create cursor symlink");
+        create_symlink("../other", home_path.join(".config/agents/skills/test-skill")).expect("Failed to create amp symlink");
+
+        // Act
+        let app_data = get_app_data_with_home(&home_path);
+
+        // Assert
+        let real_home = fs::canonicalize(&home_path).expect("Failed to resolve home");
+        let mut targets = app_data.skills[0].symlink_targets.clone();
+        targets.sort();
+        assert_eq!(targets, vec![
+            ("amp".to_string(), real_home.join(".config/agents/other").to_string_lossy().to_string()),
+            ("cursor".to_string(), skill_dir.to_string_lossy().to_string()),
+        ]);
+    }
+
+    /// Test that a relative target is resolved against the real skills directory when that directory is a symlink
+    #[test]
+    fn test_get_app_data_symlink_targets_through_symlinked_skills_dir() {
+        // Arrange: cursor's skills directory links into dotfiles, where the skill link climbs relatively
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        fs::create_dir_all(home_path.join(".agents/skills/test-skill")).expect("Failed to create skill directory");
+        let real_skills = home_path.join("dotfiles/cursor/skills");
+        fs::create_dir_all(&real_skills).expect("Failed to create real skills directory");
+        fs::create_dir_all(home_path.join("dotfiles/cursor/shared")).expect("Failed to create shared directory");
+        fs::create_dir_all(home_path.join(".cursor")).expect("Failed to create cursor directory");
+        create_symlink(&real_skills, home_path.join(".cursor/skills")).expect("Failed to link skills directory");
+        create_symlink("../shared", real_skills.join("test-skill")).expect("Failed to create skill symlink");
+
+        // Act
+        let app_data = get_app_data_with_home(&home_path);
+
+        // Assert: `..` climbs from dotfiles/cursor/skills, not from .cursor/skills
+        let real_home = fs::canonicalize(&home_path).expect("Failed to resolve home");
+        assert_eq!(app_data.skills[0].symlink_targets, vec![(
+            "cursor".to_string(),
+            real_home.join("dotfiles/cursor/shared").to_string_lossy().to_string(),
+        )]);
+    }
+
+    /// Test that get_app_data handles multiple skills with mixed SKILL.md states
+    /// 
+    /// **Validates: Requirements 1.6, 2.1**
+    #[test]
+    fn test_get_app_data_multiple_skills_mixed_states() {
+        // Arrange: Create a temp home directory with multiple skills
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        
+        // Create global skills directory
+        let skills_base = home_path.join(".agents/skills");
+        
+        // Skill 1: Has complete SKILL.md
+        let skill1_dir = skills_base.join("complete-skill");
+        fs::create_dir_all(&skill1_dir).expect("Failed to create skill1 directory");
+        fs::write(skill1_dir.join("SKILL.md"), r#"---
+name: Complete Skill
+description: A fully documented skill
+allowed-tools:
+  - tool_a
+---
+"#).expect("Failed to write SKILL.md");
+        
+        // Skill 2: No SKILL.md
+        let skill2_dir = skills_base.join("no-md-skill");
+        fs::create_dir_all(&skill2_dir).expect("Failed to create skill2 directory");
+        
+        // Skill 3: Empty SKILL.md
+        let skill3_dir = skills_base.join("empty-md-skill");
+        fs::create_dir_all(&skill3_dir).expect("Failed to create skill3 directory");
+        fs::write(skill3_dir.join("SKILL.md"), "").expect("Failed to write empty SKILL.md");
+        
+        // Act: Get app data
+        let app_data = get_app_data_with_home(&home_path);
+        
+        // Assert: All skills should be loaded with appropriate metadata
+        assert_eq!(app_data.skills.len(), 3, "Should have 3 skills");
+        
+        // Find each skill by name
+        let complete_skill = app_data.skills.iter().find(|s| s.name == "complete-skill").expect("complete-skill not found");
+        let no_md_skill = app_data.skills.iter().find(|s| s.name == "no-md-skill").expect("no-md-skill not found");
+        let empty_md_skill = app_data.skills.iter().find(|s| s.name == "empty-md-skill").expect("empty-md-skill not found");
+        
+        // Verify complete skill
+        assert_eq!(complete_skill.metadata.name, "Complete Skill");
+        assert_eq!(complete_skill.metadata.description, "A fully documented skill");
+        assert_eq!(complete_skill.metadata.allowed_tools, vec!["tool_a"]);
+        
+        // Verify no-md skill (fallback values)
+        assert_eq!(no_md_skill.metadata.name, "no-md-skill");
+        assert_eq!(no_md_skill.metadata.description, "No description available");
+        assert!(no_md_skill.metadata.allowed_tools.is_empty());
+        
+        // Verify empty-md skill (fallback values)
+        assert_eq!(empty_md_skill.metadata.name, "empty-md-skill");
+        assert_eq!(empty_md_skill.metadata.description, "No description available");
+        assert!(empty_md_skill.metadata.allowed_tools.is_empty());
+    }
+
+    /// Test load_skill_metadata function directly
+    /// 
+    /// **Validates: Requirements 1.6, 2.1**
+    #[test]
+    fn test_load_skill_metadata_with_valid_skill_md() {
+        // Arrange: Create a temp directory with SKILL.md
+        let temp_dir = create_temp_home();
+        let skill_dir = temp_dir.path().to_path_buf();
+        
+        let skill_md_content = r#"---
+name: Test Skill
+description: Test description
+allowed-tools:
+  - test_tool
+---
+"#;
+        fs::write(skill_dir.join("SKILL.md"), skill_md_content).expect("Failed to write SKILL.md");
+        
+        // Act
+        let metadata = load_skill_metadata(&skill_dir, "fallback-name");
+        
+        // Assert
+        assert_eq!(metadata.name, "Test Skill");
+        assert_eq!(metadata.description, "Test description");
+        assert_eq!(metadata.allowed_tools, vec!["test_tool"]);
+    }
+
+    /// Test load_skill_metadata function with missing SKILL.md
+    /// 
+    /// **Validates: Requirements 1.6**
+    #[test]
+    fn test_load_skill_metadata_without_skill_md() {
+        // Arrange: Create a temp directory without SKILL.md
+        let temp_dir = create_temp_home();
+        let skill_dir = temp_dir.path().to_path_buf();
+        
+        // Act
+        let metadata = load_skill_metadata(&skill_dir, "my-fallback-name");
+        
+        // Assert: Should use fallback values
+        assert_eq!(metadata.name, "my-fallback-name");
+        assert_eq!(metadata.description, "No description available");
+        assert!(metadata.allowed_tools.is_empty());
+    }
+
+    /// Test that a skill inherits missing fields from the defaults file while its own values win
+    #[test]
+    fn test_load_skill_metadata_merges_defaults() {
+        // Arrange: Defaults with a license and author; the skill sets only its own author
+        let temp_home = create_temp_home();
+        let global_dir = temp_home.path().join(".agents/skills");
+        let skill_dir = global_dir.join("licensed");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        fs::write(global_dir.join(DEFAULTS_FILE), "license: MIT\nauthor: Platform Team\n").expect("Failed to write defaults");
+        let skill_md = "---\nname: Licensed\ndescription: Has defaults\nauthor: Ada\n---\n";
+        fs::write(skill_dir.join("SKILL.md"), skill_md).expect("Failed to write SKILL.md");
+
+        // Act
+        let metadata = load_skill_metadata(&skill_dir, "licensed");
+        let names: Vec<String> = get_app_data_with_home(&temp_home.path().to_path_buf())
+            .skills
+            .into_iter()
+            .map(|skill| skill.name)
+            .collect();
+
+        // Assert
+        assert_eq!(metadata.license, Some("MIT".to_string()));
+        assert_eq!(metadata.author, Some("Ada".to_string()));
+        assert_eq!(fs::read_to_string(skill_dir.join("SKILL.md")).unwrap(), skill_md, "SKILL.md must not be rewritten");
+        assert_eq!(names, vec!["licensed".to_string()]);
+    }
+
+    // ==================== link_skill_to_all Tests ====================
+
+    /// Test that link_skill_to_all creates symlinks for all detected agents
+    /// 
+    /// **Validates: Requirements 1.4, 6.1**
+    /// - 1.4: WHEN the user clicks "Link to All" on a skill card, THE Skills_Manager SHALL create symlinks for that skill in all detected agents' skills directories
+    /// - 6.1: WHEN the user clicks "Link to All Agents" for a skill, THE Skills_Manager SHALL create symlinks in all detected agents' skills directories
+    #[test]
+    fn test_link_skill_to_all_creates_symlinks_for_detected_agents() {
+        // Arrange: Create a temp home directory with a global skill and some detected agents
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        
+        // Create global skill
+        let skill_dir = home_path.join(".agents/skills/test-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        fs::write(skill_dir.join("SKILL.md"), "# Test Skill\nA test skill.").expect("Failed to write SKILL.md");
+        
+        // Create detected agent directories
+        let detected_agents = vec![
+            ".cursor/skills",
+            ".claude/skills",
+            ".config/agents/skills",  // amp
+        ];
+        
+        for path in &detected_agents {
+            let full_path = home_path.join(path);
+            fs::create_dir_all(&full_path).expect(&format!("Failed to create directory: {}", path));
+        }
+        
+        // Act: Link skill to all
+        let result = link_skill_to_all_with_home("test-skill", &home_path).expect("link_skill_to_all should succeed");
+        
+        // Assert: All detected agents should be in success list
+        assert_eq!(result.success.len(), 3, "Should have 3 successful links");
+        assert!(result.success.contains(&"cursor".to_string()), "cursor should be in success list");
+        assert!(result.success.contains(&"claude-code".to_string()), "claude-code should be in success list");
+        assert!(result.success.contains(&"amp".to_string()), "amp should be in success list");
+        assert!(result.failed.is_empty(), "Should have no failures");
+        
+        // Verify symlinks were created
+        for path in &detected_agents {
+            let symlink_path = home_path.join(path).join("test-skill");
+            assert!(symlink_path.exists(), "Symlink should exist at {}", symlink_path.display());
+            let metadata = fs::symlink_metadata(&symlink_path).expect("Should be able to read symlink metadata");
+            assert!(metadata.file_type().is_symlink(), "Should be a symlink");
+        }
+    }
+
+    /// Test that link_skill_to_all skips non-detected agents
+    /// 
+    /// **Validates: Requirements 6.3**
+    /// - 6.3: WHEN performing batch operations, THE Skills_Manager SHALL skip agents that are not detected
+    #[test]
+    fn test_link_skill_to_all_skips_non_detected_agents() {
+        // Arrange: Create a temp home directory with a global skill and only one detected agent
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        
+        // Create global skill
+        let skill_dir = home_path.join(".agents/skills/test-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        
+        // Create only one detected agent directory
+        let cursor_path = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
+        
+        // Act: Link skill to all
+        let result = link_skill_to_all_with_home("test-skill", &home_path).expect("link_skill_to_all should succeed");
+        
+        // Assert: Only cursor should be in success list (other 26 agents are not detected)
+        assert_eq!(result.success.len(), 1, "Should have 1 successful link");
+        assert!(result.success.contains(&"cursor".to_string()), "cursor should be in success list");
+        assert!(result.failed.is_empty(), "Should have no failures (non-detected agents are skipped, not failed)");
+        
+        // Verify symlink was created for cursor
+        let symlink_path = cursor_path.join("test-skill");
+        assert!(symlink_path.exists(), "Symlink should exist for cursor");
+        
+        // Verify no symlinks were created for non-detected agents
+        let claude_symlink = home_path.join(".claude/skills/test-skill");
+        assert!(!claude_symlink.exists(), "Symlink should not exist for non-detected agent");
+    }
+
+    /// Test that link_skill_to_all returns error when skill doesn't exist
+    /// 
+    /// **Validates: Requirements 1.4**
+    #[test]
+    fn test_link_skill_to_all_fails_when_skill_not_found() {
+        // Arrange: Create a temp home directory without the skill
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        
+        // Create global skills directory but not the specific skill
+        fs::create_dir_all(home_path.join(".agents/skills")).expect("Failed to create skills directory");
+        
+        // Create a detected agent
+        fs::create_dir_all(home_path.join(".cursor/skills")).expect("Failed to create cursor directory");
+        
+        // Act: Try to link non-existent skill
+        let result = link_skill_to_all_with_home("non-existent-skill", &home_path);
+        
+        // Assert: Should return error
+        assert!(result.is_err(), "Should return error when skill doesn't exist");
+        let error = result.unwrap_err();
+        assert!(error.contains("non-existent-skill"), "Error should mention the skill name");
+    }
+
+    /// Test that link_skill_to_all handles already linked skills
+    /// 
+    /// **Validates: Requirements 1.4, 6.1**
+    #[test]
+    fn test_link_skill_to_all_handles_already_linked_skills() {
+        // Arrange: Create a temp home directory with a global skill and a pre-existing symlink
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        
+        // Create global skill
+        let skill_dir = home_path.join(".agents/skills/test-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        
+        // Create detected agent directories
+        let cursor_path = home_path.join(".cursor/skills");
+        let claude_path = home_path.join(".claude/skills");
+        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
+        fs::create_dir_all(&claude_path).expect("Failed to create claude directory");
+        
+        // Pre-create symlink for cursor
+        let cursor_symlink = cursor_path.join("test-skill");
+        create_symlink(&skill_dir, &cursor_symlink).expect("Failed to create pre-existing symlink");
+        
+        // Act: Link skill to all
+        let result = link_skill_to_all_with_home("test-skill", &home_path).expect("link_skill_to_all should succeed");
+        
+        // Assert: Both agents should be in success list (cursor already linked, claude newly linked)
+        assert_eq!(result.success.len(), 2, "Should have 2 successful links");
+        assert!(result.success.contains(&"cursor".to_string()), "cursor should be in success list");
+        assert!(result.success.contains(&"claude-code".to_string()), "claude-code should be in success list");
+        assert!(result.failed.is_empty(), "Should have no failures");
+    }
+
+    /// Test that link_skill_to_all creates parent directories if needed
+    /// 
+    /// **Validates: Requirements 5.7**
+    /// - 5.7: WHEN creating a symlink, THE Skills_Manager SHALL create the parent directory if it does not exist
+    #[test]
+    fn test_link_skill_to_all_creates_parent_directories() {
+        // Arrange: Create a temp home directory with a global skill
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        
+        // Create global skill
+        let skill_dir = home_path.join(".agents/skills/test-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        
+        // Create only the base directory for cursor (not the full skills path)
+        // This simulates an agent that exists but doesn't have a skills directory yet
+        let cursor_base = home_path.join(".cursor");
+        fs::create_dir_all(&cursor_base).expect("Failed to create cursor base directory");
+        
+        // Also create the full path for another agent to make it detected
+        let claude_path = home_path.join(".claude/skills");
+        fs::create_dir_all(&claude_path).expect("Failed to create claude directory");
+        
+        // Act: Link skill to all
+        let result = link_skill_to_all_with_home("test-skill", &home_path).expect("link_skill_to_all should succeed");
+        
+        // Assert: Only claude should be in success list (cursor is not detected because .cursor/skills doesn't exist)
+        assert_eq!(result.success.len(), 1, "Should have 1 successful link");
+        assert!(result.success.contains(&"claude-code".to_string()), "claude-code should be in success list");
+    }
+
+    /// Test that link_skill_to_all returns BatchResult with correct structure
+    /// 
+    /// **Validates: Requirements 1.4, 6.1**
+    #[test]
+    fn test_link_skill_to_all_returns_batch_result() {
+        // Arrange: Create a temp home directory with a global skill
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        
+        // Create global skill
+        let skill_dir = home_path.join(".agents/skills/test-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        
+        // Create detected agent directories
+        fs::create_dir_all(home_path.join(".cursor/skills")).expect("Failed to create cursor directory");
+        fs::create_dir_all(home_path.join(".claude/skills")).expect("Failed to create claude directory");
+        
+        // Act: Link skill to all
+        let result = link_skill_to_all_with_home("test-skill", &home_path).expect("link_skill_to_all should succeed");
+        
+        // Assert: BatchResult should have correct structure
+        assert!(result.success.len() >= 2, "Should have at least 2 successful links");
+        assert!(result.failed.is_empty(), "Should have no failures");
+        
+        // Verify success list contains agent IDs (strings)
+        for agent_id in &result.success {
+            assert!(!agent_id.is_empty(), "Agent ID should not be empty");
+        }
+    }
+
+    /// Test that link_skill_to_all handles file existing at target path
+    /// 
+    /// **Validates: Requirements 6.5**
+    /// - 6.5: IF any individual link/unlink operation fails during batch, THEN THE Skills_Manager SHALL continue with remaining agents and report errors
+    #[test]
+    fn test_link_skill_to_all_handles_file_at_target_path() {
+        // Arrange: Create a temp home directory with a global skill
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        
+        // Create global skill
+        let skill_dir = home_path.join(".agents/skills/test-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        
+        // Create detected agent directories
+        let cursor_path = home_path.join(".cursor/skills");
+        let claude_path = home_path.join(".claude/skills");
+        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
+        fs::create_dir_all(&claude_path).expect("Failed to create claude directory");
+        
+        // Create a regular file (not symlink) at cursor's target path
+        let cursor_target = cursor_path.join("test-skill");
+        fs::write(&cursor_target, "blocking file").expect("Failed to create blocking file");
+        
+        // Act: Link skill to all
+        let result = link_skill_to_all_with_home("test-skill", &home_path).expect("link_skill_to_all should succeed");
+        
+        // Assert: cursor should fail, claude should succeed
+        assert!(result.success.contains(&"claude-code".to_string()), "claude-code should be in success list");
+        assert_eq!(result.failed.len(), 1, "Should have 1 failure");
+        assert_eq!(result.failed[0].agent_id, "cursor", "cursor should be in failed list");
+        assert!(!result.failed[0].error.is_empty(), "Error message should not be empty");
+    }
+
+    /// Test that linking is refused when a global root lives inside an agent's skills directory
+    #[test]
+    fn test_link_skill_to_all_refuses_symlink_loop() {
+        // Arrange: "~/.cursor" is configured as a global root, so the global skill
+        // "skills" is cursor's own skills directory
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let cursor_path = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
+        fs::create_dir_all(home_path.join(".claude/skills")).expect("Failed to create claude directory");
+        let config = AppConfig { global_paths: vec!["~/.cursor".to_string()], ..Default::default() };
+        config::save_config_with_home(&config, &home_path).expect("Failed to save config");
+
+        // Act
+        let result = link_skill_to_all_with_home("skills", &home_path).expect("link_skill_to_all should succeed");
+
+        // Assert: cursor is refused and no link is created inside its own target
+        assert_eq!(result.failed.len(), 1, "Should have 1 failure: {:?}", result.failed);
+        assert_eq!(result.failed[0].agent_id, "cursor");
+        assert!(result.failed[0].error.contains("symlink loop"), "Unexpected error: {}", result.failed[0].error);
+        assert!(fs::symlink_metadata(cursor_path.join("skills")).is_err(), "No looping symlink should be created");
+        assert!(result.success.contains(&"claude-code".to_string()));
+    }
+
+    /// Test that an agent configured for the contents layout gets a directory of symlinks
+    #[test]
+    fn test_link_skill_to_all_contents_layout() {
+        // Arrange: cursor expects the contents layout, claude the default directory link
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let skill_dir = home_path.join(".agents/skills/test-skill");
+        fs::create_dir_all(skill_dir.join("scripts")).expect("Failed to create skill directory");
+        fs::write(skill_dir.join("SKILL.md"), "---\nname: Test\n---\n").expect("Failed to write SKILL.md");
+        let cursor_path = home_path.join(".cursor/skills");
+        let claude_path = home_path.join(".claude/skills");
+        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
+        fs::create_dir_all(&claude_path).expect("Failed to create claude directory");
+        let config = AppConfig {
+            link_layouts: [("cursor".to_string(), LinkLayout::Contents)].into_iter().collect(),
+            ..Default::default()
+        };
+        config::save_config_with_home(&config, &home_path).expect("Failed to save config");
+
+        // Act
+        let result = link_skill_to_all_with_home("test-skill", &home_path).expect("link should succeed");
+        let relinked = link_skill_to_all_with_home("test-skill", &home_path).expect("relink should succeed");
+
+        // Assert: cursor has a real directory whose entries link into the skill
+        assert_eq!(result.success.len(), 2, "Both agents should be linked: {:?}", result.failed);
+        assert_eq!(relinked.success.len(), 2, "Existing contents link should count as linked");
+        let cursor_skill = cursor_path.join("test-skill");
+        assert!(fs::symlink_metadata(&cursor_skill).unwrap().file_type().is_dir());
+        assert_eq!(fs::read_link(cursor_skill.join("SKILL.md")).expect("Should be a symlink"), skill_dir.join("SKILL.md"));
+        assert_eq!(fs::read_link(cursor_skill.join("scripts")).expect("Should be a symlink"), skill_dir.join("scripts"));
+        assert_eq!(fs::read_link(claude_path.join("test-skill")).expect("Should be a symlink"), skill_dir);
+
+        // Act & Assert: Unlinking removes the contents link too
+        let unlinked = unlink_skill_from_all_with_home("test-skill", &home_path).expect("unlink should succeed");
+        assert_eq!(unlinked.success.len(), 2);
+        assert!(fs::symlink_metadata(&cursor_skill).is_err());
+        assert!(skill_dir.join("SKILL.md").exists(), "Global skill should be untouched");
+    }
+
+    /// Test that an agent configured for SKILL.md-only linking gets just a SKILL.md symlink
+    #[test]
+    fn test_link_skill_to_all_skill_md_only_layout() {
+        // Arrange: A skill with a script; cursor links SKILL.md only
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let skill_dir = home_path.join(".agents/skills/test-skill");
+        fs::create_dir_all(skill_dir.join("scripts")).expect("Failed to create skill directory");
+        fs::write(skill_dir.join("SKILL.md"), "---\nname: Test\n---\n").expect("Failed to write SKILL.md");
+        let cursor_path = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
+        let config = AppConfig {
+            link_layouts: [("cursor".to_string(), LinkLayout::SkillMdOnly)].into_iter().collect(),
+            ..Default::default()
+        };
+        config::save_config_with_home(&config, &home_path).expect("Failed to save config");
+
+        // Act
+        let result = link_skill_to_all_with_home("test-skill", &home_path).expect("link should succeed");
+
+        // Assert: A real directory holding only the SKILL.md symlink
+        assert_eq!(result.success, vec!["cursor".to_string()]);
+        let cursor_skill = cursor_path.join("test-skill");
+        assert!(fs::symlink_metadata(&cursor_skill).unwrap().file_type().is_dir());
+        assert_eq!(fs::read_link(cursor_skill.join("SKILL.md")).expect("Should be a symlink"), skill_dir.join("SKILL.md"));
+        assert_eq!(fs::read_dir(&cursor_skill).unwrap().count(), 1, "Only SKILL.md should be linked");
+
+        // Assert: Agent detail classifies it as a link to the global skill
+        let detail = get_agent_detail_with_home("cursor", &home_path).expect("detail should succeed");
+        let skill = detail.skills.iter().find(|s| s.name == "test-skill").expect("test-skill not found");
+        assert_eq!(skill.status, AgentSkillStatus::Symlink);
+        assert_eq!(skill.source_path, Some(skill_dir.to_string_lossy().to_string()));
+        assert_eq!(skill.metadata.name, "Test");
+    }
+
+    /// Test that a local skill vendoring its files as symlinks stays local for a directory-layout agent
+    #[test]
+    fn test_local_skill_of_symlinks_is_local_for_directory_layout() {
+        // Arrange: A local cursor skill whose only entry is a symlink to a vendored SKILL.md
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let vendor_dir = home_path.join("vendor/tool");
+        fs::create_dir_all(&vendor_dir).expect("Failed to create vendor directory");
+        fs::write(vendor_dir.join("SKILL.md"), "---\nname: Vendored\n---\n").expect("Failed to write SKILL.md");
+        let local_skill = home_path.join(".cursor/skills/vendored");
+        fs::create_dir_all(&local_skill).expect("Failed to create local skill");
+        create_symlink(vendor_dir.join("SKILL.md"), local_skill.join("SKILL.md")).expect("Failed to create symlink");
+
+        // Act
+        let detail = get_agent_detail_with_home("cursor", &home_path).expect("detail should succeed");
+        let installed = all_installed_skills_with_home(&home_path);
+
+        // Assert: Listed as a local skill with its own metadata in both views
+        let skill = detail.skills.iter().find(|s| s.name == "vendored").expect("vendored not found");
+        assert_eq!(skill.status, AgentSkillStatus::Local);
+        assert_eq!(skill.metadata.name, "Vendored");
+        assert!(installed.contains(&("cursor".to_string(), "vendored".to_string(), AgentSkillStatus::Local)));
+    }
+
+    /// Test that safe mode refuses a link attempt without touching the filesystem
+    #[test]
+    fn test_link_skill_to_all_refused_in_safe_mode() {
+        // Arrange: A global skill, an agent and safe mode on
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        fs::create_dir_all(home_path.join(".agents/skills/test-skill")).expect("Failed to create skill directory");
+        let cursor_path = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
+        let mut config = AppConfig { safe_mode: true, ..Default::default() };
+        config::save_config_with_home(&config, &home_path).expect("Failed to save config");
+
+        // Act
+        let result = link_skill_to_all_with_home("test-skill", &home_path);
+
+        // Assert: Refused, and no link was created
+        assert_eq!(result.expect_err("link should be refused"), SAFE_MODE_ERROR);
+        assert!(fs::symlink_metadata(cursor_path.join("test-skill")).is_err(), "Filesystem should be unchanged");
+
+        // Act & Assert: Turning safe mode off allows linking again
+        config.safe_mode = false;
+        config::save_config_with_home(&config, &home_path).expect("Failed to save config");
+        link_skill_to_all_with_home("test-skill", &home_path).expect("link should succeed");
+        assert!(fs::symlink_metadata(cursor_path.join("test-skill")).is_ok());
+    }
+
+    /// Test that a disabled skill is skipped by link-all but can still be linked to one agent
+    #[test]
+    fn test_link_skill_to_all_skips_disabled_skill() {
+        // Arrange: A global skill marked as disabled, and one agent
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let skill_dir = home_path.join(".agents/skills/test-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        fs::write(skill_dir.join(DISABLED_MARKER), "").expect("Failed to write marker");
+        let cursor_path = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
+
+        // Act
+        let result = link_skill_to_all_with_home("test-skill", &home_path).expect("link should succeed");
+
+        // Assert: Reported as skipped for being disabled, nothing linked, and the flag is surfaced
+        assert!(result.skipped.is_empty());
+        assert_eq!(result.skipped_disabled.len(), 1);
+        assert_eq!(result.skipped_disabled[0].agent_id, "cursor");
+        assert!(fs::symlink_metadata(cursor_path.join("test-skill")).is_err());
+        let app_data = get_app_data_with_home(&home_path);
+        assert!(app_data.skills[0].disabled);
+
+        // Act & Assert: An explicit toggle still links it
+        toggle_skill_with_home("cursor", "test-skill", true, &home_path).expect("toggle should succeed");
+        assert_eq!(fs::read_link(cursor_path.join("test-skill")).expect("Should be a symlink"), skill_dir);
+    }
+
+    /// Test that toggle, delete and upload report typed errors the frontend can branch on
+    #[test]
+    fn test_skill_commands_return_typed_errors() {
+        // Arrange: A global skill linked into cursor, and a local copy of it in windsurf
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        fs::create_dir_all(home_path.join(".agents/skills/test-skill")).expect("Failed to create skill directory");
+        fs::create_dir_all(home_path.join(".cursor/skills")).expect("Failed to create cursor directory");
+        fs::create_dir_all(home_path.join(".codeium/windsurf/skills/test-skill")).expect("Failed to create local skill");
+        toggle_skill_with_home("cursor", "test-skill", true, &home_path).expect("toggle should succeed");
+
+        // Act & Assert
+        assert_eq!(
+            toggle_skill_with_home("no-such-agent", "test-skill", true, &home_path),
+            Err(SkillError::AgentNotFound("no-such-agent".to_string()))
+        );
+        assert_eq!(
+            toggle_skill_with_home("cursor", "missing", true, &home_path),
+            Err(SkillError::SkillNotFound("missing".to_string()))
+        );
+        assert_eq!(
+            delete_local_skill_with_home("cursor", "test-skill", &home_path),
+            Err(SkillError::IsSymlink("test-skill".to_string(), LocalSkillAction::Delete))
+        );
+        assert_eq!(
+            upload_to_global_with_home("windsurf", "test-skill", &home_path),
+            Err(SkillError::AlreadyExists("test-skill".to_string()))
+        );
+        assert_eq!(
+            toggle_skill_with_home("windsurf", "test-skill", false, &home_path),
+            Err(SkillError::NotASymlink("test-skill".to_string()))
+        );
+        assert!(home_path.join(".codeium/windsurf/skills/test-skill").is_dir(), "A local skill must not be toggled off");
+        // A local skill made only of symlinks is not a contents link for a directory-layout agent
+        let linked_files = home_path.join(".codeium/windsurf/skills/linked-files");
+        fs::create_dir_all(&linked_files).expect("Failed to create local skill");
+        create_symlink(home_path.join(".agents/skills/test-skill"), linked_files.join("shared")).expect("Failed to create symlink");
+        assert_eq!(
+            toggle_skill_with_home("windsurf", "linked-files", false, &home_path),
+            Err(SkillError::NotASymlink("linked-files".to_string()))
+        );
+        assert!(linked_files.join("shared").exists(), "The local skill's files must be kept");
+        assert!(home_path.join(".cursor/skills/test-skill").exists(), "A failed delete must leave the link");
+    }
+
+    /// Test that a local skill is copied between agents, creating the destination directory
+    #[test]
+    fn test_copy_local_skill_between_agents() {
+        // Arrange: A local skill in cursor and a global skill linked into cursor; amp has no skills directory
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let local_dir = home_path.join(".cursor/skills/experiment");
+        fs::create_dir_all(local_dir.join("scripts")).expect("Failed to create local skill");
+        fs::write(local_dir.join("SKILL.md"), "---\nname: experiment\n---\n").expect("Failed to write SKILL.md");
+        fs::write(local_dir.join("scripts/run.sh"), "echo hi\n").expect("Failed to write script");
+        fs::create_dir_all(home_path.join(".agents/skills/shared")).expect("Failed to create global skill");
+        toggle_skill_with_home("cursor", "shared", true, &home_path).expect("toggle should succeed");
+
+        // Act
+        let copied = copy_local_skill_with_home("cursor", "amp", "experiment", &home_path);
+        let again = copy_local_skill_with_home("cursor", "amp", "experiment", &home_path);
+        let symlinked = copy_local_skill_with_home("cursor", "amp", "shared", &home_path);
+        let escaping = copy_local_skill_with_home("cursor", "amp", "../../.agents/skills/shared", &home_path);
+
+        // Assert: The copy is a real directory with the same files; the others are refused
+        let copy_dir = home_path.join(".config/agents/skills/experiment");
+        assert_eq!(copied, Ok(()));
+        assert!(!fs::symlink_metadata(&copy_dir).unwrap().file_type().is_symlink());
+        assert_eq!(fs::read_to_string(copy_dir.join("scripts/run.sh")).unwrap(), "echo hi\n");
+        assert_eq!(again, Err(SkillError::AlreadyExistsForAgent {
+            skill_name: "experiment".to_string(),
+            agent_id: "amp".to_string(),
+        }));
+        assert_eq!(symlinked, Err(SkillError::IsSymlink("shared".to_string(), LocalSkillAction::Copy)));
+        assert_eq!(escaping, Err(SkillError::InvalidName("../../.agents/skills/shared".to_string())));
+        assert!(local_dir.exists(), "The source must be left in place");
+    }
+
+    /// Test that verifying a link that does not resolve fails and removes it, in both layouts
+    #[test]
+    fn test_verify_new_link_removes_unresolvable_link() {
+        // Arrange: A global skill, a dangling directory link and a contents link with a dangling entry
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let skill_dir = home_path.join(".agents/skills/test-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        fs::write(skill_dir.join("SKILL.md"), "---\nname: Test\n---\n").expect("Failed to write SKILL.md");
+        let cursor_link = home_path.join(".cursor/skills/test-skill");
+        let claude_link = home_path.join(".claude/skills/test-skill");
+        fs::create_dir_all(cursor_link.parent().unwrap()).expect("Failed to create cursor directory");
+        fs::create_dir_all(&claude_link).expect("Failed to create claude link directory");
+        create_symlink(home_path.join("missing/test-skill"), &cursor_link).expect("Failed to create symlink");
+        create_symlink(home_path.join("missing/SKILL.md"), claude_link.join("SKILL.md")).expect("Failed to create symlink");
+
+        // Act
+        let directory = verify_new_link(&cursor_link, &skill_dir, LinkLayout::Directory);
+        let contents = verify_new_link(&claude_link, &skill_dir, LinkLayout::SkillMdOnly);
+
+        // Assert
+        assert_eq!(directory, Err("Link created but does not resolve to the global skill".to_string()));
+        assert_eq!(contents, Err("Link created but does not resolve to the global skill".to_string()));
+        assert!(fs::symlink_metadata(&cursor_link).is_err(), "The failed link should be removed");
+        assert!(fs::symlink_metadata(&claude_link).is_err(), "The failed link should be removed");
+    }
+
+    /// Test that verification passes for links that resolve, in both layouts
+    #[test]
+    fn test_link_skill_to_all_verify_accepts_resolving_links() {
+        // Arrange: cursor uses the contents layout, claude the directory layout
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let skill_dir = home_path.join(".agents/skills/test-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        fs::write(skill_dir.join("SKILL.md"), "---\nname: Test\n---\n").expect("Failed to write SKILL.md");
+        fs::create_dir_all(home_path.join(".cursor/skills")).expect("Failed to create cursor directory");
+        fs::create_dir_all(home_path.join(".claude/skills")).expect("Failed to create claude directory");
+        let config = AppConfig {
+            link_layouts: [("cursor".to_string(), LinkLayout::Contents)].into_iter().collect(),
+            ..Default::default()
+        };
+        config::save_config_with_home(&config, &home_path).expect("Failed to save config");
+        let roots = global_skill_roots_with_home(&home_path);
+
+        // Act
+        let result = link_skill_to_all_with_roots("test-skill", &home_path, &roots, LinkOptions { verify: true, ..Default::default() })
+            .expect("link should succeed");
+
+        // Assert
+        assert_eq!(result.success.len(), 2, "Both links should verify: {:?}", result.failed);
+    }
+
+    /// Test that a skill without SKILL.md is refused in strict mode but linked in lenient mode
+    #[test]
+    fn test_link_skill_to_all_validate_before_link() {
+        // Arrange: A global skill with no SKILL.md and one detected agent
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        fs::create_dir_all(home_path.join(".agents/skills/bare-skill")).expect("Failed to create skill directory");
+        let cursor_dir = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_dir).expect("Failed to create cursor directory");
+        let roots = global_skill_roots_with_home(&home_path);
+
+        // Act
+        let strict = link_skill_to_all_with_roots("bare-skill", &home_path, &roots, LinkOptions { validate: true, ..Default::default() })
+            .expect("link should succeed");
+
+        // Assert: Refused with the validation warning, nothing linked
+        assert!(strict.success.is_empty());
+        assert_eq!(strict.failed.len(), 1);
+        assert_eq!(strict.failed[0].agent_id, "cursor");
+        assert!(strict.failed[0].error.contains("SKILL.md is missing"), "Unexpected error: {}", strict.failed[0].error);
+        assert!(fs::symlink_metadata(cursor_dir.join("bare-skill")).is_err());
+
+        // Act & Assert: Lenient mode links it
+        let lenient = link_skill_to_all_with_roots("bare-skill", &home_path, &roots, LinkOptions::default())
+            .expect("link should succeed");
+        assert_eq!(lenient.success, vec!["cursor".to_string()]);
+    }
+
+    /// Test that only the named agents are linked and unknown or undetected IDs are reported
+    #[test]
+    fn test_link_skill_to_agents_links_only_named_agents() {
+        // Arrange: cursor, claude and windsurf are detected; the skill exists globally
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        fs::create_dir_all(home_path.join(".agents/skills/review")).expect("Failed to create skill directory");
+        for agent_dir in [".cursor/skills", ".claude/skills", ".codeium/windsurf/skills"] {
+            fs::create_dir_all(home_path.join(agent_dir)).expect("Failed to create agent directory");
+        }
+        let agent_ids: Vec<String> = ["cursor", "claude-code", "nope", "codex"].iter().map(|id| id.to_string()).collect();
+
+        // Act
+        let result = link_skill_to_agents_with_home("review", &agent_ids, &home_path).expect("link should succeed");
+
+        // Assert
+        assert_eq!(result.success, vec!["cursor".to_string(), "claude-code".to_string()]);
+        let failed: Vec<&str> = result.failed.iter().map(|f| f.agent_id.as_str()).collect();
+        assert_eq!(failed, vec!["nope", "codex"]);
+        assert!(fs::symlink_metadata(home_path.join(".cursor/skills/review")).is_ok());
+        assert!(fs::symlink_metadata(home_path.join(".codeium/windsurf/skills/review")).is_err(), "Unnamed agents are untouched");
+    }
+
+    /// Test that dry runs classify agents exactly like the real link and unlink, changing nothing
+    #[test]
+    fn test_batch_dry_run_matches_real_run() {
+        // Arrange: cursor is empty, claude already links the skill, windsurf has a local copy
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let skill_dir = home_path.join(".agents/skills/review");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        fs::create_dir_all(home_path.join(".cursor/skills")).expect("Failed to create cursor directory");
+        fs::create_dir_all(home_path.join(".claude/skills")).expect("Failed to create claude directory");
+        create_symlink(&skill_dir, home_path.join(".claude/skills/review")).expect("Failed to create symlink");
+        fs::create_dir_all(home_path.join(".codeium/windsurf/skills/review")).expect("Failed to create local copy");
+        let roots = global_skill_roots_with_home(&home_path);
+        let summary = |result: &BatchResult| {
+            let failed: Vec<(String, String)> = result.failed.iter().map(|f| (f.agent_id.clone(), f.error.clone())).collect();
+            let skipped: Vec<(String, String)> = result.skipped.iter().map(|f| (f.agent_id.clone(), f.error.clone())).collect();
+            (result.success.clone(), failed, skipped)
+        };
+
+        // Act & Assert: Link
+        let dry_link = link_skill_to_all_with_roots("review", &home_path, &roots, LinkOptions { dry_run: true, ..Default::default() })
+            .expect("dry run should succeed");
+        assert!(fs::symlink_metadata(home_path.join(".cursor/skills/review")).is_err(), "Dry run must not create links");
+        let real_link = link_skill_to_all_with_roots("review", &home_path, &roots, LinkOptions::default()).expect("link should succeed");
+        assert_eq!(summary(&dry_link), summary(&real_link));
+        assert!(real_link.failed.iter().any(|f| f.error == "A file or directory already exists at the target path"));
+
+        // Act & Assert: Unlink
+        let dry_unlink = unlink_skill_from_all_dry_run_with_home("review", &home_path);
+        assert!(fs::symlink_metadata(home_path.join(".cursor/skills/review")).is_ok(), "Dry run must not remove links");
+        let real_unlink = unlink_skill_from_all_with_home("review", &home_path).expect("unlink should succeed");
+        assert_eq!(summary(&dry_unlink), summary(&real_unlink));
+        assert_eq!(real_unlink.skipped.len(), 1);
+    }
+
+    // ==================== unlink_skill_from_all Tests ====================
+
+    /// Test that unlink_skill_from_all removes symlinks from all agents
+    /// 
+    /// **Validates: Requirements 1.5, 6.2**
+    /// - 1.5: WHEN the user clicks "Unlink from All" on a skill card, THE Skills_Manager SHALL remove symlinks for that skill from all agents' skills directories
+    /// - 6.2: WHEN the user clicks "Unlink from All Agents" for a skill, THE Skills_Manager SHALL remove symlinks from all agents' skills directories
+    #[test]
+    fn test_unlink_skill_from_all_removes_symlinks() {
+        // Arrange: Create a temp home directory with a global skill and symlinks
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        
+        // Create global skill
+        let skill_dir = home_path.join(".agents/skills/test-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        
+        // Create agent directories and symlinks
+        let cursor_path = home_path.join(".cursor/skills");
+        let claude_path = home_path.join(".claude/skills");
+        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
+        fs::create_dir_all(&claude_path).expect("Failed to create claude directory");
+        
+        // Create symlinks
+        let cursor_symlink = cursor_path.join("test-skill");
+        let claude_symlink = claude_path.join("test-skill");
+        create_symlink(&skill_dir, &cursor_symlink).expect("Failed to create cursor symlink");
+        create_symlink(&skill_dir, &claude_symlink).expect("Failed to create claude symlink");
+        
+        // Verify symlinks exist before unlink
+        assert!(cursor_symlink.exists(), "Cursor symlink should exist before unlink");
+        assert!(claude_symlink.exists(), "Claude symlink should exist before unlink");
+        
+        // Act: Unlink skill from all
+        let result = unlink_skill_from_all_with_home("test-skill", &home_path).expect("unlink_skill_from_all should succeed");
+        
+        // Assert: Both agents should be in success list
+        assert_eq!(result.success.len(), 2, "Should have 2 successful unlinks");
+        assert!(result.success.contains(&"cursor".to_string()), "cursor should be in success list");
+        assert!(result.success.contains(&"claude-code".to_string()), "claude-code should be in success list");
+        assert!(result.failed.is_empty(), "Should have no failures");
+        
+        // Verify symlinks were removed
+        assert!(!cursor_symlink.exists(), "Cursor symlink should be removed");
+        assert!(!claude_symlink.exists(), "Claude symlink should be removed");
+    }
+
+    /// Test that unlink_skill_from_all attempts to remove from ALL agents (not just detected)
+    /// 
+    /// **Validates: Requirements 1.5, 6.2**
+    /// - Unlike link_skill_to_all, unlink should attempt to remove symlinks from ALL agents
+    #[test]
+    fn test_unlink_skill_from_all_removes_from_non_detected_agents() {
+        // Arrange: Create a temp home directory with symlinks but agent not "detected"
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        
+        // Create global skill
+        let skill_dir = home_path.join(".agents/skills/test-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        
+        // Create only the symlink path (not the full agent skills directory)
+        // This simulates a case where the agent was previously detected but now isn't
+        let cursor_path = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
+        
+        // Create symlink
+        let cursor_symlink = cursor_path.join("test-skill");
+        create_symlink(&skill_dir, &cursor_symlink).expect("Failed to create cursor symlink");
+        
+        // Verify symlink exists
+        assert!(cursor_symlink.exists(), "Cursor symlink should exist before unlink");
+        
+        // Act: Unlink skill from all
+        let result = unlink_skill_from_all_with_home("test-skill", &home_path).expect("unlink_skill_from_all should succeed");
+        
+        // Assert: cursor should be in success list even though it might not be "detected"
+        assert!(result.success.contains(&"cursor".to_string()), "cursor should be in success list");
+        assert!(result.failed.is_empty(), "Should have no failures");
+        
+        // Verify symlink was removed
+        assert!(!cursor_symlink.exists(), "Cursor symlink should be removed");
+    }
+
+    /// Test that unlink_skill_from_all handles no existing symlinks gracefully
+    /// 
+    /// **Validates: Requirements 1.5, 6.2**
+    #[test]
+    fn test_unlink_skill_from_all_handles_no_symlinks() {
+        // Arrange: Create a temp home directory with no symlinks
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        
+        // Create global skill
+        let skill_dir = home_path.join(".agents/skills/test-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        
+        // Create agent directories but no symlinks
+        let cursor_path = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
+        
+        // Act: Unlink skill from all
+        let result = unlink_skill_from_all_with_home("test-skill", &home_path).expect("unlink_skill_from_all should succeed");
+        
+        // Assert: Success list should be empty (nothing to unlink), no failures
+        assert!(result.success.is_empty(), "Should have no successful unlinks (nothing to unlink)");
+        assert!(result.failed.is_empty(), "Should have no failures");
+    }
+
+    /// Test that unlink_skill_from_all does not remove regular files (only symlinks)
+    /// 
+    /// **Validates: Requirements 1.5, 6.2**
+    #[test]
+    fn test_unlink_skill_from_all_ignores_regular_files() {
+        // Arrange: Create a temp home directory with a regular file instead of symlink
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        
+        // Create global skill
+        let skill_dir = home_path.join(".agents/skills/test-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        
+        // Create agent directory with a regular file (not symlink)
+        let cursor_path = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
+        let cursor_file = cursor_path.join("test-skill");
+        fs::write(&cursor_file, "regular file content").expect("Failed to create regular file");
+        
+        // Act: Unlink skill from all
+        let result = unlink_skill_from_all_with_home("test-skill", &home_path).expect("unlink_skill_from_all should succeed");
+        
+        // Assert: Success list should be empty (regular file is not a symlink)
+        assert!(result.success.is_empty(), "Should have no successful unlinks (file is not a symlink)");
+        assert!(result.failed.is_empty(), "Should have no failures");
+        
+        // Verify regular file still exists
+        assert!(cursor_file.exists(), "Regular file should not be removed");
+    }
+
+    /// Test that unlink_skill_from_all does not remove directories (only symlinks)
+    /// 
+    /// **Validates: Requirements 1.5, 6.2**
+    #[test]
+    fn test_unlink_skill_from_all_ignores_directories() {
+        // Arrange: Create a temp home directory with a directory instead of symlink
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        
+        // Create global skill
+        let skill_dir = home_path.join(".agents/skills/test-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        
+        // Create agent directory with a subdirectory (not symlink)
+        let cursor_path = home_path.join(".cursor/skills");
+        let cursor_subdir = cursor_path.join("test-skill");
+        fs::create_dir_all(&cursor_subdir).expect("Failed to create cursor subdirectory");
+        
+        // Act: Unlink skill from all
+        let result = unlink_skill_from_all_with_home("test-skill", &home_path).expect("unlink_skill_from_all should succeed");
+        
+        // Assert: Success list should be empty (directory is not a symlink)
+        assert!(result.success.is_empty(), "Should have no successful unlinks (directory is not a symlink)");
+        assert!(result.failed.is_empty(), "Should have no failures");
+        
+        // Verify directory still exists
+        assert!(cursor_subdir.exists(), "Directory should not be removed");
+    }
+
+    /// Test that unlink_skill_from_all reports local directories it left in place as skipped
+    #[test]
+    fn test_unlink_skill_from_all_reports_skipped_local_directory() {
+        // Arrange: cursor has a symlink, claude has a local copy
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let skill_dir = home_path.join(".agents/skills/test-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        let cursor_path = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
+        create_symlink(&skill_dir, cursor_path.join("test-skill")).expect("Failed to create cursor symlink");
+        let claude_local = home_path.join(".claude/skills/test-skill");
+        fs::create_dir_all(&claude_local).expect("Failed to create claude local skill");
+
+        // Act
+        let result = unlink_skill_from_all_with_home("test-skill", &home_path).expect("unlink_skill_from_all should succeed");
+
+        // Assert: Only the local directory is reported as skipped, agents without the skill are not
+        assert_eq!(result.success, vec!["cursor".to_string()]);
+        assert_eq!(result.skipped.len(), 1, "Only claude-code should be skipped");
+        assert_eq!(result.skipped[0].agent_id, "claude-code");
+        assert!(result.skipped[0].error.contains("Local directory"), "Reason should mention the local directory");
+        assert!(claude_local.exists(), "Local directory should not be removed");
+    }
+
+    /// Test that unlink_skill_from_all returns BatchResult with correct structure
+    /// 
+    /// **Validates: Requirements 1.5, 6.2**
+    #[test]
+    fn test_unlink_skill_from_all_returns_batch_result() {
+        // Arrange: Create a temp home directory with symlinks
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        
+        // Create global skill
+        let skill_dir = home_path.join(".agents/skills/test-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        
+        // Create agent directories and symlinks
+        let cursor_path = home_path.join(".cursor/skills");
+        let claude_path = home_path.join(".claude/skills");
+        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
+        fs::create_dir_all(&claude_path).expect("Failed to create claude directory");
+        
+        // Create symlinks
+        create_symlink(&skill_dir, cursor_path.join("test-skill")).expect("Failed to create cursor symlink");
+        create_symlink(&skill_dir, claude_path.join("test-skill")).expect("Failed to create claude symlink");
+        
+        // Act: Unlink skill from all
+        let result = unlink_skill_from_all_with_home("test-skill", &home_path).expect("unlink_skill_from_all should succeed");
+        
+        // Assert: BatchResult should have correct structure
+        assert_eq!(result.success.len(), 2, "Should have 2 successful unlinks");
+        assert!(result.failed.is_empty(), "Should have no failures");
+        
+        // Verify success list contains agent IDs (strings)
+        for agent_id in &result.success {
+            assert!(!agent_id.is_empty(), "Agent ID should not be empty");
+        }
+    }
+
+    /// Test that unlink_skill_from_all handles mixed states (some symlinks, some not)
+    /// 
+    /// **Validates: Requirements 1.5, 6.2**
+    #[test]
+    fn test_unlink_skill_from_all_handles_mixed_states() {
+        // Arrange: Create a temp home directory with mixed states
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        
+        // Create global skill
+        let skill_dir = home_path.join(".agents/skills/test-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        
+        // Create agent directories
+        let cursor_path = home_path.join(".cursor/skills");
+        let claude_path = home_path.join(".claude/skills");
+        let amp_path = home_path.join(".config/agents/skills");
+        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
+        fs::create_dir_all(&claude_path).expect("Failed to create claude directory");
+        fs::create_dir_all(&amp_path).expect("Failed to create amp directory");
+        
+        // cursor: has symlink
+        let cursor_symlink = cursor_path.join("test-skill");
+        create_symlink(&skill_dir, &cursor_symlink).expect("Failed to create cursor symlink");
+        
+        // claude: has regular file
+        let claude_file = claude_path.join("test-skill");
+        fs::write(&claude_file, "regular file").expect("Failed to create claude file");
+        
+        // amp: no file at all
+        
+        // Act: Unlink skill from all
+        let result = unlink_skill_from_all_with_home("test-skill", &home_path).expect("unlink_skill_from_all should succeed");
+        
+        // Assert: Only cursor should be in success list
+        assert_eq!(result.success.len(), 1, "Should have 1 successful unlink");
+        assert!(result.success.contains(&"cursor".to_string()), "cursor should be in success list");
+        assert!(result.failed.is_empty(), "Should have no failures");
+        
+        // Verify cursor symlink was removed
+        assert!(!cursor_symlink.exists(), "Cursor symlink should be removed");
+        
+        // Verify claude file still exists
+        assert!(claude_file.exists(), "Claude regular file should not be removed");
+    }
+
+    /// Test that verified unlinking only removes links that resolve to the global skill
+    #[test]
+    fn test_unlink_skill_from_all_verified_keeps_foreign_links() {
+        // Arrange: cursor links the global skill, amp has a same-named link to an unrelated directory
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let skill_dir = home_path.join(".agents/skills/test-skill");
+        let unrelated_dir = home_path.join("elsewhere/test-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        fs::create_dir_all(&unrelated_dir).expect("Failed to create unrelated directory");
+        let cursor_link = home_path.join(".cursor/skills/test-skill");
+        let amp_link = home_path.join(".config/agents/skills/test-skill");
+        for link in [&cursor_link, &amp_link] {
+            fs::create_dir_all(link.parent().unwrap()).expect("Failed to create agent directory");
+        }
+        create_symlink(&skill_dir, &cursor_link).expect("Failed to create cursor symlink");
+        create_symlink(&unrelated_dir, &amp_link).expect("Failed to create amp symlink");
+
+        // Act
+        let result = unlink_skill_from_all_verified_with_home("test-skill", &home_path).expect("unlink should succeed");
+
+        // Assert: The matching link is removed, the foreign one is reported and kept
+        assert_eq!(result.success, vec!["cursor".to_string()]);
+        assert_eq!(result.failed.len(), 1);
+        assert_eq!(result.failed[0].agent_id, "amp");
+        assert!(result.failed[0].error.contains("not the global skill"));
+        assert!(fs::symlink_metadata(&cursor_link).is_err());
+        assert_eq!(fs::read_link(&amp_link).expect("Foreign link should remain"), unrelated_dir);
+    }
+
+    /// Test that a verified dry run reports a foreign link as failed, matching the real run
+    #[test]
+    fn test_unlink_skill_from_all_verified_dry_run_reports_foreign_links() {
+        // Arrange: cursor links the global skill, amp has a same-named link to an unrelated directory
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let skill_dir = home_path.join(".agents/skills/test-skill");
+        let unrelated_dir = home_path.join("elsewhere/test-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        fs::create_dir_all(&unrelated_dir).expect("Failed to create unrelated directory");
+        let cursor_link = home_path.join(".cursor/skills/test-skill");
+        let amp_link = home_path.join(".config/agents/skills/test-skill");
+        for link in [&cursor_link, &amp_link] {
+            fs::create_dir_all(link.parent().unwrap()).expect("Failed to create agent directory");
+        }
+        create_symlink(&skill_dir, &cursor_link).expect("Failed to create cursor symlink");
+        create_symlink(&unrelated_dir, &amp_link).expect("Failed to create amp symlink");
+
+        // Act
+        let preview = unlink_skill_from_all_verified_dry_run_with_home("test-skill", &home_path)
+            .expect("dry run should succeed");
+        let cursor_kept = fs::symlink_metadata(&cursor_link).is_ok();
+        let result = unlink_skill_from_all_verified_with_home("test-skill", &home_path).expect("unlink should succeed");
+
+        // Assert: The preview flags amp as failed, leaves links alone, and matches the real run
+        assert!(cursor_kept);
+        assert_eq!(preview.success, vec!["cursor".to_string()]);
+        assert_eq!(preview.failed.len(), 1);
+        assert_eq!(preview.failed[0].agent_id, "amp");
+        assert_eq!(preview.success, result.success);
+        assert_eq!(preview.failed[0].agent_id, result.failed[0].agent_id);
+    }
+
+    /// Test that unlinking from all is refused without a valid confirmation token and runs with one
+    #[test]
+    fn test_unlink_skill_from_all_requires_confirm_token() {
+        // Arrange: A global skill linked into cursor
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let skill_dir = home_path.join(".agents/skills/test-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        let cursor_path = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
+        create_symlink(&skill_dir, cursor_path.join("test-skill")).expect("Failed to create symlink");
+
+        // Act
+        let preview = unlink_skill_from_all_preview_with_home("test-skill", &home_path);
+        let missing = unlink_skill_from_all_confirmed_with_home("test-skill", None, &home_path);
+        let wrong = unlink_skill_from_all_confirmed_with_home("test-skill", Some("0000000000000000"), &home_path);
+
+        // Assert: Both attempts are refused and nothing was removed
+        assert_eq!(preview.affected, vec!["cursor".to_string()]);
+        assert!(missing.is_err() && wrong.is_err());
+        assert!(fs::symlink_metadata(cursor_path.join("test-skill")).is_ok(), "Refused unlink must not remove links");
+
+        // Act: Confirm with the preview's token
+        let result = unlink_skill_from_all_confirmed_with_home("test-skill", Some(&preview.confirm_token), &home_path)
+            .expect("confirmed unlink should succeed");
+
+        // Assert
+        assert_eq!(result.success, vec!["cursor".to_string()]);
+        assert!(fs::symlink_metadata(cursor_path.join("test-skill")).is_err());
+    }
+
+    /// Test that dangling links, inside or outside the global directory, are reported as broken
+    #[test]
+    fn test_broken_symlinks_reported_in_agent_detail() {
+        // Arrange: One link to a deleted global skill and one to a missing project directory
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        fs::create_dir_all(home_path.join(".agents/skills")).expect("Failed to create global directory");
+        let cursor_dir = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_dir).expect("Failed to create cursor directory");
+        create_symlink(home_path.join(".agents/skills/deleted"), cursor_dir.join("deleted")).expect("Failed to create symlink");
+        create_symlink(home_path.join("projects/gone"), cursor_dir.join("outside")).expect("Failed to create symlink");
+
+        // Act
+        let detail = get_agent_detail_with_home("cursor", &home_path).expect("detail should succeed");
+        let broken = find_broken_symlinks_with_home(&home_path);
+
+        // Assert
+        let statuses: Vec<(&str, AgentSkillStatus)> = detail.skills.iter().map(|s| (s.name.as_str(), s.status.clone())).collect();
+        assert_eq!(statuses, vec![
+            ("deleted", AgentSkillStatus::BrokenSymlink),
+            ("outside", AgentSkillStatus::BrokenSymlink),
+        ]);
+        assert_eq!(detail.skills[0].metadata.name, "deleted");
+        assert_eq!(broken, vec![
+            ("cursor".to_string(), "deleted".to_string()),
+            ("cursor".to_string(), "outside".to_string()),
+        ]);
+    }
+
+    // ==================== get_dashboard Tests ====================
+
+    /// Test that the dashboard bundle is consistent with the individual queries
+    #[test]
+    fn test_dashboard_matches_individual_calls() {
+        // Arrange: Two global skills, one linked to cursor, plus a broken link in claude
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+
+        let linked_dir = home_path.join(".agents/skills/linked-skill");
+        fs::create_dir_all(&linked_dir).expect("Failed to create skill directory");
+        fs::create_dir_all(home_path.join(".agents/skills/unused-skill")).expect("Failed to create skill directory");
+
+        let cursor_path = home_path.join(".cursor/skills");
+        let claude_path = home_path.join(".claude/skills");
+        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
+        fs::create_dir_all(&claude_path).expect("Failed to create claude directory");
+        create_symlink(&linked_dir, cursor_path.join("linked-skill")).expect("Failed to create symlink");
+        create_symlink(home_path.join("missing"), claude_path.join("gone-skill")).expect("Failed to create broken symlink");
+        fs::create_dir_all(claude_path.join("local-skill")).expect("Failed to create local skill");
+
+        // Act
+        let dashboard = get_dashboard_with_home(&home_path);
+
+        // Assert: Each part equals the dedicated call
+        let app_data = get_app_data_with_home(&home_path);
+        assert_eq!(dashboard.app_data.skills.len(), app_data.skills.len());
+        assert_eq!(dashboard.total_agent_count, app_data.agents.len());
+        assert_eq!(dashboard.detected_agent_count, 2);
+        assert_eq!(dashboard.agent_skill_counts, count_agent_skills_with_home(&home_path));
+        assert_eq!(dashboard.unused_skills, find_unused_skills_with_home(&home_path));
+        assert_eq!(dashboard.unused_skills, vec!["unused-skill".to_string()]);
+        assert_eq!(dashboard.broken_link_count, find_broken_symlinks_with_home(&home_path).len());
+        assert_eq!(dashboard.broken_link_count, 1);
+
+        let claude_count = dashboard.agent_skill_counts.iter().find(|c| c.agent_id == "claude-code").expect("claude-code count missing");
+        assert_eq!(claude_count.count, 2, "Broken symlink and local directory both count as installed");
+    }
+
+    // ==================== all_installed_skills Tests ====================
+
+    /// Test that installs across two agents are aggregated with their statuses, sorted
+    #[test]
+    fn test_all_installed_skills_across_agents() {
+        // Arrange: cursor links one skill and has a local one; claude has a local copy
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        fs::create_dir_all(global_dir.join("shared")).expect("Failed to create skill directory");
+        fs::create_dir_all(global_dir.join("unused")).expect("Failed to create skill directory");
+        let cursor_dir = home_path.join(".cursor/skills");
+        fs::create_dir_all(cursor_dir.join("cursor-only")).expect("Failed to create local skill");
+        create_symlink(global_dir.join("shared"), cursor_dir.join("shared")).expect("Failed to create symlink");
+        fs::create_dir_all(home_path.join(".claude/skills/shared")).expect("Failed to create local skill");
+
+        // Act
+        let installed = all_installed_skills_with_home(&home_path);
+
+        // Assert
+        assert_eq!(installed, vec![
+            ("claude-code".to_string(), "shared".to_string(), AgentSkillStatus::Local),
+            ("cursor".to_string(), "cursor-only".to_string(), AgentSkillStatus::Local),
+            ("cursor".to_string(), "shared".to_string(), AgentSkillStatus::Symlink),
+        ]);
+    }
+
+    // ==================== Multi-root Global Path Tests ====================
+
+    /// Test that an upload can target any configured root, and only a configured one
+    #[test]
+    fn test_upload_to_global_root_picks_configured_root() {
+        // Arrange: A personal and a team root, and a local cursor skill
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let team_root = home_path.join("team/skills");
+        let config = AppConfig {
+            global_paths: vec!["~/.agents/skills".to_string(), "team/skills".to_string()],
+            ..Default::default()
+        };
+        config::save_config_with_home(&config, &home_path).expect("Failed to save config");
+        fs::create_dir_all(home_path.join(".cursor/skills/team-skill")).expect("Failed to create local skill");
+
+        // Act
+        let elsewhere = upload_to_global_root_with_home("cursor", "team-skill", Some("~/elsewhere"), &home_path);
+        let uploaded = upload_to_global_root_with_home(
+            "cursor",
+            "team-skill",
+            Some(&team_root.to_string_lossy()),
+            &home_path,
+        );
+
+        // Assert
+        assert_eq!(elsewhere, Err(SkillError::RootNotFound("~/elsewhere".to_string())));
+        assert_eq!(uploaded, Ok(()));
+        assert!(team_root.join("team-skill").is_dir());
+        assert!(!home_path.join(".agents/skills/team-skill").exists());
+    }
+
+    /// Test that skills are merged across global roots with the first root winning
+    #[test]
+    fn test_get_app_data_merges_multiple_roots() {
+        // Arrange: A personal root and a team root, both containing "shared-skill"
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+
+        let personal_root = home_path.join(".agents/skills");
+        let team_root = home_path.join("team/skills");
+        fs::create_dir_all(personal_root.join("shared-skill")).expect("Failed to create personal skill");
+        fs::create_dir_all(team_root.join("shared-skill")).expect("Failed to create team skill");
+        fs::create_dir_all(team_root.join("team-only")).expect("Failed to create team skill");
+        fs::write(personal_root.join("shared-skill/SKILL.md"), "---\nname: Personal\n---\n").expect("Failed to write SKILL.md");
+        fs::write(team_root.join("shared-skill/SKILL.md"), "---\nname: Team\n---\n").expect("Failed to write SKILL.md");
+
+        let config = AppConfig {
+            global_paths: vec!["~/.agents/skills".to_string(), "team/skills".to_string()],
+            ..Default::default()
+        };
+        config::save_config_with_home(&config, &home_path).expect("Failed to save config");
+
+        let cursor_path = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
+
+        // Act
+        let app_data = get_app_data_with_home(&home_path);
+
+        // Assert: One entry per name, first root wins, shadowed duplicate recorded
+        assert_eq!(app_data.skills.len(), 2, "Duplicate names should be merged");
+        let shared = app_data.skills.iter().find(|s| s.name == "shared-skill").expect("shared-skill not found");
+        assert_eq!(shared.metadata.name, "Personal", "First root should win");
+        assert_eq!(shared.root, personal_root.to_string_lossy());
+        assert_eq!(shared.shadowed_roots, vec![team_root.to_string_lossy().to_string()]);
+
+        // Assert: Linking targets the root where each skill was found
+        link_skill_to_all_with_home("shared-skill", &home_path).expect("link should succeed");
+        link_skill_to_all_with_home("team-only", &home_path).expect("link should succeed");
+        assert_eq!(fs::read_link(cursor_path.join("shared-skill")).expect("Should be a symlink"), personal_root.join("shared-skill"));
+        assert_eq!(fs::read_link(cursor_path.join("team-only")).expect("Should be a symlink"), team_root.join("team-only"));
+
+        // Assert: Agent detail treats skills from every root as global
+        let detail = get_agent_detail_with_home("cursor", &home_path).expect("detail should succeed");
+        assert!(detail.skills.iter().all(|s| s.in_global), "All skills should be in global");
+    }
+
+    /// Test that the compact payload keeps names and counts but drops tools and long descriptions
+    #[test]
+    fn test_get_app_data_compact_drops_heavy_fields() {
+        // Arrange: A skill with tools and a long multi-line description, linked into cursor
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let skill_dir = home_path.join(".agents/skills/review");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        let long_line = "word ".repeat(40);
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            format!("---\nname: Review\ndescription: |\n  {}\n  Second line.\nallowed-tools:\n  - Read\n  - Bash\n---\n", long_line),
+        ).expect("Failed to write SKILL.md");
+        let cursor_path = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
+        create_symlink(&skill_dir, cursor_path.join("review")).expect("Failed to create symlink");
+
+        // Act
+        let full = serde_json::to_value(get_app_data_with_home(&home_path)).unwrap();
+        let compact = serde_json::to_value(get_app_data_compact_with_home(&home_path)).unwrap();
+
+        // Assert: Same agents and skills, without the heavy fields
+        assert_eq!(compact["agents"], full["agents"]);
+        let skill = &compact["skills"][0];
+        assert_eq!(skill["name"], "review");
+        assert_eq!(skill["installed_count"], 1);
+        assert!(skill.get("metadata").is_none() && skill.get("allowed_tools").is_none());
+        let summary = skill["summary"].as_str().unwrap();
+        assert_eq!(summary.chars().count(), SUMMARY_MAX_CHARS);
+        assert!(summary.ends_with("...") && !summary.contains("Second line"));
+        assert!(compact.to_string().len() < full.to_string().len());
+    }
+
+    /// Test that the streamed scan reports every agent and skill, adding up to the full result
+    #[test]
+    fn test_scan_app_data_events_sum_to_full_result() {
+        // Arrange: Two roots sharing a skill, and a linked agent
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let personal_root = home_path.join(".agents/skills");
+        let team_root = home_path.join("team/skills");
+        for dir in [personal_root.join("shared-skill"), team_root.join("shared-skill"), team_root.join("team-only")] {
+            fs::create_dir_all(&dir).expect("Failed to create skill directory");
+        }
+        let cursor_path = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
+        create_symlink(team_root.join("team-only"), cursor_path.join("team-only")).expect("Failed to create symlink");
+        let roots = vec![personal_root.clone(), team_root.clone()];
+
+        // Act
+        let mut agents: Vec<Agent> = Vec::new();
+        let mut skills: Vec<Skill> = Vec::new();
+        let mut completed = 0;
+        let result = scan_app_data_with_roots(&home_path, &roots, |event| match event {
+            ScanEvent::AgentDetected(agent) => agents.push(agent.clone()),
+            ScanEvent::SkillLoaded(skill) => skills.push(skill.clone()),
+            ScanEvent::ScanComplete => completed += 1,
+        });
+
+        // Assert: The events add up to exactly what get_app_data returns
+        let full = get_app_data_with_roots(&home_path, &roots);
+        assert_eq!(completed, 1);
+        assert_eq!(agents, full.agents);
+        assert_eq!(
+            serde_json::to_value(&skills).unwrap(),
+            serde_json::to_value(&full.skills).unwrap()
+        );
+        assert_eq!(serde_json::to_value(&result.skills).unwrap(), serde_json::to_value(&full.skills).unwrap());
+        let shared = skills.iter().find(|s| s.name == "shared-skill").expect("shared-skill not emitted");
+        assert_eq!(shared.shadowed_roots, vec![team_root.to_string_lossy().to_string()]);
+    }
+
+    /// Test that a custom order puts listed skills first and appends the rest alphabetically
+    #[test]
+    fn test_get_agent_detail_custom_order() {
+        // Arrange: Four skills in cursor, two of them in a custom order
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        for name in ["alpha", "beta", "gamma", "delta"] {
+            fs::create_dir_all(home_path.join(".cursor/skills").join(name)).expect("Failed to create skill directory");
+        }
+        let order = vec!["gamma".to_string(), "missing".to_string(), "beta".to_string()];
+        set_agent_skill_order_with_home("cursor", &order, &home_path).expect("set order should succeed");
+
+        // Act
+        let by_name = get_agent_detail_in_order_with_home("cursor", SortOrder::Name, &home_path).expect("detail should succeed");
+        let custom = get_agent_detail_in_order_with_home("cursor", SortOrder::Custom, &home_path).expect("detail should succeed");
+
+        // Assert
+        let names = |detail: &AgentDetailData| detail.skills.iter().map(|s| s.name.clone()).collect::<Vec<_>>();
+        assert_eq!(get_agent_skill_order_with_home("cursor", &home_path), Ok(order));
+        assert_eq!(names(&by_name), vec!["alpha", "beta", "delta", "gamma"]);
+        assert_eq!(names(&custom), vec!["gamma", "beta", "alpha", "delta"]);
+    }
+
+    /// Test that agent detail reports a missing global directory instead of failing
+    #[test]
+    fn test_get_agent_detail_without_global_directory() {
+        // Arrange: An agent with a local skill and no global skills directory at all
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        fs::create_dir_all(home_path.join(".cursor/skills/local-skill")).expect("Failed to create local skill");
+
+        // Act
+        let detail = get_agent_detail_with_home("cursor", &home_path).expect("detail should succeed");
+
+        // Assert
+        assert!(!detail.global_dir_exists);
+        assert_eq!(detail.skills.len(), 1);
+        assert!(!detail.skills[0].in_global);
+
+        // Act & Assert: Creating the directory is reflected
+        fs::create_dir_all(home_path.join(".agents/skills")).expect("Failed to create global directory");
+        let detail = get_agent_detail_with_home("cursor", &home_path).expect("detail should succeed");
+        assert!(detail.global_dir_exists);
+    }
+
+    /// Test that a nested-scan agent lists skills inside plugin directories as `<plugin>/<skill>`
+    #[test]
+    fn test_get_agent_detail_nested_plugin_layout() {
+        // Arrange: A plain skill and a plugin holding two skills
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let claude_dir = home_path.join(".claude/skills");
+        for skill in ["plain", "my-plugin/review", "my-plugin/deploy"] {
+            fs::create_dir_all(claude_dir.join(skill)).expect("Failed to create skill directory");
+            fs::write(claude_dir.join(skill).join("SKILL.md"), "---\nname: Skill\n---\n").expect("Failed to write SKILL.md");
+        }
+        let config = AppConfig { nested_scan_agents: vec!["claude-code".to_string()], ..Default::default() };
+
+        // Act
+        let flat = get_agent_detail_with_home("claude-code", &home_path).expect("detail should succeed");
+        config::save_config_with_home(&config, &home_path).expect("Failed to save config");
+        let nested = get_agent_detail_with_home("claude-code", &home_path).expect("detail should succeed");
+
+        // Assert
+        let names = |detail: &AgentDetailData| detail.skills.iter().map(|s| s.name.clone()).collect::<Vec<_>>();
+        assert_eq!(names(&flat), vec!["my-plugin", "plain"]);
+        assert_eq!(names(&nested), vec!["my-plugin/deploy", "my-plugin/review", "plain"]);
+        assert!(nested.skills.iter().all(|s| s.status == AgentSkillStatus::Local));
+    }
+
+    /// Test that an NFD-named agent skill matches the NFC-named global skill it links to
+    #[test]
+    fn test_get_agent_detail_matches_unicode_normalized_names() {
+        // Arrange: Global "café" in NFC, linked into cursor under its NFD spelling
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let global_skill = home_path.join(".agents/skills/caf\u{e9}");
+        fs::create_dir_all(&global_skill).expect("Failed to create skill directory");
+        let cursor_dir = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_dir).expect("Failed to create cursor directory");
+        create_symlink(&global_skill, cursor_dir.join("cafe\u{301}")).expect("Failed to create symlink");
+
+        // Act
+        let detail = get_agent_detail_with_home("cursor", &home_path).expect("detail should succeed");
+
+        // Assert: One installed entry that is recognized as global
+        assert_eq!(detail.skills.len(), 1, "Skills: {:?}", detail.skills.iter().map(|s| &s.name).collect::<Vec<_>>());
+        assert_eq!(detail.skills[0].status, AgentSkillStatus::Symlink);
+        assert!(detail.skills[0].in_global);
+    }
+
+    /// Test that app data and toggling treat an NFD-named agent link as the NFC-named global skill
+    #[test]
+    fn test_app_data_and_toggle_match_unicode_normalized_names() {
+        // Arrange: Global "café" in NFC, linked into cursor under its NFD spelling
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let global_skill = home_path.join(".agents/skills/caf\u{e9}");
+        fs::create_dir_all(&global_skill).expect("Failed to create skill directory");
+        let cursor_dir = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_dir).expect("Failed to create cursor directory");
+        let nfd_link = cursor_dir.join("cafe\u{301}");
+        create_symlink(&global_skill, &nfd_link).expect("Failed to create symlink");
+
+        // Act
+        let app_data = get_app_data_with_home(&home_path);
+        let enabled_again = toggle_skill_with_home("cursor", "caf\u{e9}", true, &home_path);
+        let disabled = toggle_skill_with_home("cursor", "caf\u{e9}", false, &home_path);
+
+        // Assert: Shown as linked, not linked twice, and unlinked through its NFD spelling
+        let skill = app_data.skills.iter().find(|s| s.name == "caf\u{e9}").expect("café not found");
+        assert_eq!(skill.linked_agents, vec!["cursor".to_string()]);
+        assert_eq!(skill.symlinked_agents, vec!["cursor".to_string()]);
+        assert!(enabled_again.is_err(), "The skill is already linked");
+        assert_eq!(disabled, Ok(()));
+        assert!(fs::symlink_metadata(&nfd_link).is_err());
+        assert!(fs::symlink_metadata(cursor_dir.join("caf\u{e9}")).is_err());
+    }
+
+    /// Test that an agent's `<skill>.override/SKILL.md` replaces the displayed metadata of a symlinked skill
+    #[test]
+    fn test_get_agent_detail_uses_override_metadata() {
+        // Arrange: A global skill linked into cursor, with a cursor-specific override
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let skill_dir = home_path.join(".agents/skills/test-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        fs::write(skill_dir.join("SKILL.md"), "---\nname: Test Skill\ndescription: Global description\n---\n")
+            .expect("Failed to write SKILL.md");
+        let cursor_path = home_path.join(".cursor/skills");
+        fs::create_dir_all(cursor_path.join("test-skill.override")).expect("Failed to create override directory");
+        fs::write(
+            cursor_path.join("test-skill.override/SKILL.md"),
+            "---\nname: Test Skill\ndescription: Cursor description\n---\n",
+        ).expect("Failed to write override SKILL.md");
+        create_symlink(&skill_dir, cursor_path.join("test-skill")).expect("Failed to create symlink");
+
+        // Act
+        let detail = get_agent_detail_with_home("cursor", &home_path).expect("detail should succeed");
+
+        // Assert: Override metadata shown, link untouched, override directory not listed as a skill
+        assert_eq!(detail.skills.len(), 1, "Override directory should not be listed: {:?}", detail.skills);
+        let skill = &detail.skills[0];
+        assert!(skill.has_override);
+        assert_eq!(skill.metadata.description, "Cursor description");
+        assert_eq!(skill.status, AgentSkillStatus::Symlink);
+        assert_eq!(fs::read_link(cursor_path.join("test-skill")).expect("Should be a symlink"), skill_dir);
+    }
+
+    /// Test that the tool surface counts tools across an agent's linked and local skills only
+    #[test]
+    fn test_agent_tool_surface_aggregates_installed_skills() {
+        // Arrange: A linked global skill, a local skill, and a global skill that is not installed
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        let cursor_path = home_path.join(".cursor/skills");
+        for (dir, content) in [
+            (global_dir.join("linked"), "---\nname: Linked\nallowed-tools:\n  - Read\n  - Bash\n---\n"),
+            (global_dir.join("unused"), "---\nname: Unused\nallowed-tools:\n  - WebFetch\n---\n"),
+            (cursor_path.join("local"), "---\nname: Local\nallowed-tools:\n  - Read\n---\n"),
+        ] {
+            fs::create_dir_all(&dir).expect("Failed to create skill directory");
+            fs::write(dir.join("SKILL.md"), content).expect("Failed to write SKILL.md");
+        }
+        create_symlink(global_dir.join("linked"), cursor_path.join("linked")).expect("Failed to create symlink");
+
+        // Act
+        let surface = agent_tool_surface_with_home("cursor", &home_path).expect("surface should succeed");
+
+        // Assert
+        assert_eq!(surface, vec![("Bash".to_string(), 1), ("Read".to_string(), 2)]);
+    }
+
+    /// Test that a tool the agent does not support is reported, and that linking can refuse it
+    #[test]
+    fn test_check_skill_agent_compatibility_reports_unsupported_tool() {
+        // Arrange: cursor supports Read and Bash only; the skill also wants WebFetch
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let skill_dir = home_path.join(".agents/skills/fetcher");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        fs::write(skill_dir.join("SKILL.md"), "---\nname: Fetcher\nallowed-tools:\n  - Read\n  - Bash(git:*)\n  - WebFetch\n---\n")
+            .expect("Failed to write SKILL.md");
+        fs::create_dir_all(home_path.join(".cursor/skills")).expect("Failed to create cursor directory");
+        fs::create_dir_all(home_path.join(".claude/skills")).expect("Failed to create claude directory");
+        let config = AppConfig {
+            supported_tools: [("cursor".to_string(), vec!["Read".to_string(), "Bash".to_string()])].into_iter().collect(),
+            ..Default::default()
+        };
+        config::save_config_with_home(&config, &home_path).expect("Failed to save config");
+
+        // Act
+        let cursor = check_skill_agent_compatibility_with_home("fetcher", "cursor", &home_path);
+        let claude = check_skill_agent_compatibility_with_home("fetcher", "claude-code", &home_path);
+
+        // Assert: Only cursor has a restriction
+        assert_eq!(cursor, Ok(vec!["WebFetch".to_string()]));
+        assert_eq!(claude, Ok(Vec::new()));
+
+        // Act & Assert: Refusing leaves cursor unlinked; warning links it and reports the tool
+        let error = link_skill_checked_with_home("cursor", "fetcher", true, &home_path).expect_err("should refuse");
+        assert!(error.contains("WebFetch"), "Unexpected error: {}", error);
+        assert!(fs::symlink_metadata(home_path.join(".cursor/skills/fetcher")).is_err());
+        let warnings = link_skill_checked_with_home("cursor", "fetcher", false, &home_path).expect("should link");
+        assert_eq!(warnings, vec!["WebFetch".to_string()]);
+        assert!(fs::symlink_metadata(home_path.join(".cursor/skills/fetcher")).is_ok());
+    }
+
+    // ==================== resolve_agent_skill Tests ====================
+
+    /// Test that a symlinked skill resolves to the global library with its metadata
+    #[test]
+    fn test_resolve_agent_skill_follows_symlink() {
+        // Arrange
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let skill_dir = home_path.join(".agents/skills/test-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        fs::write(skill_dir.join("SKILL.md"), "---\nname: Resolved Skill\ndescription: From global\n---\n")
+            .expect("Failed to write SKILL.md");
+        let cursor_path = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
+        create_symlink(&skill_dir, cursor_path.join("test-skill")).expect("Failed to create symlink");
+
+        // Act
+        let resolved = resolve_agent_skill_with_home("cursor", "test-skill", &home_path).expect("resolve should succeed");
+
+        // Assert
+        assert_eq!(resolved.status, AgentSkillStatus::Symlink);
+        assert_eq!(resolved.link_target, Some(skill_dir.to_string_lossy().to_string()));
+        assert_eq!(resolved.resolved_path, fs::canonicalize(&skill_dir).unwrap().to_string_lossy());
+        assert!(resolved.in_global_library);
+        assert_eq!(resolved.metadata.name, "Resolved Skill");
+    }
+
+    /// Test that a broken symlink is reported as an error naming the missing target
     #[test]
-    fn test_agent_detected_when_directory_exists() {
-        // Arrange: Create a temp home directory with a cursor skills directory
+    fn test_resolve_agent_skill_broken_symlink_errors() {
+        // Arrange
         let temp_home = create_temp_home();
         let home_path = temp_home.path().to_path_buf();
-        
-        // Create the cursor skills directory
-        let cursor_skills_path = home_path.join(".cursor/skills");
-        fs::create_dir_all(&cursor_skills_path).expect("Failed to create cursor skills directory");
-        
-        // Act: Detect agents
-        let agents = detect_agents_with_home(&home_path);
-        
-        // Assert: Cursor agent should be detected
-        let cursor_agent = agents.iter().find(|a| a.id == "cursor").expect("Cursor agent not found");
-        assert!(cursor_agent.detected, "Cursor agent should be detected when directory exists");
-        assert_eq!(cursor_agent.path, ".cursor/skills");
-        assert_eq!(cursor_agent.name, "Cursor");
+        let cursor_path = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
+        let missing = home_path.join(".agents/skills/gone");
+        create_symlink(&missing, cursor_path.join("gone")).expect("Failed to create symlink");
+
+        // Act
+        let result = resolve_agent_skill_with_home("cursor", "gone", &home_path);
+
+        // Assert
+        let error = result.expect_err("Broken symlink should error");
+        assert!(error.contains("Broken symlink"), "Unexpected error: {}", error);
+        assert!(error.contains(&missing.to_string_lossy().to_string()));
     }
 
-    /// Test that agent is marked as not detected when directory doesn't exist
-    /// 
-    /// **Validates: Requirements 3.3**
-    /// - 3.3: WHEN an agent's skills directory does not exist, THE Skills_Manager SHALL mark the agent as not detected
+    // ==================== find_duplicate_skills Tests ====================
+
+    /// Test that byte-identical skills under different names are grouped together
     #[test]
-    fn test_agent_not_detected_when_directory_does_not_exist() {
-        // Arrange: Create an empty temp home directory (no agent directories)
+    fn test_find_duplicate_skills_groups_identical_copies() {
+        // Arrange: Two identical copies and one skill with different content
         let temp_home = create_temp_home();
         let home_path = temp_home.path().to_path_buf();
-        
-        // Act: Detect agents
-        let agents = detect_agents_with_home(&home_path);
-        
-        // Assert: All agents should be not detected
-        for agent in &agents {
-            assert!(!agent.detected, "Agent {} should not be detected when directory doesn't exist", agent.id);
+        let global_dir = home_path.join(".agents/skills");
+        for name in ["review-copy", "review", "other"] {
+            fs::create_dir_all(global_dir.join(name).join("scripts")).expect("Failed to create skill directory");
+            fs::write(global_dir.join(name).join("scripts/run.sh"), "echo review").expect("Failed to write script");
         }
-        
-        // Verify we have all 27 agents
-        assert_eq!(agents.len(), 27, "Should have 27 agent definitions");
+        for name in ["review-copy", "review"] {
+            fs::write(global_dir.join(name).join("SKILL.md"), "---\nname: Review\n---\n").expect("Failed to write SKILL.md");
+        }
+        fs::write(global_dir.join("other/SKILL.md"), "---\nname: Other\n---\n").expect("Failed to write SKILL.md");
+
+        // Act
+        let groups = find_duplicate_skills_with_home(&home_path);
+
+        // Assert
+        assert_eq!(groups, vec![vec!["review".to_string(), "review-copy".to_string()]]);
     }
 
-    /// Test detection for multiple agents with mixed existence states
-    /// 
-    /// **Validates: Requirements 3.2, 3.3**
-    /// - 3.2: WHEN detecting agents, THE Skills_Manager SHALL check if each agent's skills directory exists
-    /// - 3.3: WHEN an agent's skills directory does not exist, THE Skills_Manager SHALL mark the agent as not detected
+    /// Test that skills declaring the same metadata name are grouped by that name
     #[test]
-    fn test_mixed_agent_detection_states() {
-        // Arrange: Create a temp home directory with some agent directories
+    fn test_find_metadata_name_collisions_groups_shared_names() {
+        // Arrange: Two different skills both named "Git Helper", and one uniquely named skill
         let temp_home = create_temp_home();
         let home_path = temp_home.path().to_path_buf();
-        
-        // Create directories for specific agents
-        let agents_to_create = vec![
-            ".cursor/skills",           // cursor
-            ".claude/skills",           // claude-code
-            ".config/agents/skills",    // amp
-            ".gemini/skills",           // gemini-cli
-        ];
-        
-        for path in &agents_to_create {
-            let full_path = home_path.join(path);
-            fs::create_dir_all(&full_path).expect(&format!("Failed to create directory: {}", path));
+        let global_dir = home_path.join(".agents/skills");
+        for (dir, content) in [
+            ("git-helper", "---\nname: Git Helper\ndescription: Commits\n---\n"),
+            ("git-tools", "---\nname: Git Helper\ndescription: Branches\n---\n"),
+            ("review", "---\nname: Review\n---\n"),
+        ] {
+            fs::create_dir_all(global_dir.join(dir)).expect("Failed to create skill directory");
+            fs::write(global_dir.join(dir).join("SKILL.md"), content).expect("Failed to write SKILL.md");
         }
-        
-        // Act: Detect agents
-        let agents = detect_agents_with_home(&home_path);
-        
-        // Assert: Check specific agents are detected
-        let detected_ids = vec!["cursor", "claude-code", "amp", "gemini-cli"];
-        let not_detected_ids = vec!["cline", "codex", "neovate", "windsurf"];
-        
-        for id in detected_ids {
-            let agent = agents.iter().find(|a| a.id == id).expect(&format!("Agent {} not found", id));
-            assert!(agent.detected, "Agent {} should be detected", id);
+
+        // Act
+        let collisions = find_metadata_name_collisions_with_home(&home_path);
+
+        // Assert
+        assert_eq!(collisions, vec![(
+            "Git Helper".to_string(),
+            vec!["git-helper".to_string(), "git-tools".to_string()],
+        )]);
+    }
+
+    // ==================== library_quality_report Tests ====================
+
+    /// Test that the quality report flags parse source and missing fields per skill
+    #[test]
+    fn test_library_quality_report_completeness() {
+        // Arrange: A complete frontmatter skill, a heading-only skill and a skill without SKILL.md
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        for name in ["complete", "heading", "empty"] {
+            fs::create_dir_all(global_dir.join(name)).expect("Failed to create skill directory");
         }
-        
-        for id in not_detected_ids {
-            let agent = agents.iter().find(|a| a.id == id).expect(&format!("Agent {} not found", id));
-            assert!(!agent.detected, "Agent {} should not be detected", id);
+        fs::write(
+            global_dir.join("complete/SKILL.md"),
+            "---\nname: Complete\ndescription: Has it all\nallowed-tools:\n  - Read\ntags:\n  - docs\n---\n",
+        ).expect("Failed to write SKILL.md");
+        fs::write(global_dir.join("heading/SKILL.md"), "# Heading Skill\n\nJust a description.\n").expect("Failed to write SKILL.md");
+
+        // Act
+        let report = library_quality_report_with_home(&home_path);
+
+        // Assert
+        assert_eq!(report, vec![
+            SkillQuality {
+                name: "complete".to_string(),
+                parse_source: Some(ParseSource::Frontmatter),
+                has_name: true,
+                has_description: true,
+                has_allowed_tools: true,
+                has_tags: true,
+            },
+            SkillQuality {
+                name: "empty".to_string(),
+                parse_source: None,
+                has_name: false,
+                has_description: false,
+                has_allowed_tools: false,
+                has_tags: false,
+            },
+            SkillQuality {
+                name: "heading".to_string(),
+                parse_source: Some(ParseSource::Heading),
+                has_name: true,
+                has_description: true,
+                has_allowed_tools: false,
+                has_tags: false,
+            },
+        ]);
+    }
+
+    // ==================== library_stats Tests ====================
+
+    /// Test that library statistics aggregate sizes, files and distinct metadata values
+    #[test]
+    fn test_library_stats_small_library() {
+        // Arrange: Two skills sharing a tool and a tag, and one skill without SKILL.md
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        let skill_a = "---\nname: A\nallowed-tools:\n  - Read\n  - Bash\ntags:\n  - git\n---\n";
+        let skill_b = "---\nname: B\nallowed-tools:\n  - Read\ntags:\n  - git\n  - review\n---\n";
+        fs::create_dir_all(global_dir.join("a/scripts")).expect("Failed to create skill a");
+        fs::write(global_dir.join("a/SKILL.md"), skill_a).expect("Failed to write SKILL.md");
+        fs::write(global_dir.join("a/scripts/run.sh"), "echo a").expect("Failed to write script");
+        fs::create_dir_all(global_dir.join("b")).expect("Failed to create skill b");
+        fs::write(global_dir.join("b/SKILL.md"), skill_b).expect("Failed to write SKILL.md");
+        fs::create_dir_all(global_dir.join("c")).expect("Failed to create skill c");
+        fs::write(global_dir.join("c/notes.txt"), "notes").expect("Failed to write notes");
+
+        // Act
+        let stats = library_stats_with_home(&home_path);
+
+        // Assert
+        assert_eq!(stats, LibraryStats {
+            total_skills: 3,
+            total_size: (skill_a.len() + "echo a".len() + skill_b.len() + "notes".len()) as u64,
+            total_files: 4,
+            missing_skill_md: 1,
+            distinct_tools: 2,
+            distinct_tags: 2,
+        });
+    }
+
+    // ==================== recently_modified_skills Tests ====================
+
+    /// Test that every query term must appear in the name or description, ignoring case
+    #[test]
+    fn test_search_skills_matches_all_terms() {
+        // Arrange
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        for (name, title, description) in [
+            ("pr-review", "PR Review", "Reviews pull requests for style issues"),
+            ("commit", "Commit Helper", "Writes commit messages"),
+            ("lint", "Linter", "Checks STYLE in source files"),
+        ] {
+            fs::create_dir_all(global_dir.join(name)).expect("Failed to create skill directory");
+            fs::write(global_dir.join(name).join("SKILL.md"), format!("---\nname: {}\ndescription: {}\n---\n", title, description))
+                .expect("Failed to write SKILL.md");
         }
-        
-        // Verify total count
-        assert_eq!(agents.len(), 27, "Should have 27 agent definitions");
-        
-        // Count detected vs not detected
-        let detected_count = agents.iter().filter(|a| a.detected).count();
-        let not_detected_count = agents.iter().filter(|a| !a.detected).count();
-        
-        assert_eq!(detected_count, 4, "Should have 4 detected agents");
-        assert_eq!(not_detected_count, 23, "Should have 23 not detected agents");
+        let names = |query: &str| -> Vec<String> {
+            search_skills_with_home(query, &home_path).into_iter().map(|skill| skill.name).collect()
+        };
+
+        // Act & Assert
+        assert_eq!(names("style"), vec!["lint".to_string(), "pr-review".to_string()]);
+        assert_eq!(names("review  STYLE"), vec!["pr-review".to_string()]);
+        assert_eq!(names("commit style"), Vec::<String>::new());
+        assert_eq!(names(" "), vec!["commit".to_string(), "lint".to_string(), "pr-review".to_string()]);
     }
 
-    /// Test that agent definitions contain correct data
+    /// Test that only global skills the agent does not have are offered for installation
     #[test]
-    fn test_agent_definitions_are_complete() {
-        let definitions = get_agent_definition_list();
-        
-        // Verify we have all 27 agents
-        assert_eq!(definitions.len(), 27, "Should have 27 agent definitions");
-        
-        // Verify each definition has non-empty values
-        for (id, name, path) in &definitions {
-            assert!(!id.is_empty(), "Agent ID should not be empty");
-            assert!(!name.is_empty(), "Agent name should not be empty");
-            assert!(!path.is_empty(), "Agent path should not be empty");
+    fn test_available_skills_for_agent_excludes_installed() {
+        // Arrange: Two global skills and a hidden directory; cursor already links one
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        for name in ["installed", "missing", ".hidden"] {
+            fs::create_dir_all(global_dir.join(name)).expect("Failed to create skill directory");
         }
-        
-        // Verify specific agents exist with correct paths
-        let expected_agents = vec![
-            ("cursor", "Cursor", ".cursor/skills"),
-            ("claude-code", "Claude Code", ".claude/skills"),
-            ("windsurf", "Windsurf", ".codeium/windsurf/skills"),
-            ("pi", "Pi", ".pi/agent/skills"),
-        ];
-        
-        for (expected_id, expected_name, expected_path) in expected_agents {
-            let found = definitions.iter().find(|(id, _, _)| *id == expected_id);
-            assert!(found.is_some(), "Agent {} should exist", expected_id);
-            let (_, name, path) = found.unwrap();
-            assert_eq!(*name, expected_name, "Agent {} should have correct name", expected_id);
-            assert_eq!(*path, expected_path, "Agent {} should have correct path", expected_id);
+        let cursor_dir = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_dir).expect("Failed to create cursor directory");
+        create_symlink(global_dir.join("installed"), cursor_dir.join("installed")).expect("Failed to create symlink");
+
+        // Act
+        let available = available_skills_for_agent_with_home("cursor", &home_path);
+
+        // Assert
+        let names: Vec<&str> = available.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["missing"]);
+    }
+
+    /// Test that skills are ordered by their newest file, newest first, and capped at the limit
+    #[test]
+    fn test_recently_modified_skills_orders_by_mtime() {
+        // Arrange: Three skills whose newest files are an hour apart
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        let now = std::time::SystemTime::now();
+        let hours_ago = |hours: u64| now - std::time::Duration::from_secs(hours * 3600);
+        for (name, modified) in [("oldest", hours_ago(3)), ("newest", hours_ago(1)), ("middle", hours_ago(2))] {
+            let skill_dir = global_dir.join(name);
+            fs::create_dir_all(skill_dir.join("docs")).expect("Failed to create skill directory");
+            for file in ["SKILL.md", "docs/notes.md"] {
+                fs::write(skill_dir.join(file), name).expect("Failed to write file");
+                fs::File::options().write(true).open(skill_dir.join(file))
+                    .and_then(|f| f.set_modified(hours_ago(5)))
+                    .expect("Failed to set mtime");
+            }
+            // The nested file is the newest one in each skill
+            fs::File::options().write(true).open(skill_dir.join("docs/notes.md"))
+                .and_then(|f| f.set_modified(modified))
+                .expect("Failed to set mtime");
+            // A link back to the skill must not be walked
+            create_symlink(&skill_dir, skill_dir.join("docs/loop")).expect("Failed to create symlink");
         }
+
+        // Act
+        let all = recently_modified_skills_with_home(&home_path, 10);
+        let limited = recently_modified_skills_with_home(&home_path, 2);
+
+        // Assert
+        let names: Vec<&str> = all.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["newest", "middle", "oldest"]);
+        let limited_names: Vec<&str> = limited.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(limited_names, vec!["newest", "middle"]);
     }
 
-    /// Test detection with nested directory paths
-    /// 
-    /// **Validates: Requirements 3.2**
+    // ==================== normalize_agent_links Tests ====================
+
+    /// Test converting a symlinked skill to a local copy and back to a symlink
     #[test]
-    fn test_agent_detection_with_nested_paths() {
-        // Arrange: Create a temp home directory
+    fn test_normalize_agent_links_symlink_to_copy_and_back() {
+        // Arrange: cursor links the global skill via symlink
         let temp_home = create_temp_home();
         let home_path = temp_home.path().to_path_buf();
-        
-        // Create agents with deeply nested paths
-        let nested_agents = vec![
-            (".gemini/antigravity/global_skills", "antigravity"),  // 3 levels deep
-            (".codeium/windsurf/skills", "windsurf"),              // 3 levels deep
-            (".pi/agent/skills", "pi"),                            // 3 levels deep
-        ];
-        
-        for (path, _) in &nested_agents {
-            let full_path = home_path.join(path);
-            fs::create_dir_all(&full_path).expect(&format!("Failed to create directory: {}", path));
+        let skill_dir = home_path.join(".agents/skills/test-skill");
+        fs::create_dir_all(skill_dir.join("docs")).expect("Failed to create skill directory");
+        fs::write(skill_dir.join("SKILL.md"), "---\nname: Test\n---\n").expect("Failed to write SKILL.md");
+        fs::write(skill_dir.join("docs/guide.md"), "guide").expect("Failed to write guide");
+        let cursor_skill = home_path.join(".cursor/skills/test-skill");
+        fs::create_dir_all(cursor_skill.parent().unwrap()).expect("Failed to create cursor directory");
+        create_symlink(&skill_dir, &cursor_skill).expect("Failed to create symlink");
+
+        // Act: Convert to copies
+        let result = normalize_agent_links_with_home("cursor", LinkStyle::Copy, &home_path).expect("normalize should succeed");
+
+        // Assert: The symlink is now a local copy with the same content
+        assert_eq!(result.success, vec!["test-skill".to_string()]);
+        let metadata = fs::symlink_metadata(&cursor_skill).expect("Skill should exist");
+        assert!(metadata.file_type().is_dir() && !metadata.file_type().is_symlink(), "Should be a local copy");
+        assert_eq!(fs::read_to_string(cursor_skill.join("docs/guide.md")).expect("Failed to read guide"), "guide");
+
+        // Act: Convert back to symlinks
+        let result = normalize_agent_links_with_home("cursor", LinkStyle::Symlink, &home_path).expect("normalize should succeed");
+
+        // Assert
+        assert_eq!(result.success, vec!["test-skill".to_string()]);
+        assert_eq!(fs::read_link(&cursor_skill).expect("Should be a symlink"), skill_dir);
+    }
+
+    /// Test that a local copy that diverges from global is skipped rather than replaced
+    #[test]
+    fn test_normalize_agent_links_skips_divergent_copy() {
+        // Arrange
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let skill_dir = home_path.join(".agents/skills/test-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        fs::write(skill_dir.join("SKILL.md"), "global").expect("Failed to write SKILL.md");
+        let cursor_skill = home_path.join(".cursor/skills/test-skill");
+        fs::create_dir_all(&cursor_skill).expect("Failed to create local copy");
+        fs::write(cursor_skill.join("SKILL.md"), "edited locally").expect("Failed to write SKILL.md");
+
+        // Act
+        let result = normalize_agent_links_with_home("cursor", LinkStyle::Symlink, &home_path).expect("normalize should succeed");
+
+        // Assert
+        assert!(result.success.is_empty());
+        assert_eq!(result.skipped.len(), 1);
+        assert_eq!(fs::read_to_string(cursor_skill.join("SKILL.md")).expect("Local copy should remain"), "edited locally");
+    }
+
+    /// Test that a hidden file present only in the local copy keeps it from being replaced
+    #[test]
+    fn test_normalize_agent_links_keeps_copy_with_local_hidden_file() {
+        // Arrange: A managed local copy that gained a `.env` file
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let skill_dir = home_path.join(".agents/skills/test-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        fs::write(skill_dir.join("SKILL.md"), "---\nname: Test\n---\n").expect("Failed to write SKILL.md");
+        let cursor_skill = home_path.join(".cursor/skills/test-skill");
+        fs::create_dir_all(cursor_skill.parent().unwrap()).expect("Failed to create cursor directory");
+        create_symlink(&skill_dir, &cursor_skill).expect("Failed to create symlink");
+        normalize_agent_links_with_home("cursor", LinkStyle::Copy, &home_path).expect("normalize should succeed");
+        fs::write(cursor_skill.join(".env"), "TOKEN=local").expect("Failed to write .env");
+
+        // Act
+        let result = normalize_agent_links_with_home("cursor", LinkStyle::Symlink, &home_path).expect("normalize should succeed");
+
+        // Assert
+        assert!(result.success.is_empty());
+        assert_eq!(result.skipped[0].error, "Local content differs from the global skill");
+        assert_eq!(fs::read_to_string(cursor_skill.join(".env")).expect(".env should remain"), "TOKEN=local");
+    }
+
+    // ==================== move_skill_between_roots Tests ====================
+
+    /// Test that moving a skill to another root repoints the agent symlinks into the new root
+    #[test]
+    fn test_move_skill_between_roots_updates_symlinks() {
+        // Arrange: A personal skill linked into cursor and claude, with a team root configured
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let config = AppConfig {
+            global_paths: vec!["~/.agents/skills".to_string(), "team/skills".to_string()],
+            ..Default::default()
+        };
+        config::save_config_with_home(&config, &home_path).expect("Failed to save config");
+        let personal_skill = home_path.join(".agents/skills/review");
+        fs::create_dir_all(&personal_skill).expect("Failed to create skill directory");
+        fs::write(personal_skill.join("SKILL.md"), "---\nname: Review\n---\n").expect("Failed to write SKILL.md");
+        for agent_path in [".cursor/skills", ".claude/skills"] {
+            fs::create_dir_all(home_path.join(agent_path)).expect("Failed to create agent directory");
+            create_symlink(&personal_skill, home_path.join(agent_path).join("review")).expect("Failed to create symlink");
+        }
+
+        // Act
+        let result = move_skill_between_roots_with_home("review", "~/.agents/skills", "team/skills", &home_path)
+            .expect("move should succeed");
+
+        // Assert
+        let team_skill = home_path.join("team/skills/review");
+        assert_eq!(result.success, vec!["claude-code".to_string(), "cursor".to_string()]);
+        assert!(!personal_skill.exists(), "Skill should be removed from the source root");
+        assert!(team_skill.join("SKILL.md").exists(), "Skill should be in the target root");
+        for agent_path in [".cursor/skills", ".claude/skills"] {
+            let link = home_path.join(agent_path).join("review");
+            assert_eq!(fs::read_link(&link).expect("Should be a symlink"), team_skill);
+        }
+
+        // Assert: Moving back onto an existing name is refused
+        fs::create_dir_all(&personal_skill).expect("Failed to recreate skill directory");
+        assert!(move_skill_between_roots_with_home("review", "team/skills", "~/.agents/skills", &home_path).is_err());
+    }
+
+    // ==================== patch_skill_metadata Tests ====================
+
+    /// Test that patching only the description leaves name, tools and body untouched
+    #[test]
+    fn test_patch_skill_metadata_updates_only_given_fields() {
+        // Arrange
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let skill_dir = home_path.join(".agents/skills/my-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: My Skill\ndescription: Old\nallowed-tools:\n  - Read\n  - Grep\nauthor: Sam\n---\n\n# Body\n",
+        ).expect("Failed to write SKILL.md");
+        let patch = PartialSkillMetadata { description: Some("New description".to_string()), ..Default::default() };
+
+        // Act
+        patch_skill_metadata_with_home("my-skill", &patch, &home_path).expect("patch should succeed");
+
+        // Assert
+        let content = fs::read_to_string(skill_dir.join("SKILL.md")).expect("Failed to read SKILL.md");
+        let metadata = parse_skill_md(&content);
+        assert_eq!(metadata.description, "New description");
+        assert_eq!(metadata.name, "My Skill");
+        assert_eq!(metadata.allowed_tools, vec!["Read", "Grep"]);
+        assert_eq!(metadata.author, Some("Sam".to_string()));
+        assert!(content.ends_with("---\n\n# Body\n"), "Body should be preserved");
+    }
+
+    /// Test that saving metadata replaces the frontmatter, keeps the body, and leaves no temp file
+    #[test]
+    fn test_update_skill_metadata_preserves_body() {
+        // Arrange: One frontmatter skill and one heading-format skill
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        for (name, content) in [
+            ("framed", "---\nname: Framed\ndescription: Old\n---\n\n# Notes\n\nKeep me.\n"),
+            ("heading", "# Heading\n\nFirst paragraph.\n"),
+        ] {
+            fs::create_dir_all(global_dir.join(name)).expect("Failed to create skill directory");
+            fs::write(global_dir.join(name).join("SKILL.md"), content).expect("Failed to write SKILL.md");
+        }
+        let metadata = SkillMetadata {
+            name: "Renamed".to_string(),
+            description: "Edited in the UI".to_string(),
+            ..Default::default()
+        };
+
+        // Act
+        update_skill_metadata_with_home("framed", &metadata, &home_path).expect("update should succeed");
+        update_skill_metadata_with_home("heading", &metadata, &home_path).expect("update should succeed");
+
+        // Assert
+        let framed = fs::read_to_string(global_dir.join("framed/SKILL.md")).expect("Failed to read SKILL.md");
+        assert_eq!(framed, "---\nname: Renamed\ndescription: Edited in the UI\n---\n\n# Notes\n\nKeep me.\n");
+        let heading = fs::read_to_string(global_dir.join("heading/SKILL.md")).expect("Failed to read SKILL.md");
+        assert_eq!(heading, "---\nname: Renamed\ndescription: Edited in the UI\n---\n# Heading\n\nFirst paragraph.\n");
+        let leftovers: Vec<_> = fs::read_dir(global_dir.join("framed")).unwrap().flatten().map(|e| e.file_name()).collect();
+        assert_eq!(leftovers, vec![std::ffi::OsString::from("SKILL.md")]);
+    }
+
+    /// Test that saving displayed metadata writes only the edit, not fallbacks or inherited defaults
+    #[test]
+    fn test_update_skill_metadata_skips_fallbacks_and_defaults() {
+        // Arrange: A skill with only a tag, in a directory whose defaults set a license
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        fs::create_dir_all(global_dir.join("bare")).expect("Failed to create skill directory");
+        fs::write(global_dir.join("bare/SKILL.md"), "---\ntags:\n  - git\n---\n").expect("Failed to write SKILL.md");
+        fs::write(global_dir.join(DEFAULTS_FILE), "license: MIT\n").expect("Failed to write defaults");
+        let mut metadata = get_app_data_with_home(&home_path).skills[0].metadata.clone();
+        assert_eq!(metadata.description, "No description available");
+
+        // Act: The UI edits only the author
+        metadata.author = Some("Sam".to_string());
+        update_skill_metadata_with_home("bare", &metadata, &home_path).expect("update should succeed");
+
+        // Assert
+        let content = fs::read_to_string(global_dir.join("bare/SKILL.md")).expect("Failed to read SKILL.md");
+        assert_eq!(parse_skill_md(&content), SkillMetadata {
+            author: Some("Sam".to_string()),
+            tags: vec!["git".to_string()],
+            ..Default::default()
+        });
+    }
+
+    // ==================== skill_repository_url Tests ====================
+
+    /// Test that an https repository is returned and non-URL or file:// values are refused
+    #[test]
+    fn test_skill_repository_url_rejects_non_http_values() {
+        // Arrange
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        for (name, repository) in [
+            ("web", "https://github.com/example/skills"),
+            ("text", "see the README"),
+            ("local", "file:///etc/passwd"),
+        ] {
+            fs::create_dir_all(global_dir.join(name)).expect("Failed to create skill directory");
+            fs::write(global_dir.join(name).join("SKILL.md"), format!("---\nname: {}\nrepository: {}\n---\n", name, repository))
+                .expect("Failed to write SKILL.md");
         }
-        
-        // Act: Detect agents
-        let agents = detect_agents_with_home(&home_path);
-        
-        // Assert: Nested path agents should be detected
-        for (_, id) in &nested_agents {
-            let agent = agents.iter().find(|a| a.id == *id).expect(&format!("Agent {} not found", id));
-            assert!(agent.detected, "Agent {} with nested path should be detected", id);
+
+        // Act & Assert
+        assert_eq!(
+            skill_repository_url_with_home("web", &home_path),
+            Ok("https://github.com/example/skills".to_string())
+        );
+        for name in ["text", "local"] {
+            let error = skill_repository_url_with_home(name, &home_path).expect_err("non-http repository should error");
+            assert!(error.contains("not an http(s) URL"), "Unexpected error: {}", error);
         }
     }
 
-    // ==================== SKILL.md Parsing Tests ====================
-
-    /// Test that get_app_data parses SKILL.md files with frontmatter format
-    /// 
-    /// **Validates: Requirements 2.1**
-    /// - 2.1: WHEN reading a skill directory, THE Skills_Manager SHALL look for a SKILL.md file in the skill's root directory
+    /// Test that the repository is read from a UTF-16 SKILL.md
     #[test]
-    fn test_get_app_data_parses_skill_md_frontmatter() {
-        // Arrange: Create a temp home directory with a skill containing SKILL.md
+    fn test_skill_repository_url_reads_utf16_skill_md() {
+        // Arrange
         let temp_home = create_temp_home();
         let home_path = temp_home.path().to_path_buf();
-        
-        // Create global skills directory with a skill
-        let skill_dir = home_path.join(".agents/skills/my-skill");
+        let skill_dir = home_path.join(".agents/skills/wide");
         fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
-        
-        // Create SKILL.md with frontmatter
-        let skill_md_content = r#"---
-name: My Awesome Skill
-description: This skill does amazing things
-allowed-tools:
-  - tool1
-  - tool2
----
+        let content = "---\nname: wide\nrepository: https://github.com/example/wide\n---\n";
+        fs::write(skill_dir.join("SKILL.md"), SkillMdEncoding::Utf16Le.encode(content).unwrap()).expect("Failed to write SKILL.md");
 
-# My Awesome Skill
+        // Act
+        let url = skill_repository_url_with_home("wide", &home_path);
 
-Detailed documentation here.
-"#;
-        fs::write(skill_dir.join("SKILL.md"), skill_md_content).expect("Failed to write SKILL.md");
-        
-        // Act: Get app data
-        let app_data = get_app_data_with_home(&home_path);
-        
-        // Assert: Skill should have parsed metadata
-        assert_eq!(app_data.skills.len(), 1, "Should have 1 skill");
-        let skill = &app_data.skills[0];
-        assert_eq!(skill.name, "my-skill", "Skill directory name should be preserved");
-        assert_eq!(skill.metadata.name, "My Awesome Skill", "Skill name should be parsed from frontmatter");
-        assert_eq!(skill.metadata.description, "This skill does amazing things", "Description should be parsed from frontmatter");
-        assert_eq!(skill.metadata.allowed_tools, vec!["tool1", "tool2"], "Allowed tools should be parsed from frontmatter");
+        // Assert
+        assert_eq!(url, Ok("https://github.com/example/wide".to_string()));
     }
 
-    /// Test that get_app_data parses SKILL.md files with heading format
-    /// 
-    /// **Validates: Requirements 2.1**
+    // ==================== scaffold_missing_skill_md Tests ====================
+
+    /// Test that a skill without SKILL.md gets a generated one and existing files are left alone
     #[test]
-    fn test_get_app_data_parses_skill_md_heading_format() {
-        // Arrange: Create a temp home directory with a skill containing SKILL.md
+    fn test_scaffold_missing_skill_md_generates_file() {
+        // Arrange
         let temp_home = create_temp_home();
         let home_path = temp_home.path().to_path_buf();
-        
-        // Create global skills directory with a skill
-        let skill_dir = home_path.join(".agents/skills/heading-skill");
-        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
-        
-        // Create SKILL.md with heading format
-        let skill_md_content = r#"# Heading Based Skill
+        let global_dir = home_path.join(".agents/skills");
+        fs::create_dir_all(global_dir.join("bare-skill")).expect("Failed to create skill directory");
+        fs::create_dir_all(global_dir.join("documented")).expect("Failed to create skill directory");
+        fs::write(global_dir.join("documented/SKILL.md"), "# Documented\n").expect("Failed to write SKILL.md");
 
-This is a skill using the heading format.
+        // Act
+        let result = scaffold_missing_skill_md_with_home(&home_path).expect("scaffold should succeed");
 
-## Allowed Tools
-- read_file
-- write_file
-"#;
-        fs::write(skill_dir.join("SKILL.md"), skill_md_content).expect("Failed to write SKILL.md");
-        
-        // Act: Get app data
-        let app_data = get_app_data_with_home(&home_path);
-        
-        // Assert: Skill should have parsed metadata
-        assert_eq!(app_data.skills.len(), 1, "Should have 1 skill");
-        let skill = &app_data.skills[0];
-        assert_eq!(skill.metadata.name, "Heading Based Skill", "Skill name should be parsed from heading");
-        assert_eq!(skill.metadata.description, "This is a skill using the heading format.", "Description should be parsed from first paragraph");
-        assert_eq!(skill.metadata.allowed_tools, vec!["read_file", "write_file"], "Allowed tools should be parsed");
+        // Assert
+        assert_eq!(result.success, vec!["bare-skill".to_string()]);
+        assert_eq!(result.skipped.len(), 1);
+        assert_eq!(result.skipped[0].agent_id, "documented");
+        let content = fs::read_to_string(global_dir.join("bare-skill/SKILL.md")).expect("SKILL.md should be generated");
+        let metadata = parse_skill_md(&content);
+        assert_eq!(metadata.name, "bare-skill");
+        assert_eq!(metadata.description, "No description available");
+        assert_eq!(fs::read_to_string(global_dir.join("documented/SKILL.md")).unwrap(), "# Documented\n");
     }
 
-    /// Test that get_app_data uses fallback values when SKILL.md is missing
-    /// 
-    /// **Validates: Requirements 1.6**
-    /// - 1.6: IF parsing SKILL.md fails, THEN THE Skills_Manager SHALL display the skill name from the directory name and show "No description available"
+    // ==================== swap_skill_names Tests ====================
+
+    /// Test that swapping two skills renames the directories and the agent links to match
     #[test]
-    fn test_get_app_data_fallback_when_skill_md_missing() {
-        // Arrange: Create a temp home directory with a skill without SKILL.md
+    fn test_swap_skill_names_updates_symlinks() {
+        // Arrange: "alpha" holds beta's content and vice versa; cursor links alpha, claude links both
         let temp_home = create_temp_home();
         let home_path = temp_home.path().to_path_buf();
-        
-        // Create global skills directory with a skill (no SKILL.md)
-        let skill_dir = home_path.join(".agents/skills/no-skill-md");
-        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
-        
-        // Act: Get app data
-        let app_data = get_app_data_with_home(&home_path);
-        
-        // Assert: Skill should have fallback metadata
-        assert_eq!(app_data.skills.len(), 1, "Should have 1 skill");
-        let skill = &app_data.skills[0];
-        assert_eq!(skill.name, "no-skill-md", "Skill directory name should be preserved");
-        assert_eq!(skill.metadata.name, "no-skill-md", "Skill name should fallback to directory name");
-        assert_eq!(skill.metadata.description, "No description available", "Description should be fallback message");
-        assert!(skill.metadata.allowed_tools.is_empty(), "Allowed tools should be empty");
+        let global_dir = home_path.join(".agents/skills");
+        for (name, content) in [("alpha", "beta content"), ("beta", "alpha content")] {
+            fs::create_dir_all(global_dir.join(name)).expect("Failed to create skill directory");
+            fs::write(global_dir.join(name).join("SKILL.md"), content).expect("Failed to write SKILL.md");
+        }
+        let cursor_dir = home_path.join(".cursor/skills");
+        let claude_dir = home_path.join(".claude/skills");
+        fs::create_dir_all(&cursor_dir).expect("Failed to create cursor directory");
+        fs::create_dir_all(&claude_dir).expect("Failed to create claude directory");
+        create_symlink(global_dir.join("alpha"), cursor_dir.join("alpha")).expect("Failed to create symlink");
+        create_symlink(global_dir.join("alpha"), claude_dir.join("alpha")).expect("Failed to create symlink");
+        create_symlink(global_dir.join("beta"), claude_dir.join("beta")).expect("Failed to create symlink");
+
+        // Act
+        let result = swap_skill_names_with_home("alpha", "beta", &home_path).expect("swap should succeed");
+
+        // Assert: Directories swapped, no temp directory left behind
+        assert_eq!(fs::read_to_string(global_dir.join("alpha/SKILL.md")).unwrap(), "alpha content");
+        assert_eq!(fs::read_to_string(global_dir.join("beta/SKILL.md")).unwrap(), "beta content");
+        assert_eq!(fs::read_dir(&global_dir).unwrap().count(), 2);
+
+        // Assert: Agents keep the same content, now under the corrected name
+        assert_eq!(result.success, vec!["claude-code".to_string(), "cursor".to_string()]);
+        assert!(fs::symlink_metadata(cursor_dir.join("alpha")).is_err(), "cursor's old link name should be gone");
+        assert_eq!(fs::read_link(cursor_dir.join("beta")).expect("Should be a symlink"), global_dir.join("beta"));
+        assert_eq!(fs::read_link(claude_dir.join("alpha")).expect("Should be a symlink"), global_dir.join("alpha"));
+        assert_eq!(fs::read_link(claude_dir.join("beta")).expect("Should be a symlink"), global_dir.join("beta"));
+        assert_eq!(fs::read_to_string(cursor_dir.join("beta/SKILL.md")).unwrap(), "beta content");
     }
 
-    /// Test that get_app_data uses fallback values when SKILL.md has empty name
-    /// 
-    /// **Validates: Requirements 1.6**
+    /// Test that a link whose replacement cannot be created is kept instead of deleted
     #[test]
-    fn test_get_app_data_fallback_when_skill_md_has_empty_name() {
-        // Arrange: Create a temp home directory with a skill with empty name in SKILL.md
+    fn test_swap_skill_names_keeps_link_when_relink_fails() {
+        // Arrange: The temporary name for cursor's new link is already taken
         let temp_home = create_temp_home();
         let home_path = temp_home.path().to_path_buf();
-        
-        // Create global skills directory with a skill
-        let skill_dir = home_path.join(".agents/skills/empty-name-skill");
-        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
-        
-        // Create SKILL.md with empty name
-        let skill_md_content = r#"---
-description: Has description but no name
----
-"#;
-        fs::write(skill_dir.join("SKILL.md"), skill_md_content).expect("Failed to write SKILL.md");
-        
-        // Act: Get app data
-        let app_data = get_app_data_with_home(&home_path);
-        
-        // Assert: Skill should have directory name as fallback
-        assert_eq!(app_data.skills.len(), 1, "Should have 1 skill");
-        let skill = &app_data.skills[0];
-        assert_eq!(skill.metadata.name, "empty-name-skill", "Skill name should fallback to directory name");
-        assert_eq!(skill.metadata.description, "Has description but no name", "Description should be parsed");
+        let global_dir = home_path.join(".agents/skills");
+        for name in ["alpha", "beta"] {
+            fs::create_dir_all(global_dir.join(name)).expect("Failed to create skill directory");
+        }
+        let cursor_dir = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_dir).expect("Failed to create cursor directory");
+        create_symlink(global_dir.join("alpha"), cursor_dir.join("alpha")).expect("Failed to create symlink");
+        fs::write(cursor_dir.join(format!(".alpha.swap-{}", std::process::id())), "").expect("Failed to write blocker");
+
+        // Act
+        let result = swap_skill_names_with_home("alpha", "beta", &home_path).expect("swap should succeed");
+
+        // Assert
+        assert!(result.success.is_empty());
+        assert_eq!(result.failed.len(), 1);
+        assert_eq!(fs::read_link(cursor_dir.join("alpha")).expect("Old link should be kept"), global_dir.join("alpha"));
     }
 
-    /// Test that get_app_data uses fallback description when SKILL.md has empty description
-    /// 
-    /// **Validates: Requirements 1.6**
+    // ==================== delete_global_skill_preview Tests ====================
+
+    /// Test that the preview lists every agent linking the skill and ignores unrelated same-named entries
     #[test]
-    fn test_get_app_data_fallback_when_skill_md_has_empty_description() {
-        // Arrange: Create a temp home directory with a skill with empty description in SKILL.md
+    fn test_delete_global_skill_preview_lists_linking_agents() {
+        // Arrange: cursor and claude link the skill; windsurf has an unrelated local copy
         let temp_home = create_temp_home();
         let home_path = temp_home.path().to_path_buf();
-        
-        // Create global skills directory with a skill
-        let skill_dir = home_path.join(".agents/skills/empty-desc-skill");
+        let skill_dir = home_path.join(".agents/skills/test-skill");
         fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
-        
-        // Create SKILL.md with name but no description
-        let skill_md_content = r#"---
-name: Named Skill
----
-"#;
-        fs::write(skill_dir.join("SKILL.md"), skill_md_content).expect("Failed to write SKILL.md");
-        
-        // Act: Get app data
-        let app_data = get_app_data_with_home(&home_path);
-        
-        // Assert: Skill should have fallback description
-        assert_eq!(app_data.skills.len(), 1, "Should have 1 skill");
-        let skill = &app_data.skills[0];
-        assert_eq!(skill.metadata.name, "Named Skill", "Skill name should be parsed");
-        assert_eq!(skill.metadata.description, "No description available", "Description should be fallback message");
+        for agent_dir in [".cursor/skills", ".claude/skills"] {
+            let agent_path = home_path.join(agent_dir);
+            fs::create_dir_all(&agent_path).expect("Failed to create agent directory");
+            create_symlink(&skill_dir, agent_path.join("test-skill")).expect("Failed to create symlink");
+        }
+        fs::create_dir_all(home_path.join(".codeium/windsurf/skills/test-skill")).expect("Failed to create local skill");
+
+        // Act
+        let mut agents = delete_global_skill_preview_with_home("test-skill", &home_path);
+        agents.sort();
+
+        // Assert
+        assert_eq!(agents, vec!["claude-code".to_string(), "cursor".to_string()]);
+        assert!(skill_dir.exists(), "Preview must not delete anything");
     }
 
-    /// Test that get_app_data handles multiple skills with mixed SKILL.md states
-    /// 
-    /// **Validates: Requirements 1.6, 2.1**
+    // ==================== consolidate_duplicates Tests ====================
+
+    /// Test that a duplicate's links move to the canonical skill and a differing skill is refused
     #[test]
-    fn test_get_app_data_multiple_skills_mixed_states() {
-        // Arrange: Create a temp home directory with multiple skills
+    fn test_consolidate_duplicates_relinks_and_deletes() {
+        // Arrange: review-copy is identical to review and linked into cursor; other differs, and
+        // review-env differs only by a hidden file
         let temp_home = create_temp_home();
         let home_path = temp_home.path().to_path_buf();
-        
-        // Create global skills directory
-        let skills_base = home_path.join(".agents/skills");
-        
-        // Skill 1: Has complete SKILL.md
-        let skill1_dir = skills_base.join("complete-skill");
-        fs::create_dir_all(&skill1_dir).expect("Failed to create skill1 directory");
-        fs::write(skill1_dir.join("SKILL.md"), r#"---
-name: Complete Skill
-description: A fully documented skill
-allowed-tools:
-  - tool_a
----
-"#).expect("Failed to write SKILL.md");
-        
-        // Skill 2: No SKILL.md
-        let skill2_dir = skills_base.join("no-md-skill");
-        fs::create_dir_all(&skill2_dir).expect("Failed to create skill2 directory");
-        
-        // Skill 3: Empty SKILL.md
-        let skill3_dir = skills_base.join("empty-md-skill");
-        fs::create_dir_all(&skill3_dir).expect("Failed to create skill3 directory");
-        fs::write(skill3_dir.join("SKILL.md"), "").expect("Failed to write empty SKILL.md");
-        
-        // Act: Get app data
-        let app_data = get_app_data_with_home(&home_path);
-        
-        // Assert: All skills should be loaded with appropriate metadata
-        assert_eq!(app_data.skills.len(), 3, "Should have 3 skills");
-        
-        // Find each skill by name
-        let complete_skill = app_data.skills.iter().find(|s| s.name == "complete-skill").expect("complete-skill not found");
-        let no_md_skill = app_data.skills.iter().find(|s| s.name == "no-md-skill").expect("no-md-skill not found");
-        let empty_md_skill = app_data.skills.iter().find(|s| s.name == "empty-md-skill").expect("empty-md-skill not found");
-        
-        // Verify complete skill
-        assert_eq!(complete_skill.metadata.name, "Complete Skill");
-        assert_eq!(complete_skill.metadata.description, "A fully documented skill");
-        assert_eq!(complete_skill.metadata.allowed_tools, vec!["tool_a"]);
-        
-        // Verify no-md skill (fallback values)
-        assert_eq!(no_md_skill.metadata.name, "no-md-skill");
-        assert_eq!(no_md_skill.metadata.description, "No description available");
-        assert!(no_md_skill.metadata.allowed_tools.is_empty());
-        
-        // Verify empty-md skill (fallback values)
-        assert_eq!(empty_md_skill.metadata.name, "empty-md-skill");
-        assert_eq!(empty_md_skill.metadata.description, "No description available");
-        assert!(empty_md_skill.metadata.allowed_tools.is_empty());
+        let global_dir = home_path.join(".agents/skills");
+        for (name, content) in [("review", "same"), ("review-copy", "same"), ("other", "different"), ("review-env", "same")] {
+            fs::create_dir_all(global_dir.join(name)).expect("Failed to create skill directory");
+            fs::write(global_dir.join(name).join("SKILL.md"), content).expect("Failed to write SKILL.md");
+        }
+        fs::write(global_dir.join("review-env/.env"), "TOKEN=1").expect("Failed to write .env");
+        let cursor_path = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
+        create_symlink(global_dir.join("review-copy"), cursor_path.join("review-copy")).expect("Failed to create symlink");
+
+        // Act
+        let result = consolidate_duplicates_with_home(
+            "review",
+            vec!["review-copy".to_string(), "other".to_string(), "review-env".to_string()],
+            &home_path,
+        )
+        .expect("consolidate should succeed");
+
+        // Assert: The link now points at the canonical skill and the duplicate is gone
+        assert_eq!(result.success, vec!["cursor/review-copy".to_string(), "review-copy".to_string()]);
+        assert_eq!(
+            fs::read_link(cursor_path.join("review-copy")).expect("Should be a symlink"),
+            global_dir.join("review")
+        );
+        assert!(!global_dir.join("review-copy").exists());
+
+        // Assert: The differing skills were refused and kept
+        let refused: Vec<&str> = result.failed.iter().map(|f| f.agent_id.as_str()).collect();
+        assert_eq!(refused, vec!["other", "review-env"]);
+        assert!(global_dir.join("other").exists());
+        assert!(global_dir.join("review-env/.env").exists());
     }
 
-    /// Test load_skill_metadata function directly
-    /// 
-    /// **Validates: Requirements 1.6, 2.1**
+    // ==================== Empty Skill Tests ====================
+
+    /// Test that an empty skill is found, then removed together with the links pointing at it
     #[test]
-    fn test_load_skill_metadata_with_valid_skill_md() {
-        // Arrange: Create a temp directory with SKILL.md
-        let temp_dir = create_temp_home();
-        let skill_dir = temp_dir.path().to_path_buf();
-        
-        let skill_md_content = r#"---
-name: Test Skill
-description: Test description
-allowed-tools:
-  - test_tool
----
-"#;
-        fs::write(skill_dir.join("SKILL.md"), skill_md_content).expect("Failed to write SKILL.md");
-        
+    fn test_remove_empty_skills_removes_skill_and_links() {
+        // Arrange: An empty skill (only an empty subdirectory) linked into cursor, and a normal skill
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        fs::create_dir_all(global_dir.join("empty/scripts")).expect("Failed to create empty skill");
+        fs::create_dir_all(global_dir.join("review")).expect("Failed to create skill directory");
+        fs::write(global_dir.join("review/SKILL.md"), "---\nname: Review\n---\n").expect("Failed to write SKILL.md");
+        let cursor_path = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
+        create_symlink(global_dir.join("empty"), cursor_path.join("empty")).expect("Failed to create symlink");
+
+        // Act & Assert: Detection is read-only
+        assert_eq!(find_empty_skills_with_home(&home_path), vec!["empty".to_string()]);
+        assert!(global_dir.join("empty").exists());
+
         // Act
-        let metadata = load_skill_metadata(&skill_dir, "fallback-name");
-        
+        let result = remove_empty_skills_with_home(&home_path).expect("remove should succeed");
+
         // Assert
-        assert_eq!(metadata.name, "Test Skill");
-        assert_eq!(metadata.description, "Test description");
-        assert_eq!(metadata.allowed_tools, vec!["test_tool"]);
+        assert_eq!(result.success, vec!["cursor/empty".to_string(), "empty".to_string()]);
+        assert!(!global_dir.join("empty").exists());
+        assert!(fs::symlink_metadata(cursor_path.join("empty")).is_err(), "Link should be removed");
+        assert!(global_dir.join("review/SKILL.md").exists());
     }
 
-    /// Test load_skill_metadata function with missing SKILL.md
-    /// 
-    /// **Validates: Requirements 1.6**
+    // ==================== bulk_update_field Tests ====================
+
+    /// Test that setting author across two skills updates both files and keeps their descriptions
     #[test]
-    fn test_load_skill_metadata_without_skill_md() {
-        // Arrange: Create a temp directory without SKILL.md
-        let temp_dir = create_temp_home();
-        let skill_dir = temp_dir.path().to_path_buf();
-        
+    fn test_bulk_update_field_sets_author() {
+        // Arrange: Two skills, one of them with a body
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        for (name, description) in [("skill-a", "First skill"), ("skill-b", "Second skill")] {
+            fs::create_dir_all(global_dir.join(name)).expect("Failed to create skill directory");
+            fs::write(
+                global_dir.join(name).join("SKILL.md"),
+                format!("---\nname: {}\ndescription: {}\n---\n\nBody of {}\n", name, description, name),
+            ).expect("Failed to write SKILL.md");
+        }
+
         // Act
-        let metadata = load_skill_metadata(&skill_dir, "my-fallback-name");
-        
-        // Assert: Should use fallback values
-        assert_eq!(metadata.name, "my-fallback-name");
-        assert_eq!(metadata.description, "No description available");
-        assert!(metadata.allowed_tools.is_empty());
+        let result = bulk_update_field_with_home("author", "Jane Doe", None, &home_path).expect("bulk update should succeed");
+
+        // Assert
+        assert_eq!(result.success, vec!["skill-a".to_string(), "skill-b".to_string()]);
+        assert!(result.failed.is_empty());
+        for (name, description) in [("skill-a", "First skill"), ("skill-b", "Second skill")] {
+            let content = fs::read_to_string(global_dir.join(name).join("SKILL.md")).expect("Failed to read SKILL.md");
+            let metadata = parse_skill_md(&content);
+            assert_eq!(metadata.author, Some("Jane Doe".to_string()));
+            assert_eq!(metadata.description, description, "Description should be preserved");
+            assert!(content.ends_with(&format!("Body of {}\n", name)), "Body should be preserved");
+        }
     }
 
-    // ==================== link_skill_to_all Tests ====================
+    /// Test that an unknown field name is rejected
+    #[test]
+    fn test_bulk_update_field_rejects_unknown_field() {
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
 
-    /// Test that link_skill_to_all creates symlinks for all detected agents
-    /// 
-    /// **Validates: Requirements 1.4, 6.1**
-    /// - 1.4: WHEN the user clicks "Link to All" on a skill card, THE Skills_Manager SHALL create symlinks for that skill in all detected agents' skills directories
-    /// - 6.1: WHEN the user clicks "Link to All Agents" for a skill, THE Skills_Manager SHALL create symlinks in all detected agents' skills directories
+        let result = bulk_update_field_with_home("colour", "blue", None, &home_path);
+
+        assert!(result.is_err(), "Unknown field should error");
+    }
+
+    // ==================== rename_tool Tests ====================
+
+    /// Test that a tool is renamed in every skill using it, without duplicating an existing entry
     #[test]
-    fn test_link_skill_to_all_creates_symlinks_for_detected_agents() {
-        // Arrange: Create a temp home directory with a global skill and some detected agents
+    fn test_rename_tool_updates_skills_without_duplicates() {
+        // Arrange: Two skills use "bash", one of them already lists "shell"; a third does not use it
         let temp_home = create_temp_home();
         let home_path = temp_home.path().to_path_buf();
-        
-        // Create global skill
-        let skill_dir = home_path.join(".agents/skills/test-skill");
-        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
-        fs::write(skill_dir.join("SKILL.md"), "# Test Skill\nA test skill.").expect("Failed to write SKILL.md");
-        
-        // Create detected agent directories
-        let detected_agents = vec![
-            ".cursor/skills",
-            ".claude/skills",
-            ".config/agents/skills",  // amp
-        ];
-        
-        for path in &detected_agents {
-            let full_path = home_path.join(path);
-            fs::create_dir_all(&full_path).expect(&format!("Failed to create directory: {}", path));
-        }
-        
-        // Act: Link skill to all
-        let result = link_skill_to_all_with_home("test-skill", &home_path).expect("link_skill_to_all should succeed");
-        
-        // Assert: All detected agents should be in success list
-        assert_eq!(result.success.len(), 3, "Should have 3 successful links");
-        assert!(result.success.contains(&"cursor".to_string()), "cursor should be in success list");
-        assert!(result.success.contains(&"claude-code".to_string()), "claude-code should be in success list");
-        assert!(result.success.contains(&"amp".to_string()), "amp should be in success list");
-        assert!(result.failed.is_empty(), "Should have no failures");
-        
-        // Verify symlinks were created
-        for path in &detected_agents {
-            let symlink_path = home_path.join(path).join("test-skill");
-            assert!(symlink_path.exists(), "Symlink should exist at {}", symlink_path.display());
-            let metadata = fs::symlink_metadata(&symlink_path).expect("Should be able to read symlink metadata");
-            assert!(metadata.file_type().is_symlink(), "Should be a symlink");
+        let global_dir = home_path.join(".agents/skills");
+        for (name, tools) in [("one", "  - bash\n  - Read\n"), ("two", "  - shell\n  - bash\n"), ("three", "  - Read\n")] {
+            fs::create_dir_all(global_dir.join(name)).expect("Failed to create skill directory");
+            fs::write(
+                global_dir.join(name).join("SKILL.md"),
+                format!("---\nname: {}\ndescription: Test\nallowed-tools:\n{}---\n\n# Body\n", name, tools),
+            ).expect("Failed to write SKILL.md");
         }
+
+        // Act
+        let result = rename_tool_with_home("bash", "shell", &home_path).expect("rename should succeed");
+
+        // Assert
+        assert_eq!(result.success, vec!["one".to_string(), "two".to_string()]);
+        assert!(result.failed.is_empty());
+        let tools = |name: &str| load_skill_metadata(&global_dir.join(name), name).allowed_tools;
+        assert_eq!(tools("one"), vec!["shell".to_string(), "Read".to_string()]);
+        assert_eq!(tools("two"), vec!["shell".to_string()]);
+        assert_eq!(tools("three"), vec!["Read".to_string()]);
+        let content = fs::read_to_string(global_dir.join("one/SKILL.md")).expect("Failed to read SKILL.md");
+        assert!(content.ends_with("# Body\n"), "Body should be preserved");
     }
 
-    /// Test that link_skill_to_all skips non-detected agents
-    /// 
-    /// **Validates: Requirements 6.3**
-    /// - 6.3: WHEN performing batch operations, THE Skills_Manager SHALL skip agents that are not detected
+    // ==================== normalize_line_endings Tests ====================
+
+    /// Test that a CRLF SKILL.md is converted to LF and an LF one is left untouched
     #[test]
-    fn test_link_skill_to_all_skips_non_detected_agents() {
-        // Arrange: Create a temp home directory with a global skill and only one detected agent
+    fn test_normalize_line_endings_converts_crlf_to_lf() {
+        // Arrange
         let temp_home = create_temp_home();
         let home_path = temp_home.path().to_path_buf();
-        
-        // Create global skill
-        let skill_dir = home_path.join(".agents/skills/test-skill");
-        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
-        
-        // Create only one detected agent directory
-        let cursor_path = home_path.join(".cursor/skills");
-        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
-        
-        // Act: Link skill to all
-        let result = link_skill_to_all_with_home("test-skill", &home_path).expect("link_skill_to_all should succeed");
-        
-        // Assert: Only cursor should be in success list (other 26 agents are not detected)
-        assert_eq!(result.success.len(), 1, "Should have 1 successful link");
-        assert!(result.success.contains(&"cursor".to_string()), "cursor should be in success list");
-        assert!(result.failed.is_empty(), "Should have no failures (non-detected agents are skipped, not failed)");
-        
-        // Verify symlink was created for cursor
-        let symlink_path = cursor_path.join("test-skill");
-        assert!(symlink_path.exists(), "Symlink should exist for cursor");
-        
-        // Verify no symlinks were created for non-detected agents
-        let claude_symlink = home_path.join(".claude/skills/test-skill");
-        assert!(!claude_symlink.exists(), "Symlink should not exist for non-detected agent");
+        let global_dir = home_path.join(".agents/skills");
+        for (name, content) in [("windows", "---\r\nname: Windows\r\n---\r\n\r\n# Body\r\n"), ("unix", "---\nname: Unix\n---\n")] {
+            fs::create_dir_all(global_dir.join(name)).expect("Failed to create skill directory");
+            fs::write(global_dir.join(name).join("SKILL.md"), content).expect("Failed to write SKILL.md");
+        }
+        let unix_md = global_dir.join("unix/SKILL.md");
+        let earlier = std::time::SystemTime::now() - std::time::Duration::from_secs(3600);
+        fs::File::options().write(true).open(&unix_md).and_then(|f| f.set_modified(earlier))
+            .expect("Failed to set modification time");
+
+        // Act
+        let result = normalize_line_endings_with_home(&home_path, LineEnding::Lf).expect("normalize should succeed");
+
+        // Assert
+        assert_eq!(result.success, vec!["windows".to_string()]);
+        assert_eq!(result.skipped.len(), 1);
+        assert_eq!(result.skipped[0].agent_id, "unix");
+        let content = fs::read_to_string(global_dir.join("windows/SKILL.md")).expect("Failed to read SKILL.md");
+        assert_eq!(content, "---\nname: Windows\n---\n\n# Body\n");
+        let modified = fs::metadata(&unix_md).and_then(|m| m.modified()).expect("Failed to read modification time");
+        assert_eq!(modified, earlier, "An already-LF file must not be rewritten");
     }
 
-    /// Test that link_skill_to_all returns error when skill doesn't exist
-    /// 
-    /// **Validates: Requirements 1.4**
+    /// Test that a UTF-16 SKILL.md keeps its encoding when its line endings are converted
     #[test]
-    fn test_link_skill_to_all_fails_when_skill_not_found() {
-        // Arrange: Create a temp home directory without the skill
+    fn test_normalize_line_endings_keeps_utf16() {
+        // Arrange
         let temp_home = create_temp_home();
         let home_path = temp_home.path().to_path_buf();
-        
-        // Create global skills directory but not the specific skill
-        fs::create_dir_all(home_path.join(".agents/skills")).expect("Failed to create skills directory");
-        
-        // Create a detected agent
-        fs::create_dir_all(home_path.join(".cursor/skills")).expect("Failed to create cursor directory");
-        
-        // Act: Try to link non-existent skill
-        let result = link_skill_to_all_with_home("non-existent-skill", &home_path);
-        
-        // Assert: Should return error
-        assert!(result.is_err(), "Should return error when skill doesn't exist");
-        let error = result.unwrap_err();
-        assert!(error.contains("non-existent-skill"), "Error should mention the skill name");
+        let skill_dir = home_path.join(".agents/skills/wide");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        let encoded = SkillMdEncoding::Utf16Le.encode("---\nname: Wide\n---\n").expect("Failed to encode");
+        fs::write(skill_dir.join("SKILL.md"), encoded).expect("Failed to write SKILL.md");
+
+        // Act
+        let result = normalize_line_endings_with_home(&home_path, LineEnding::Crlf).expect("normalize should succeed");
+
+        // Assert
+        assert_eq!(result.success, vec!["wide".to_string()]);
+        let expected = SkillMdEncoding::Utf16Le.encode("---\r\nname: Wide\r\n---\r\n").unwrap();
+        assert_eq!(fs::read(skill_dir.join("SKILL.md")).expect("Failed to read SKILL.md"), expected);
     }
 
-    /// Test that link_skill_to_all handles already linked skills
-    /// 
-    /// **Validates: Requirements 1.4, 6.1**
+    // ==================== validate_skill Tests ====================
+
+    /// Test that a UTF-16 SKILL.md loads its name and is flagged as non-UTF-8
     #[test]
-    fn test_link_skill_to_all_handles_already_linked_skills() {
-        // Arrange: Create a temp home directory with a global skill and a pre-existing symlink
+    fn test_utf16_skill_md_loads_with_warning() {
+        // Arrange
         let temp_home = create_temp_home();
         let home_path = temp_home.path().to_path_buf();
-        
-        // Create global skill
-        let skill_dir = home_path.join(".agents/skills/test-skill");
+        let skill_dir = home_path.join(".agents/skills/wide-skill");
         fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
-        
-        // Create detected agent directories
-        let cursor_path = home_path.join(".cursor/skills");
-        let claude_path = home_path.join(".claude/skills");
-        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
-        fs::create_dir_all(&claude_path).expect("Failed to create claude directory");
-        
-        // Pre-create symlink for cursor
-        let cursor_symlink = cursor_path.join("test-skill");
-        create_symlink(&skill_dir, &cursor_symlink).expect("Failed to create pre-existing symlink");
-        
-        // Act: Link skill to all
-        let result = link_skill_to_all_with_home("test-skill", &home_path).expect("link_skill_to_all should succeed");
-        
-        // Assert: Both agents should be in success list (cursor already linked, claude newly linked)
-        assert_eq!(result.success.len(), 2, "Should have 2 successful links");
-        assert!(result.success.contains(&"cursor".to_string()), "cursor should be in success list");
-        assert!(result.success.contains(&"claude-code".to_string()), "claude-code should be in success list");
-        assert!(result.failed.is_empty(), "Should have no failures");
+        let mut bytes = vec![0xFF, 0xFE];
+        bytes.extend("---\nname: Wide Skill\ndescription: Saved on Windows\n---\n".encode_utf16().flat_map(|u| u.to_le_bytes()));
+        fs::write(skill_dir.join("SKILL.md"), bytes).expect("Failed to write SKILL.md");
+
+        // Act
+        let metadata = load_skill_metadata(&skill_dir, "wide-skill");
+        let warnings = validate_skill_with_home("wide-skill", &home_path).expect("validation should succeed");
+
+        // Assert
+        assert_eq!(metadata.name, "Wide Skill");
+        assert_eq!(metadata.description, "Saved on Windows");
+        assert_eq!(warnings, vec![ValidationWarning::NonUtf8 { encoding: "utf-16le".to_string() }]);
     }
 
-    /// Test that link_skill_to_all creates parent directories if needed
-    /// 
-    /// **Validates: Requirements 5.7**
-    /// - 5.7: WHEN creating a symlink, THE Skills_Manager SHALL create the parent directory if it does not exist
+    /// Test that editing a Latin-1 SKILL.md writes it back as Latin-1
     #[test]
-    fn test_link_skill_to_all_creates_parent_directories() {
-        // Arrange: Create a temp home directory with a global skill
+    fn test_edit_skill_md_keeps_latin1() {
+        // Arrange
         let temp_home = create_temp_home();
         let home_path = temp_home.path().to_path_buf();
-        
-        // Create global skill
-        let skill_dir = home_path.join(".agents/skills/test-skill");
+        let skill_dir = home_path.join(".agents/skills/cafe");
         fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
-        
-        // Create only the base directory for cursor (not the full skills path)
-        // This simulates an agent that exists but doesn't have a skills directory yet
-        let cursor_base = home_path.join(".cursor");
-        fs::create_dir_all(&cursor_base).expect("Failed to create cursor base directory");
-        
-        // Also create the full path for another agent to make it detected
-        let claude_path = home_path.join(".claude/skills");
-        fs::create_dir_all(&claude_path).expect("Failed to create claude directory");
-        
-        // Act: Link skill to all
-        let result = link_skill_to_all_with_home("test-skill", &home_path).expect("link_skill_to_all should succeed");
-        
-        // Assert: Only claude should be in success list (cursor is not detected because .cursor/skills doesn't exist)
-        assert_eq!(result.success.len(), 1, "Should have 1 successful link");
-        assert!(result.success.contains(&"claude-code".to_string()), "claude-code should be in success list");
+        fs::write(skill_dir.join("SKILL.md"), b"---\nname: Caf\xe9\n---\n").expect("Failed to write SKILL.md");
+
+        // Act
+        let edited = edit_skill_md(&skill_dir, |metadata| metadata.author = Some("Zo\u{eb}".to_string()))
+            .expect("edit should succeed");
+
+        // Assert
+        assert!(edited);
+        let bytes = fs::read(skill_dir.join("SKILL.md")).expect("Failed to read SKILL.md");
+        assert_eq!(SkillMdEncoding::detect(&bytes), SkillMdEncoding::Latin1);
+        assert_eq!(parse_skill_md(&decode_skill_md(&bytes).0).author.as_deref(), Some("Zo\u{eb}"));
     }
 
-    /// Test that link_skill_to_all returns BatchResult with correct structure
-    /// 
-    /// **Validates: Requirements 1.4, 6.1**
+    /// Test that saving metadata to a UTF-16 SKILL.md keeps it UTF-16
     #[test]
-    fn test_link_skill_to_all_returns_batch_result() {
-        // Arrange: Create a temp home directory with a global skill
+    fn test_update_skill_metadata_keeps_utf16() {
+        // Arrange: A UTF-16LE SKILL.md
         let temp_home = create_temp_home();
         let home_path = temp_home.path().to_path_buf();
-        
-        // Create global skill
-        let skill_dir = home_path.join(".agents/skills/test-skill");
+        let skill_dir = home_path.join(".agents/skills/wide");
         fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
-        
-        // Create detected agent directories
-        fs::create_dir_all(home_path.join(".cursor/skills")).expect("Failed to create cursor directory");
-        fs::create_dir_all(home_path.join(".claude/skills")).expect("Failed to create claude directory");
-        
-        // Act: Link skill to all
-        let result = link_skill_to_all_with_home("test-skill", &home_path).expect("link_skill_to_all should succeed");
-        
-        // Assert: BatchResult should have correct structure
-        assert!(result.success.len() >= 2, "Should have at least 2 successful links");
-        assert!(result.failed.is_empty(), "Should have no failures");
-        
-        // Verify success list contains agent IDs (strings)
-        for agent_id in &result.success {
-            assert!(!agent_id.is_empty(), "Agent ID should not be empty");
-        }
+        let encoded = SkillMdEncoding::Utf16Le.encode("---\nname: Wide\n---\n\nBody\n").expect("Failed to encode");
+        fs::write(skill_dir.join("SKILL.md"), encoded).expect("Failed to write SKILL.md");
+        let mut metadata = load_skill_metadata(&skill_dir, "wide");
+        metadata.description = "Saved".to_string();
+
+        // Act
+        let result = update_skill_metadata_with_home("wide", &metadata, &home_path);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+        let bytes = fs::read(skill_dir.join("SKILL.md")).expect("Failed to read SKILL.md");
+        assert_eq!(SkillMdEncoding::detect(&bytes), SkillMdEncoding::Utf16Le);
+        let content = decode_skill_md(&bytes).0;
+        assert_eq!(parse_skill_md(&content).description, "Saved");
+        assert!(content.ends_with("\nBody\n"));
     }
 
-    /// Test that link_skill_to_all handles file existing at target path
-    /// 
-    /// **Validates: Requirements 6.5**
-    /// - 6.5: IF any individual link/unlink operation fails during batch, THEN THE Skills_Manager SHALL continue with remaining agents and report errors
+    /// Test that a description longer than the configured limit is flagged with a shorter suggestion
     #[test]
-    fn test_link_skill_to_all_handles_file_at_target_path() {
-        // Arrange: Create a temp home directory with a global skill
+    fn test_validate_skill_flags_long_description() {
+        // Arrange
         let temp_home = create_temp_home();
         let home_path = temp_home.path().to_path_buf();
-        
-        // Create global skill
-        let skill_dir = home_path.join(".agents/skills/test-skill");
-        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
-        
-        // Create detected agent directories
-        let cursor_path = home_path.join(".cursor/skills");
-        let claude_path = home_path.join(".claude/skills");
-        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
-        fs::create_dir_all(&claude_path).expect("Failed to create claude directory");
-        
-        // Create a regular file (not symlink) at cursor's target path
-        let cursor_target = cursor_path.join("test-skill");
-        fs::write(&cursor_target, "blocking file").expect("Failed to create blocking file");
-        
-        // Act: Link skill to all
-        let result = link_skill_to_all_with_home("test-skill", &home_path).expect("link_skill_to_all should succeed");
-        
-        // Assert: cursor should fail, claude should succeed
-        assert!(result.success.contains(&"claude-code".to_string()), "claude-code should be in success list");
-        assert_eq!(result.failed.len(), 1, "Should have 1 failure");
-        assert_eq!(result.failed[0].agent_id, "cursor", "cursor should be in failed list");
-        assert!(!result.failed[0].error.is_empty(), "Error message should not be empty");
+        let skill_dir = home_path.join(".agents/skills/wordy-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: Wordy Skill\ndescription: Reviews pull requests. It checks style, tests and docs in great detail.\n---\n",
+        ).expect("Failed to write SKILL.md");
+        let config = AppConfig { max_description_len: Some(40), ..Default::default() };
+        config::save_config_with_home(&config, &home_path).expect("Failed to save config");
+
+        // Act
+        let warnings = validate_skill_with_home("wordy-skill", &home_path).expect("validation should succeed");
+
+        // Assert
+        assert_eq!(warnings, vec![ValidationWarning::DescriptionTooLong {
+            length: 71,
+            max: 40,
+            suggestion: "Reviews pull requests.".to_string(),
+        }]);
     }
 
-    // ==================== unlink_skill_from_all Tests ====================
+    /// Test that library validation combines clean, problematic and unreadable skills into one report
+    #[test]
+    fn test_validate_library_combined_report() {
+        // Arrange: One clean skill, one with a long description, and one whose SKILL.md cannot be read
+        let temp_home = create_temp_home();
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        for (name, description) in [("clean", "Short."), ("wordy", "Reviews pull requests in great detail.")] {
+            fs::create_dir_all(global_dir.join(name)).expect("Failed to create skill directory");
+            fs::write(global_dir.join(name).join("SKILL.md"), format!("---\nname: {}\ndescription: {}\n---\n", name, description))
+                .expect("Failed to write SKILL.md");
+        }
+        fs::create_dir_all(global_dir.join("broken/SKILL.md")).expect("Failed to create unreadable SKILL.md");
+        let config = AppConfig { max_description_len: Some(20), ..Default::default() };
+        config::save_config_with_home(&config, &home_path).expect("Failed to save config");
 
-    /// Test that unlink_skill_from_all removes symlinks from all agents
-    /// 
-    /// **Validates: Requirements 1.5, 6.2**
-    /// - 1.5: WHEN the user clicks "Unlink from All" on a skill card, THE Skills_Manager SHALL remove symlinks for that skill from all agents' skills directories
-    /// - 6.2: WHEN the user clicks "Unlink from All Agents" for a skill, THE Skills_Manager SHALL remove symlinks from all agents' skills directories
+        // Act
+        let report = validate_library_with_home(&home_path);
+
+        // Assert
+        assert!(!report.is_clean);
+        assert_eq!(report.skills.len(), 3);
+        assert!(report.skills["clean"].is_empty());
+        assert!(matches!(report.skills["wordy"][..], [ValidationWarning::DescriptionTooLong { max: 20, .. }]));
+        assert!(matches!(report.skills["broken"][..], [ValidationWarning::ReadError { .. }]));
+    }
+
+    /// Test that a heading-format skill is reported as unstable and a canonical one is not
     #[test]
-    fn test_unlink_skill_from_all_removes_symlinks() {
-        // Arrange: Create a temp home directory with a global skill and symlinks
+    fn test_check_roundtrip_stability() {
+        // Arrange
         let temp_home = create_temp_home();
         let home_path = temp_home.path().to_path_buf();
-        
-        // Create global skill
-        let skill_dir = home_path.join(".agents/skills/test-skill");
-        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
-        
-        // Create agent directories and symlinks
-        let cursor_path = home_path.join(".cursor/skills");
-        let claude_path = home_path.join(".claude/skills");
-        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
-        fs::create_dir_all(&claude_path).expect("Failed to create claude directory");
-        
-        // Create symlinks
-        let cursor_symlink = cursor_path.join("test-skill");
-        let claude_symlink = claude_path.join("test-skill");
-        create_symlink(&skill_dir, &cursor_symlink).expect("Failed to create cursor symlink");
-        create_symlink(&skill_dir, &claude_symlink).expect("Failed to create claude symlink");
-        
-        // Verify symlinks exist before unlink
-        assert!(cursor_symlink.exists(), "Cursor symlink should exist before unlink");
-        assert!(claude_symlink.exists(), "Claude symlink should exist before unlink");
-        
-        // Act: Unlink skill from all
-        let result = unlink_skill_from_all_with_home("test-skill", &home_path).expect("unlink_skill_from_all should succeed");
-        
-        // Assert: Both agents should be in success list
-        assert_eq!(result.success.len(), 2, "Should have 2 successful unlinks");
-        assert!(result.success.contains(&"cursor".to_string()), "cursor should be in success list");
-        assert!(result.success.contains(&"claude-code".to_string()), "claude-code should be in success list");
-        assert!(result.failed.is_empty(), "Should have no failures");
-        
-        // Verify symlinks were removed
-        assert!(!cursor_symlink.exists(), "Cursor symlink should be removed");
-        assert!(!claude_symlink.exists(), "Claude symlink should be removed");
+        let global_dir = home_path.join(".agents/skills");
+        let canonical = SkillMetadata {
+            name: "Canonical".to_string(),
+            description: "Already formatted".to_string(),
+            ..Default::default()
+        };
+        for (name, content) in [
+            ("canonical", format!("{}\n# Canonical\n\nBody text.\n", skill_parser::format_skill_md(&canonical))),
+            ("heading", "# Heading Skill\n\nDescribed by its first paragraph.\n".to_string()),
+        ] {
+            fs::create_dir_all(global_dir.join(name)).expect("Failed to create skill directory");
+            fs::write(global_dir.join(name).join("SKILL.md"), content).expect("Failed to write SKILL.md");
+        }
+
+        // Act
+        let unstable = check_roundtrip_stability_with_home(&home_path);
+
+        // Assert
+        assert_eq!(unstable, vec!["heading".to_string()]);
     }
 
-    /// Test that unlink_skill_from_all attempts to remove from ALL agents (not just detected)
-    /// 
-    /// **Validates: Requirements 1.5, 6.2**
-    /// - Unlike link_skill_to_all, unlink should attempt to remove symlinks from ALL agents
+    /// Test that a heading-format skill is converted once and a second call changes nothing
     #[test]
-    fn test_unlink_skill_from_all_removes_from_non_detected_agents() {
-        // Arrange: Create a temp home directory with symlinks but agent not "detected"
+    fn test_convert_skill_to_frontmatter_is_idempotent() {
+        // Arrange
         let temp_home = create_temp_home();
         let home_path = temp_home.path().to_path_buf();
-        
-        // Create global skill
-        let skill_dir = home_path.join(".agents/skills/test-skill");
+        let skill_dir = home_path.join(".agents/skills/heading");
         fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
-        
-        // Create only the symlink path (not the full agent skills directory)
-        // This simulates a case where the agent was previously detected but now isn't
-        let cursor_path = home_path.join(".cursor/skills");
-        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
-        
-        // Create symlink
-        let cursor_symlink = cursor_path.join("test-skill");
-        create_symlink(&skill_dir, &cursor_symlink).expect("Failed to create cursor symlink");
-        
-        // Verify symlink exists
-        assert!(cursor_symlink.exists(), "Cursor symlink should exist before unlink");
-        
-        // Act: Unlink skill from all
-        let result = unlink_skill_from_all_with_home("test-skill", &home_path).expect("unlink_skill_from_all should succeed");
-        
-        // Assert: cursor should be in success list even though it might not be "detected"
-        assert!(result.success.contains(&"cursor".to_string()), "cursor should be in success list");
-        assert!(result.failed.is_empty(), "Should have no failures");
-        
-        // Verify symlink was removed
-        assert!(!cursor_symlink.exists(), "Cursor symlink should be removed");
+        let original = "# Heading Skill\n\nDescribed by its first paragraph.\n";
+        fs::write(skill_dir.join("SKILL.md"), original).expect("Failed to write SKILL.md");
+
+        // Act
+        let first = convert_skill_to_frontmatter_with_home("heading", &home_path).expect("conversion should succeed");
+        let converted = fs::read_to_string(skill_dir.join("SKILL.md")).expect("Failed to read SKILL.md");
+        let second = convert_skill_to_frontmatter_with_home("heading", &home_path).expect("conversion should succeed");
+
+        // Assert
+        assert!(first);
+        assert!(!second, "A skill already in frontmatter form should be left alone");
+        assert!(converted.starts_with("---\nname: Heading Skill\n"), "Unexpected content: {}", converted);
+        assert!(converted.ends_with(original), "The body should be preserved");
+        assert_eq!(fs::read_to_string(skill_dir.join("SKILL.md")).unwrap(), converted);
     }
 
-    /// Test that unlink_skill_from_all handles no existing symlinks gracefully
-    /// 
-    /// **Validates: Requirements 1.5, 6.2**
+    /// Test that converting a UTF-16 heading-format skill keeps the file UTF-16
     #[test]
-    fn test_unlink_skill_from_all_handles_no_symlinks() {
-        // Arrange: Create a temp home directory with no symlinks
+    fn test_convert_skill_to_frontmatter_keeps_utf16() {
+        // Arrange
         let temp_home = create_temp_home();
         let home_path = temp_home.path().to_path_buf();
-        
-        // Create global skill
-        let skill_dir = home_path.join(".agents/skills/test-skill");
+        let skill_dir = home_path.join(".agents/skills/wide");
         fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
-        
-        // Create agent directories but no symlinks
-        let cursor_path = home_path.join(".cursor/skills");
-        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
-        
-        // Act: Unlink skill from all
-        let result = unlink_skill_from_all_with_home("test-skill", &home_path).expect("unlink_skill_from_all should succeed");
-        
-        // Assert: Success list should be empty (nothing to unlink), no failures
-        assert!(result.success.is_empty(), "Should have no successful unlinks (nothing to unlink)");
-        assert!(result.failed.is_empty(), "Should have no failures");
+        let original = "# Wide Skill\n\nWritten on Windows.\n";
+        fs::write(skill_dir.join("SKILL.md"), SkillMdEncoding::Utf16Le.encode(original).unwrap())
+            .expect("Failed to write SKILL.md");
+
+        // Act
+        let converted = convert_skill_to_frontmatter_with_home("wide", &home_path).expect("conversion should succeed");
+
+        // Assert
+        assert!(converted);
+        let bytes = fs::read(skill_dir.join("SKILL.md")).expect("Failed to read SKILL.md");
+        assert_eq!(SkillMdEncoding::detect(&bytes), SkillMdEncoding::Utf16Le);
+        let (content, _warning) = decode_skill_md(&bytes);
+        assert!(content.starts_with("---\nname: Wide Skill\n"), "Unexpected content: {}", content);
+        assert!(content.ends_with(original), "The body should be preserved");
     }
 
-    /// Test that unlink_skill_from_all does not remove regular files (only symlinks)
-    /// 
-    /// **Validates: Requirements 1.5, 6.2**
+    // ==================== Tool Validation Tests ====================
+
+    /// Test that a skill requesting `shell_exec` is flagged by a `*exec*` pattern and others are not
     #[test]
-    fn test_unlink_skill_from_all_ignores_regular_files() {
-        // Arrange: Create a temp home directory with a regular file instead of symlink
+    fn test_scan_skills_for_denied_tools_flags_glob_match() {
+        // Arrange
         let temp_home = create_temp_home();
         let home_path = temp_home.path().to_path_buf();
-        
-        // Create global skill
-        let skill_dir = home_path.join(".agents/skills/test-skill");
-        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
-        
-        // Create agent directory with a regular file (not symlink)
-        let cursor_path = home_path.join(".cursor/skills");
-        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
-        let cursor_file = cursor_path.join("test-skill");
-        fs::write(&cursor_file, "regular file content").expect("Failed to create regular file");
-        
-        // Act: Unlink skill from all
-        let result = unlink_skill_from_all_with_home("test-skill", &home_path).expect("unlink_skill_from_all should succeed");
-        
-        // Assert: Success list should be empty (regular file is not a symlink)
-        assert!(result.success.is_empty(), "Should have no successful unlinks (file is not a symlink)");
-        assert!(result.failed.is_empty(), "Should have no failures");
-        
-        // Verify regular file still exists
-        assert!(cursor_file.exists(), "Regular file should not be removed");
+        let global_dir = home_path.join(".agents/skills");
+        for (name, tools) in [("runner", "  - Read\n  - shell_exec\n"), ("reader", "  - Read\n  - Grep\n")] {
+            fs::create_dir_all(global_dir.join(name)).expect("Failed to create skill directory");
+            fs::write(global_dir.join(name).join("SKILL.md"), format!("---\nname: {}\nallowed-tools:\n{}---\n", name, tools))
+                .expect("Failed to write SKILL.md");
+        }
+
+        // Act
+        let flagged = scan_skills_for_denied_tools_with_home(&["*exec*".to_string(), "WebFetch".to_string()], &home_path);
+
+        // Assert
+        assert_eq!(flagged, vec![("runner".to_string(), vec!["shell_exec".to_string()])]);
+        assert!(glob_matches("Bash?", "bash1"));
+        assert!(!glob_matches("*exec", "exec_shell"));
     }
 
-    /// Test that unlink_skill_from_all does not remove directories (only symlinks)
-    /// 
-    /// **Validates: Requirements 1.5, 6.2**
+    /// Test that a misspelled tool is reported with the correct tool as a suggestion
     #[test]
-    fn test_unlink_skill_from_all_ignores_directories() {
-        // Arrange: Create a temp home directory with a directory instead of symlink
+    fn test_validate_tools_suggests_misspelled_tool() {
+        // Arrange: A skill listing one known tool, one typo and one unknown tool
         let temp_home = create_temp_home();
         let home_path = temp_home.path().to_path_buf();
-        
-        // Create global skill
-        let skill_dir = home_path.join(".agents/skills/test-skill");
+        let skill_dir = home_path.join(".agents/skills/my-skill");
         fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
-        
-        // Create agent directory with a subdirectory (not symlink)
-        let cursor_path = home_path.join(".cursor/skills");
-        let cursor_subdir = cursor_path.join("test-skill");
-        fs::create_dir_all(&cursor_subdir).expect("Failed to create cursor subdirectory");
-        
-        // Act: Unlink skill from all
-        let result = unlink_skill_from_all_with_home("test-skill", &home_path).expect("unlink_skill_from_all should succeed");
-        
-        // Assert: Success list should be empty (directory is not a symlink)
-        assert!(result.success.is_empty(), "Should have no successful unlinks (directory is not a symlink)");
-        assert!(result.failed.is_empty(), "Should have no failures");
-        
-        // Verify directory still exists
-        assert!(cursor_subdir.exists(), "Directory should not be removed");
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: My Skill\ndescription: Does things\nallowed-tools:\n  - Read\n  - Grpe\n  - Teleport\n---\n\n# Usage\n",
+        ).expect("Failed to write SKILL.md");
+        let known_tools: Vec<String> = ["Read", "Write", "Grep", "Bash"].iter().map(|t| t.to_string()).collect();
+
+        // Act
+        let issues = validate_tools_with_home("my-skill", &known_tools, false, &home_path)
+            .expect("validation should succeed");
+
+        // Assert
+        assert_eq!(issues, vec![
+            ToolIssue { tool: "Grpe".to_string(), suggestion: Some("Grep".to_string()), fixed: false },
+            ToolIssue { tool: "Teleport".to_string(), suggestion: None, fixed: false },
+        ]);
     }
 
-    /// Test that unlink_skill_from_all returns BatchResult with correct structure
-    /// 
-    /// **Validates: Requirements 1.5, 6.2**
+    /// Test that fix rewrites typos in SKILL.md and keeps the body
     #[test]
-    fn test_unlink_skill_from_all_returns_batch_result() {
-        // Arrange: Create a temp home directory with symlinks
+    fn test_validate_tools_fix_rewrites_typos() {
+        // Arrange
         let temp_home = create_temp_home();
         let home_path = temp_home.path().to_path_buf();
-        
-        // Create global skill
-        let skill_dir = home_path.join(".agents/skills/test-skill");
+        let skill_dir = home_path.join(".agents/skills/my-skill");
         fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
-        
-        // Create agent directories and symlinks
-        let cursor_path = home_path.join(".cursor/skills");
-        let claude_path = home_path.join(".claude/skills");
-        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
-        fs::create_dir_all(&claude_path).expect("Failed to create claude directory");
-        
-        // Create symlinks
-        create_symlink(&skill_dir, cursor_path.join("test-skill")).expect("Failed to create cursor symlink");
-        create_symlink(&skill_dir, claude_path.join("test-skill")).expect("Failed to create claude symlink");
-        
-        // Act: Unlink skill from all
-        let result = unlink_skill_from_all_with_home("test-skill", &home_path).expect("unlink_skill_from_all should succeed");
-        
-        // Assert: BatchResult should have correct structure
-        assert_eq!(result.success.len(), 2, "Should have 2 successful unlinks");
-        assert!(result.failed.is_empty(), "Should have no failures");
-        
-        // Verify success list contains agent IDs (strings)
-        for agent_id in &result.success {
-            assert!(!agent_id.is_empty(), "Agent ID should not be empty");
-        }
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: My Skill\ndescription: Does things\nallowed-tools:\n  - bash\n  - Teleport\n---\n\n# Usage\n",
+        ).expect("Failed to write SKILL.md");
+        let known_tools: Vec<String> = ["Read", "Bash"].iter().map(|t| t.to_string()).collect();
+
+        // Act
+        let issues = validate_tools_with_home("my-skill", &known_tools, true, &home_path)
+            .expect("validation should succeed");
+
+        // Assert: Only the typo is fixed, unknown tools are left alone
+        assert!(issues[0].fixed);
+        assert!(!issues[1].fixed);
+        let content = fs::read_to_string(skill_dir.join("SKILL.md")).expect("Failed to read SKILL.md");
+        assert_eq!(parse_skill_md(&content).allowed_tools, vec!["Bash", "Teleport"]);
+        assert!(content.ends_with("---\n\n# Usage\n"), "Body should be preserved");
     }
 
-    /// Test that unlink_skill_from_all handles mixed states (some symlinks, some not)
-    /// 
-    /// **Validates: Requirements 1.5, 6.2**
+    /// Test that tools inherited from the defaults file are not reported as fixable
     #[test]
-    fn test_unlink_skill_from_all_handles_mixed_states() {
-        // Arrange: Create a temp home directory with mixed states
+    fn test_validate_tools_ignores_inherited_defaults() {
+        // Arrange: The skill lists no tools, the defaults list a typo
         let temp_home = create_temp_home();
         let home_path = temp_home.path().to_path_buf();
-        
-        // Create global skill
-        let skill_dir = home_path.join(".agents/skills/test-skill");
-        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
-        
-        // Create agent directories
-        let cursor_path = home_path.join(".cursor/skills");
-        let claude_path = home_path.join(".claude/skills");
-        let amp_path = home_path.join(".config/agents/skills");
-        fs::create_dir_all(&cursor_path).expect("Failed to create cursor directory");
-        fs::create_dir_all(&claude_path).expect("Failed to create claude directory");
-        fs::create_dir_all(&amp_path).expect("Failed to create amp directory");
-        
-        // cursor: has symlink
-        let cursor_symlink = cursor_path.join("test-skill");
-        create_symlink(&skill_dir, &cursor_symlink).expect("Failed to create cursor symlink");
-        
-        // claude: has regular file
-        let claude_file = claude_path.join("test-skill");
-        fs::write(&claude_file, "regular file").expect("Failed to create claude file");
-        
-        // amp: no file at all
-        
-        // Act: Unlink skill from all
-        let result = unlink_skill_from_all_with_home("test-skill", &home_path).expect("unlink_skill_from_all should succeed");
-        
-        // Assert: Only cursor should be in success list
-        assert_eq!(result.success.len(), 1, "Should have 1 successful unlink");
-        assert!(result.success.contains(&"cursor".to_string()), "cursor should be in success list");
-        assert!(result.failed.is_empty(), "Should have no failures");
-        
-        // Verify cursor symlink was removed
-        assert!(!cursor_symlink.exists(), "Cursor symlink should be removed");
-        
-        // Verify claude file still exists
-        assert!(claude_file.exists(), "Claude regular file should not be removed");
+        let global_dir = home_path.join(".agents/skills");
+        fs::create_dir_all(global_dir.join("my-skill")).expect("Failed to create skill directory");
+        fs::write(global_dir.join("my-skill/SKILL.md"), "---\nname: My Skill\n---\n").expect("Failed to write SKILL.md");
+        fs::write(global_dir.join(DEFAULTS_FILE), "allowed-tools:\n  - bash\n").expect("Failed to write defaults");
+        let known_tools: Vec<String> = vec!["Bash".to_string()];
+
+        // Act
+        let issues = validate_tools_with_home("my-skill", &known_tools, true, &home_path)
+            .expect("validation should succeed");
+
+        // Assert
+        assert!(issues.is_empty(), "Unexpected issues: {:?}", issues);
     }
 }
 
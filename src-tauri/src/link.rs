@@ -0,0 +1,182 @@
+//! Skill Link Module
+//!
+//! Every link the manager creates goes through [`create_skill_link`], which hides the
+//! platform differences. On Unix it is a plain symlink. On Windows, directories are
+//! linked with a directory symlink and files with a file symlink; creating symlinks
+//! needs a privilege (or Developer Mode) that ordinary users often lack, so when a
+//! directory symlink fails with "privilege not held" the link is made as a directory
+//! junction instead, which any user may create. Files have no junction equivalent, so a
+//! file symlink failing the same way reports how to enable Developer Mode. Links are removed with
+//! [`remove_skill_link`], since Windows removes directory links and junctions as
+//! directories rather than files.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Windows error returned when the process may not create symlinks
+#[cfg(any(windows, test))]
+const ERROR_PRIVILEGE_NOT_HELD: i32 = 1314;
+
+/// Whether a failed symlink creation should be retried as a junction.
+#[cfg(any(windows, test))]
+fn needs_junction_fallback(error: &io::Error) -> bool {
+    error.raw_os_error() == Some(ERROR_PRIVILEGE_NOT_HELD)
+}
+
+/// Explains a file symlink that failed for lack of the symlink privilege; other errors
+/// are returned as they are.
+#[cfg(any(windows, test))]
+fn explain_file_symlink_error(error: io::Error) -> io::Error {
+    if !needs_junction_fallback(&error) {
+        return error;
+    }
+    io::Error::new(
+        error.kind(),
+        "Linking SKILL.md needs permission to create symlinks: enable Developer Mode in Windows Settings \
+         or run the app as administrator",
+    )
+}
+
+/// Creates a link at `dst` pointing at `src`.
+#[cfg(unix)]
+pub fn create_skill_link(src: &Path, dst: &Path) -> io::Result<()> {
+    std::os::unix::fs::symlink(src, dst)
+}
+
+/// Creates a link at `dst` pointing at `src`, falling back to a directory junction when
+/// the process lacks the privilege to create a directory symlink.
+#[cfg(windows)]
+pub fn create_skill_link(src: &Path, dst: &Path) -> io::Result<()> {
+    // Relative targets are relative to the directory holding the link
+    let resolved = match dst.parent() {
+        Some(parent) if src.is_relative() => parent.join(src),
+        _ => src.to_path_buf(),
+    };
+    if resolved.is_file() {
+        return std::os::windows::fs::symlink_file(src, dst).map_err(explain_file_symlink_error);
+    }
+    match std::os::windows::fs::symlink_dir(src, dst) {
+        Err(e) if needs_junction_fallback(&e) => create_junction(&resolved, dst),
+        result => result,
+    }
+}
+
+/// Creates a directory junction by setting its reparse point directly, without a shell.
+/// Junctions always store an absolute target.
+#[cfg(windows)]
+fn create_junction(target: &Path, dst: &Path) -> io::Result<()> {
+    junction::create(target, dst)
+}
+
+/// Repoints the link at `dst` to `src` without a moment where `dst` is missing: the new
+/// link is created beside the old one and renamed over it. On failure the old link is
+/// left (or put back) as it was.
+pub fn replace_skill_link(src: &Path, dst: &Path) -> io::Result<()> {
+    let file_name = dst.file_name().unwrap_or_default().to_string_lossy().to_string();
+    let temp = dst.with_file_name(format!(".{}.relink-{}", file_name, std::process::id()));
+    create_skill_link(src, &temp)?;
+    let replaced = fs::rename(&temp, dst).or_else(|_| {
+        // Windows cannot rename over a directory link, so swap the old one out first
+        let old_target = fs::read_link(dst)?;
+        remove_skill_link(dst)?;
+        fs::rename(&temp, dst).inspect_err(|_| {
+            let _ = create_skill_link(&old_target, dst);
+        })
+    });
+    if replaced.is_err() {
+        let _ = remove_skill_link(&temp);
+    }
+    replaced
+}
+
+/// Removes a link created by [`create_skill_link`], leaving its target untouched.
+#[cfg(unix)]
+pub fn remove_skill_link(link: &Path) -> io::Result<()> {
+    fs::remove_file(link)
+}
+
+/// Removes a link created by [`create_skill_link`], leaving its target untouched.
+/// Directory symlinks and junctions must be removed as directories.
+#[cfg(windows)]
+pub fn remove_skill_link(link: &Path) -> io::Result<()> {
+    use std::os::windows::fs::FileTypeExt;
+    if fs::symlink_metadata(link)?.file_type().is_symlink_dir() {
+        fs::remove_dir(link)
+    } else {
+        fs::remove_file(link)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test that only the privilege-not-held error triggers the junction fallback
+    #[test]
+    fn test_needs_junction_fallback() {
+        assert!(needs_junction_fallback(&io::Error::from_raw_os_error(ERROR_PRIVILEGE_NOT_HELD)));
+        assert!(!needs_junction_fallback(&io::Error::from_raw_os_error(5)));
+        assert!(!needs_junction_fallback(&io::Error::other("not an OS error")));
+    }
+
+    /// Test that a missing symlink privilege for a file link is explained, other errors kept as they are
+    #[test]
+    fn test_explain_file_symlink_error() {
+        let explained = explain_file_symlink_error(io::Error::from_raw_os_error(ERROR_PRIVILEGE_NOT_HELD));
+        let other = explain_file_symlink_error(io::Error::from_raw_os_error(5));
+
+        assert!(explained.to_string().contains("enable Developer Mode"));
+        assert_eq!(other.raw_os_error(), Some(5));
+    }
+
+    /// Test that a created link resolves to the skill directory
+    #[test]
+    fn test_create_skill_link_resolves_to_source() {
+        let temp = tempfile::TempDir::new().expect("Failed to create temp directory");
+        let src = temp.path().join("skill");
+        std::fs::create_dir_all(&src).expect("Failed to create skill directory");
+        std::fs::write(src.join("SKILL.md"), "---\nname: Skill\n---\n").expect("Failed to write SKILL.md");
+
+        create_skill_link(&src, &temp.path().join("link")).expect("Failed to create link");
+
+        assert!(temp.path().join("link/SKILL.md").is_file());
+        assert_eq!(
+            std::fs::canonicalize(temp.path().join("link")).unwrap(),
+            std::fs::canonicalize(&src).unwrap()
+        );
+    }
+
+    /// Test that replacing a link repoints it and leaves no temporary link behind
+    #[test]
+    fn test_replace_skill_link_repoints() {
+        let temp = tempfile::TempDir::new().expect("Failed to create temp directory");
+        let (old, new) = (temp.path().join("old"), temp.path().join("new"));
+        std::fs::create_dir_all(&old).expect("Failed to create old skill");
+        std::fs::create_dir_all(&new).expect("Failed to create new skill");
+        let link = temp.path().join("links/skill");
+        std::fs::create_dir_all(link.parent().unwrap()).expect("Failed to create link directory");
+        create_skill_link(&old, &link).expect("Failed to create link");
+
+        replace_skill_link(&new, &link).expect("Failed to replace link");
+
+        assert_eq!(std::fs::read_link(&link).unwrap(), new);
+        assert_eq!(std::fs::read_dir(link.parent().unwrap()).unwrap().count(), 1);
+    }
+
+    /// Test that removing a link leaves the skill it pointed at in place
+    #[test]
+    fn test_remove_skill_link_keeps_target() {
+        let temp = tempfile::TempDir::new().expect("Failed to create temp directory");
+        let src = temp.path().join("skill");
+        std::fs::create_dir_all(&src).expect("Failed to create skill directory");
+        std::fs::write(src.join("SKILL.md"), "---\nname: Skill\n---\n").expect("Failed to write SKILL.md");
+        let link = temp.path().join("link");
+        create_skill_link(&src, &link).expect("Failed to create link");
+
+        remove_skill_link(&link).expect("Failed to remove link");
+
+        assert!(std::fs::symlink_metadata(&link).is_err());
+        assert!(src.join("SKILL.md").is_file());
+    }
+}
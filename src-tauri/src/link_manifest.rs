@@ -0,0 +1,516 @@
+//! Link Manifest Module
+//!
+//! This module applies a declarative description of which global skills should be
+//! linked into which agents. The manifest is a JSON or TOML file (chosen by the
+//! `.toml` extension) mapping agent IDs to skill names, plus an optional `prune` flag:
+//!
+//! ```json
+//! { "prune": true, "cursor": ["review", "commit"], "claude-code": ["review"] }
+//! ```
+//!
+//! Missing links are created. With `prune`, symlinks into a global root in a listed
+//! agent that the manifest does not mention are removed; links pointing anywhere else,
+//! local directories and agents missing from the manifest are left untouched.
+//!
+//! The report records every removed link's targets, so an apply can be rolled back.
+
+use crate::confirm::ConfirmPreview;
+use crate::repair::normalize_lexically;
+use crate::{
+    check_link_not_cyclic, create_symlink, detect_agents_with_home, ensure_not_safe_mode, find_global_skill,
+    get_home_dir, global_skill_roots_with_home, is_contents_link, link, link_with_layout, remove_contents_link,
+    validate_skill_name, BatchResult, FailedOperation, LinkLayout,
+};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Desired link state read from a manifest file
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct LinkManifest {
+    /// Remove symlinks in listed agents that the manifest does not mention
+    #[serde(default)]
+    pub prune: bool,
+    /// Skill names to link, by agent ID
+    #[serde(flatten)]
+    pub agents: BTreeMap<String, Vec<String>>,
+}
+
+/// A link removed by pruning, with what it pointed at so it can be recreated
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct RemovedLink {
+    pub name: String,
+    pub target: Option<String>,            // Symlink target, for a directory symlink
+    pub entries: BTreeMap<String, String>, // Symlink target by entry name, for a directory of symlinks
+}
+
+/// Changes made to one agent while applying a manifest
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default)]
+pub struct AgentApplyReport {
+    pub agent_id: String,
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub errors: Vec<FailedOperation>, // Keyed by skill name; the agent ID for agent-level errors
+    #[serde(default)]
+    pub removed_links: Vec<RemovedLink>, // Prior state of each removed link, in `removed` order
+}
+
+/// Result of applying a manifest, one entry per agent in the manifest
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default)]
+pub struct ApplyReport {
+    pub agents: Vec<AgentApplyReport>,
+}
+
+/// Reads a manifest file, parsing it as TOML for `.toml` files and JSON otherwise.
+pub fn read_link_manifest(path: &PathBuf) -> Result<LinkManifest, String> {
+    let content = fs::read_to_string(path).map_err(|e| format!("Failed to read manifest: {}", e))?;
+    if path.extension().map(|ext| ext == "toml").unwrap_or(false) {
+        toml::from_str(&content).map_err(|e| format!("Failed to parse manifest: {}", e))
+    } else {
+        serde_json::from_str(&content).map_err(|e| format!("Failed to parse manifest: {}", e))
+    }
+}
+
+/// Whether the symlink at `path` points inside one of `roots`, resolving a relative
+/// target against the real directory holding the link.
+fn target_in_roots(path: &Path, roots: &[PathBuf]) -> bool {
+    let Ok(target) = fs::read_link(path) else {
+        return false;
+    };
+    let parent = path.parent().map(|parent| fs::canonicalize(parent).unwrap_or_else(|_| parent.to_path_buf()));
+    let target = normalize_lexically(&parent.unwrap_or_default().join(target));
+    roots.iter().any(|root| target.starts_with(root) && target != *root)
+}
+
+/// Lists the links in an agent that pruning would remove, sorted by name. Only links
+/// into a global root are pruned; local skills and links the user pointed elsewhere
+/// are kept.
+fn prunable_links(agent_dir: &PathBuf, layout: LinkLayout, skills: &[String], roots: &[PathBuf]) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(agent_dir) else {
+        return Vec::new();
+    };
+    // Roots as configured and as resolved, since links may use either spelling
+    let roots: Vec<PathBuf> = roots
+        .iter()
+        .flat_map(|root| [normalize_lexically(root), fs::canonicalize(root).unwrap_or_else(|_| normalize_lexically(root))])
+        .collect();
+    let mut names: Vec<String> = entries
+        .flatten()
+        .filter(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.starts_with('.') || skills.contains(&name) {
+                return false;
+            }
+            let path = entry.path();
+            let is_symlink = fs::symlink_metadata(&path).map(|m| m.file_type().is_symlink()).unwrap_or(false);
+            if is_symlink {
+                target_in_roots(&path, &roots)
+            } else if layout != LinkLayout::Directory && is_contents_link(&path) {
+                fs::read_dir(&path)
+                    .map(|inner| inner.flatten().all(|inner| target_in_roots(&inner.path(), &roots)))
+                    .unwrap_or(false)
+            } else {
+                false
+            }
+        })
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .collect();
+    names.sort();
+    names
+}
+
+/// Links the skills listed for one agent and, when pruning, removes unlisted symlinks.
+fn apply_to_agent(
+    agent_dir: &PathBuf,
+    layout: LinkLayout,
+    skills: &[String],
+    prune: bool,
+    roots: &[PathBuf],
+    report: &mut AgentApplyReport,
+) {
+    let mut error = |skill: &str, message: String| {
+        report.errors.push(FailedOperation { agent_id: skill.to_string(), error: message });
+    };
+
+    for skill_name in skills {
+        if let Err(e) = validate_skill_name(skill_name) {
+            error(skill_name, e);
+            continue;
+        }
+        let Some(global_skill_path) = find_global_skill(roots, skill_name) else {
+            error(skill_name, "Skill not found in global skills".to_string());
+            continue;
+        };
+        let link = agent_dir.join(skill_name);
+        if let Ok(metadata) = fs::symlink_metadata(&link) {
+            let contents_linked = layout != LinkLayout::Directory && is_contents_link(&link);
+            if !metadata.file_type().is_symlink() && !contents_linked {
+                error(skill_name, "A file or directory already exists at the target path".to_string());
+            }
+            continue;
+        }
+        let result = fs::create_dir_all(agent_dir)
+            .map_err(|e| format!("Failed to create agent directory: {}", e))
+            .and_then(|_| check_link_not_cyclic(&global_skill_path, &link))
+            .and_then(|_| {
+                link_with_layout(&global_skill_path, &link, layout).map_err(|e| format!("Failed to create symlink: {}", e))
+            });
+        match result {
+            Ok(_) => report.added.push(skill_name.clone()),
+            Err(e) => error(skill_name, e),
+        }
+    }
+
+    if !prune {
+        return;
+    }
+    for name in prunable_links(agent_dir, layout, skills, roots) {
+        let path = agent_dir.join(&name);
+        let read_target = |path: &PathBuf| fs::read_link(path).map(|t| t.to_string_lossy().to_string()).ok();
+        let mut removed_link = RemovedLink { name: name.clone(), ..Default::default() };
+        let is_symlink = fs::symlink_metadata(&path).map(|m| m.file_type().is_symlink()).unwrap_or(false);
+        let result = if is_symlink {
+            removed_link.target = read_target(&path);
+            link::remove_skill_link(&path)
+        } else {
+            for inner in fs::read_dir(&path).into_iter().flatten().flatten() {
+                if let Some(target) = read_target(&inner.path()) {
+                    removed_link.entries.insert(inner.file_name().to_string_lossy().to_string(), target);
+                }
+            }
+            remove_contents_link(&path)
+        };
+        match result {
+            Ok(_) => {
+                report.removed.push(name);
+                report.removed_links.push(removed_link);
+            }
+            Err(e) => error(&name, format!("Failed to remove symlink: {}", e)),
+        }
+    }
+}
+
+/// Applies a link manifest file. Each agent's changes and errors are reported separately;
+/// a problem with one agent or skill never stops the others.
+pub fn apply_manifest_with_home(manifest_path: &PathBuf, home: &PathBuf) -> Result<ApplyReport, String> {
+    ensure_not_safe_mode(home)?;
+    let manifest = read_link_manifest(manifest_path)?;
+    let agents = detect_agents_with_home(home);
+    let roots = global_skill_roots_with_home(home);
+
+    let mut report = ApplyReport::default();
+    for (agent_id, skills) in &manifest.agents {
+        let mut agent_report = AgentApplyReport { agent_id: agent_id.clone(), ..Default::default() };
+        match agents.iter().find(|a| &a.id == agent_id) {
+            Some(agent) => {
+                apply_to_agent(&home.join(&agent.path), agent.link_layout, skills, manifest.prune, &roots, &mut agent_report)
+            }
+            None => agent_report.errors.push(FailedOperation {
+                agent_id: agent_id.clone(),
+                error: format!("Agent '{}' not found", agent_id),
+            }),
+        }
+        report.agents.push(agent_report);
+    }
+
+    Ok(report)
+}
+
+/// Lists the links, as `agent_id/skill_name`, that applying a manifest would prune, with a
+/// confirmation token. The token also covers the manifest's content, so editing the
+/// manifest invalidates it. A manifest without `prune` affects nothing.
+pub fn apply_manifest_preview_with_home(manifest_path: &PathBuf, home: &PathBuf) -> Result<ConfirmPreview, String> {
+    let content = fs::read_to_string(manifest_path).map_err(|e| format!("Failed to read manifest: {}", e))?;
+    let manifest = read_link_manifest(manifest_path)?;
+    let mut affected: Vec<String> = Vec::new();
+    if manifest.prune {
+        let agents = detect_agents_with_home(home);
+        let roots = global_skill_roots_with_home(home);
+        for (agent_id, skills) in &manifest.agents {
+            if let Some(agent) = agents.iter().find(|a| &a.id == agent_id) {
+                for name in prunable_links(&home.join(&agent.path), agent.link_layout, skills, &roots) {
+                    affected.push(format!("{}/{}", agent_id, name));
+                }
+            }
+        }
+    }
+    Ok(ConfirmPreview::new("apply_manifest", &content, affected))
+}
+
+#[tauri::command]
+pub fn apply_manifest_preview(manifest_path: String) -> Result<ConfirmPreview, String> {
+    let home = get_home_dir();
+    apply_manifest_preview_with_home(&PathBuf::from(manifest_path), &home)
+}
+
+/// Applies a link manifest. A pruning manifest only runs if `confirm_token` matches the
+/// current preview.
+pub fn apply_manifest_confirmed_with_home(
+    manifest_path: &PathBuf,
+    confirm_token: Option<&str>,
+    home: &PathBuf,
+) -> Result<ApplyReport, String> {
+    if read_link_manifest(manifest_path)?.prune {
+        apply_manifest_preview_with_home(manifest_path, home)?.verify(confirm_token)?;
+    }
+    apply_manifest_with_home(manifest_path, home)
+}
+
+#[tauri::command]
+pub fn apply_manifest(manifest_path: String, confirm_token: Option<String>) -> Result<ApplyReport, String> {
+    // No library backup: applying only changes agent directories, and the returned
+    // report is what `rollback_apply` uses to undo it
+    let home = get_home_dir();
+    apply_manifest_confirmed_with_home(&PathBuf::from(manifest_path), confirm_token.as_deref(), &home)
+}
+
+/// Recreates a link removed by pruning.
+fn restore_link(link: &PathBuf, removed: &RemovedLink) -> Result<(), String> {
+    if fs::symlink_metadata(link).is_ok() {
+        return Err("Something already exists at the link path".to_string());
+    }
+    if let Some(target) = &removed.target {
+        return create_symlink(target, link).map_err(|e| format!("Failed to create symlink: {}", e));
+    }
+    fs::create_dir(link).map_err(|e| format!("Failed to create directory: {}", e))?;
+    for (entry, target) in &removed.entries {
+        create_symlink(target, link.join(entry)).map_err(|e| format!("Failed to create symlink: {}", e))?;
+    }
+    Ok(())
+}
+
+/// Undoes an apply from its report: links it added are removed and links it pruned are
+/// recreated with their original targets. Anything that is no longer a link, or a path
+/// that has been reused since, is reported as failed and left alone.
+/// Results are keyed by `agent_id/skill_name`.
+pub fn rollback_apply_with_home(report: &ApplyReport, home: &PathBuf) -> Result<BatchResult, String> {
+    ensure_not_safe_mode(home)?;
+    let agents = detect_agents_with_home(home);
+
+    let mut success: Vec<String> = Vec::new();
+    let mut failed: Vec<FailedOperation> = Vec::new();
+    for agent_report in &report.agents {
+        let Some(agent) = agents.iter().find(|a| a.id == agent_report.agent_id) else {
+            continue;
+        };
+        let agent_dir = home.join(&agent.path);
+        let key = |name: &str| format!("{}/{}", agent.id, name);
+
+        for name in &agent_report.added {
+            if let Err(e) = validate_skill_name(name) {
+                failed.push(FailedOperation { agent_id: key(name), error: e });
+                continue;
+            }
+            let link = agent_dir.join(name);
+            let is_symlink = fs::symlink_metadata(&link).map(|m| m.file_type().is_symlink()).unwrap_or(false);
+            let result = if is_symlink {
+                link::remove_skill_link(&link).map_err(|e| format!("Failed to remove symlink: {}", e))
+            } else if agent.link_layout != LinkLayout::Directory && is_contents_link(&link) {
+                remove_contents_link(&link).map_err(|e| format!("Failed to remove linked directory: {}", e))
+            } else {
+                Err("No longer a link created by the apply".to_string())
+            };
+            match result {
+                Ok(_) => success.push(key(name)),
+                Err(e) => failed.push(FailedOperation { agent_id: key(name), error: e }),
+            }
+        }
+
+        for removed in &agent_report.removed_links {
+            if let Err(e) = validate_skill_name(&removed.name) {
+                failed.push(FailedOperation { agent_id: key(&removed.name), error: e });
+                continue;
+            }
+            match restore_link(&agent_dir.join(&removed.name), removed) {
+                Ok(_) => success.push(key(&removed.name)),
+                Err(e) => failed.push(FailedOperation { agent_id: key(&removed.name), error: e }),
+            }
+        }
+    }
+
+    Ok(BatchResult { success, failed, ..Default::default() })
+}
+
+#[tauri::command]
+pub fn rollback_apply(report: ApplyReport) -> Result<BatchResult, String> {
+    let home = get_home_dir();
+    rollback_apply_with_home(&report, &home)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::create_symlink;
+    use tempfile::TempDir;
+
+    /// Test that a pruning manifest is refused without a valid confirmation token and applied with one
+    #[test]
+    fn test_apply_manifest_prune_requires_confirm_token() {
+        // Arrange: cursor links "old", which a pruning manifest does not list
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        for name in ["review", "old"] {
+            fs::create_dir_all(global_dir.join(name)).expect("Failed to create skill directory");
+        }
+        let cursor_dir = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_dir).expect("Failed to create cursor directory");
+        create_symlink(global_dir.join("old"), cursor_dir.join("old")).expect("Failed to create symlink");
+        let manifest_path = home_path.join("skills.json");
+        fs::write(&manifest_path, r#"{"prune": true, "cursor": ["review"]}"#).expect("Failed to write manifest");
+
+        // Act
+        let preview = apply_manifest_preview_with_home(&manifest_path, &home_path).expect("preview should succeed");
+        let refused = apply_manifest_confirmed_with_home(&manifest_path, Some("stale"), &home_path);
+
+        // Assert: Nothing changed
+        assert_eq!(preview.affected, vec!["cursor/old".to_string()]);
+        assert!(refused.is_err());
+        assert!(fs::symlink_metadata(cursor_dir.join("old")).is_ok());
+        assert!(fs::symlink_metadata(cursor_dir.join("review")).is_err());
+
+        // Act
+        let report = apply_manifest_confirmed_with_home(&manifest_path, Some(&preview.confirm_token), &home_path)
+            .expect("confirmed apply should succeed");
+
+        // Assert
+        assert_eq!(report.agents[0].removed, vec!["old".to_string()]);
+        assert_eq!(report.agents[0].added, vec!["review".to_string()]);
+    }
+
+    /// Test that a two-agent manifest links missing skills and prunes unlisted symlinks
+    #[test]
+    fn test_apply_manifest_two_agents() {
+        // Arrange: Three global skills; cursor already links "old", claude has a local skill
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        for name in ["review", "commit", "old"] {
+            fs::create_dir_all(global_dir.join(name)).expect("Failed to create skill directory");
+        }
+        let cursor_dir = home_path.join(".cursor/skills");
+        let claude_dir = home_path.join(".claude/skills");
+        fs::create_dir_all(claude_dir.join("local-skill")).expect("Failed to create local skill");
+        fs::create_dir_all(&cursor_dir).expect("Failed to create cursor directory");
+        create_symlink(global_dir.join("old"), cursor_dir.join("old")).expect("Failed to create symlink");
+        let manifest_path = home_path.join("skills.toml");
+        fs::write(
+            &manifest_path,
+            "prune = true\ncursor = [\"review\", \"commit\"]\nclaude-code = [\"review\", \"missing\"]\n",
+        ).expect("Failed to write manifest");
+
+        // Act
+        let report = apply_manifest_with_home(&manifest_path, &home_path).expect("apply should succeed");
+
+        // Assert: Agents reported in manifest order (sorted by ID)
+        assert_eq!(report.agents.len(), 2);
+        let claude = &report.agents[0];
+        assert_eq!(claude.agent_id, "claude-code");
+        assert_eq!(claude.added, vec!["review".to_string()]);
+        assert!(claude.removed.is_empty(), "Local skills must not be pruned");
+        assert_eq!(claude.errors.len(), 1);
+        assert_eq!(claude.errors[0].agent_id, "missing");
+        assert!(claude_dir.join("local-skill").is_dir());
+
+        let cursor = &report.agents[1];
+        assert_eq!(cursor.added, vec!["review".to_string(), "commit".to_string()]);
+        assert_eq!(cursor.removed, vec!["old".to_string()]);
+        assert!(cursor.errors.is_empty());
+        assert_eq!(fs::read_link(cursor_dir.join("commit")).expect("Should be a symlink"), global_dir.join("commit"));
+        assert!(fs::symlink_metadata(cursor_dir.join("old")).is_err(), "Unlisted symlink should be pruned");
+
+        // Act & Assert: Applying again changes nothing
+        let again = apply_manifest_with_home(&manifest_path, &home_path).expect("apply should succeed");
+        assert!(again.agents.iter().all(|a| a.added.is_empty() && a.removed.is_empty()));
+    }
+
+    /// Test that pruning keeps links pointing outside the global roots and rejects invalid names
+    #[test]
+    fn test_apply_manifest_prune_keeps_user_links_and_rejects_invalid_names() {
+        // Arrange: cursor links "old" from the library and "mine" from a user checkout
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        for name in ["review", "old"] {
+            fs::create_dir_all(global_dir.join(name)).expect("Failed to create skill directory");
+        }
+        let checkout = home_path.join("src/mine");
+        fs::create_dir_all(&checkout).expect("Failed to create checkout");
+        let cursor_dir = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_dir).expect("Failed to create cursor directory");
+        create_symlink(global_dir.join("old"), cursor_dir.join("old")).expect("Failed to create symlink");
+        create_symlink(&checkout, cursor_dir.join("mine")).expect("Failed to create symlink");
+        let manifest_path = home_path.join("skills.json");
+        fs::write(&manifest_path, r#"{"prune": true, "cursor": ["review", "../../.agents/skills/review"]}"#)
+            .expect("Failed to write manifest");
+
+        // Act
+        let report = apply_manifest_with_home(&manifest_path, &home_path).expect("apply should succeed");
+
+        // Assert: Only the library link is pruned, and the traversing name is refused
+        let cursor = &report.agents[0];
+        assert_eq!(cursor.added, vec!["review".to_string()]);
+        assert_eq!(cursor.removed, vec!["old".to_string()]);
+        assert_eq!(cursor.errors.len(), 1);
+        assert!(cursor.errors[0].error.contains("Invalid skill name"));
+        assert_eq!(fs::read_link(cursor_dir.join("mine")).expect("User link should remain"), checkout);
+    }
+
+    /// Link targets of every entry in an agent directory, None for real directories
+    fn link_state(dir: &PathBuf) -> BTreeMap<String, Option<PathBuf>> {
+        fs::read_dir(dir)
+            .expect("Failed to read agent directory")
+            .flatten()
+            .map(|entry| (entry.file_name().to_string_lossy().to_string(), fs::read_link(entry.path()).ok()))
+            .collect()
+    }
+
+    /// Test that rolling back an apply restores the exact pre-apply links
+    #[test]
+    fn test_rollback_apply_restores_previous_state() {
+        // Arrange: cursor links "old" and has a local skill; the manifest links "review" and prunes
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        for name in ["review", "old"] {
+            fs::create_dir_all(global_dir.join(name)).expect("Failed to create skill directory");
+        }
+        let cursor_dir = home_path.join(".cursor/skills");
+        fs::create_dir_all(cursor_dir.join("local-skill")).expect("Failed to create local skill");
+        create_symlink(global_dir.join("old"), cursor_dir.join("old")).expect("Failed to create symlink");
+        let manifest_path = home_path.join("skills.json");
+        fs::write(&manifest_path, r#"{ "prune": true, "cursor": ["review"] }"#).expect("Failed to write manifest");
+        let before = link_state(&cursor_dir);
+
+        // Act
+        let report = apply_manifest_with_home(&manifest_path, &home_path).expect("apply should succeed");
+        assert_ne!(link_state(&cursor_dir), before, "Apply should change the agent");
+        let rollback = rollback_apply_with_home(&report, &home_path).expect("rollback should succeed");
+
+        // Assert
+        assert!(rollback.failed.is_empty(), "Unexpected failures: {:?}", rollback.failed);
+        assert_eq!(rollback.success, vec!["cursor/review".to_string(), "cursor/old".to_string()]);
+        assert_eq!(link_state(&cursor_dir), before);
+    }
+
+    /// Test that a JSON manifest without `prune` leaves unlisted links in place
+    #[test]
+    fn test_apply_manifest_json_without_prune() {
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        fs::create_dir_all(global_dir.join("review")).expect("Failed to create skill directory");
+        fs::create_dir_all(global_dir.join("old")).expect("Failed to create skill directory");
+        let cursor_dir = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_dir).expect("Failed to create cursor directory");
+        create_symlink(global_dir.join("old"), cursor_dir.join("old")).expect("Failed to create symlink");
+        let manifest_path = home_path.join("skills.json");
+        fs::write(&manifest_path, r#"{ "cursor": ["review"] }"#).expect("Failed to write manifest");
+
+        let report = apply_manifest_with_home(&manifest_path, &home_path).expect("apply should succeed");
+
+        assert_eq!(report.agents[0].added, vec!["review".to_string()]);
+        assert!(report.agents[0].removed.is_empty());
+        assert!(fs::symlink_metadata(cursor_dir.join("old")).is_ok());
+    }
+}
@@ -0,0 +1,109 @@
+//! Link Metadata Module
+//!
+//! A copy-style link is a local directory that the manager created by copying a
+//! global skill into an agent. On disk it looks exactly like a skill the user wrote
+//! locally, so each agent's skills directory keeps a hidden `.link-meta.json` sidecar
+//! recording which local directories the manager created. Normalizing back to
+//! symlinks only replaces those, never a user's own local skill.
+
+use crate::{detect_agents_with_home, get_home_dir};
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Name of the sidecar file inside an agent's skills directory
+pub const LINK_META_FILE: &str = ".link-meta.json";
+
+/// Manager-created state of one agent's skills directory
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct LinkMeta {
+    pub copies: BTreeSet<String>, // Local directories created as copy-style links
+}
+
+/// Reads an agent's sidecar. A missing or invalid file means nothing is tracked.
+pub fn read_link_meta(agent_dir: &Path) -> LinkMeta {
+    fs::read_to_string(agent_dir.join(LINK_META_FILE))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Writes an agent's sidecar, removing it when nothing is tracked.
+pub fn write_link_meta(agent_dir: &Path, meta: &LinkMeta) -> Result<(), String> {
+    let path = agent_dir.join(LINK_META_FILE);
+    if meta.copies.is_empty() {
+        if path.exists() {
+            fs::remove_file(&path).map_err(|e| format!("Failed to remove link metadata: {}", e))?;
+        }
+        return Ok(());
+    }
+    let content = serde_json::to_string_pretty(meta).map_err(|e| format!("Failed to serialize link metadata: {}", e))?;
+    fs::write(&path, content).map_err(|e| format!("Failed to write link metadata: {}", e))
+}
+
+/// Lists the local directories in an agent that the manager created as copy-style links
+/// and that still exist, sorted by name.
+pub fn list_manager_copies_with_home(agent_id: &str, home: &PathBuf) -> Result<Vec<String>, String> {
+    let agent = detect_agents_with_home(home)
+        .into_iter()
+        .find(|a| a.id == agent_id)
+        .ok_or_else(|| format!("Agent '{}' not found", agent_id))?;
+    let agent_dir = home.join(&agent.path);
+
+    Ok(read_link_meta(&agent_dir)
+        .copies
+        .into_iter()
+        .filter(|name| {
+            fs::symlink_metadata(agent_dir.join(name))
+                .map(|m| m.file_type().is_dir())
+                .unwrap_or(false)
+        })
+        .collect())
+}
+
+#[tauri::command]
+pub fn list_manager_copies(agent_id: String) -> Result<Vec<String>, String> {
+    let home = get_home_dir();
+    list_manager_copies_with_home(&agent_id, &home)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{create_symlink, normalize_agent_links_with_home, LinkStyle};
+    use tempfile::TempDir;
+
+    /// Test that a copy made by the manager is tracked and a user's identical local skill is not
+    #[test]
+    fn test_manager_copy_tracked_and_user_copy_untouched() {
+        // Arrange: Two global skills; cursor links one and has its own local copy of the other
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        let cursor_dir = home_path.join(".cursor/skills");
+        for dir in [global_dir.join("linked"), global_dir.join("mine"), cursor_dir.join("mine")] {
+            fs::create_dir_all(&dir).expect("Failed to create skill directory");
+            fs::write(dir.join("SKILL.md"), "---\nname: Skill\n---\n").expect("Failed to write SKILL.md");
+        }
+        create_symlink(global_dir.join("linked"), cursor_dir.join("linked")).expect("Failed to create symlink");
+
+        // Act: Convert to copies
+        normalize_agent_links_with_home("cursor", LinkStyle::Copy, &home_path).expect("normalize should succeed");
+
+        // Assert: Only the manager-created copy is tracked
+        assert_eq!(list_manager_copies_with_home("cursor", &home_path), Ok(vec!["linked".to_string()]));
+
+        // Act: Convert back to symlinks
+        let result = normalize_agent_links_with_home("cursor", LinkStyle::Symlink, &home_path)
+            .expect("normalize should succeed");
+
+        // Assert: The manager's copy is a symlink again; the user's local skill is untouched
+        assert_eq!(result.success, vec!["linked".to_string()]);
+        assert_eq!(result.skipped.len(), 1);
+        assert_eq!(result.skipped[0].agent_id, "mine");
+        assert!(!fs::symlink_metadata(cursor_dir.join("mine")).unwrap().file_type().is_symlink());
+        assert_eq!(list_manager_copies_with_home("cursor", &home_path), Ok(Vec::new()));
+        assert!(!cursor_dir.join(LINK_META_FILE).exists());
+    }
+}
@@ -0,0 +1,359 @@
+//! Metadata Manifest Module
+//!
+//! Parsing every SKILL.md on each `get_app_data` call is slow for very large
+//! libraries. This module caches each skill's parsed metadata, together with the
+//! SKILL.md modification time and size, in a hidden `.manifest.json` at the top of
+//! each global root. When a manifest is present, only skills whose SKILL.md changed
+//! since it was built are parsed again. The manifest is only written by
+//! `build_manifest`; a missing or unreadable manifest means no caching.
+//!
+//! Several app instances may rebuild at once, so each build holds an advisory lock
+//! file in the root, refreshing it while it works, and replaces the manifest
+//! atomically through a temporary file.
+//! A reader therefore never sees a half-written manifest from a build, and a manifest
+//! corrupted some other way is simply ignored.
+
+use crate::{
+    ensure_not_safe_mode, get_home_dir, global_skill_roots_with_home, load_skill_metadata, SkillMetadata, DEFAULTS_FILE,
+};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Name of the manifest file inside each global root
+pub const MANIFEST_FILE: &str = ".manifest.json";
+
+/// Name of the advisory lock file held while a root's manifest is rebuilt
+pub const MANIFEST_LOCK_FILE: &str = ".manifest.lock";
+
+/// A lock not refreshed for this long is assumed to be left behind by a crashed build
+const STALE_LOCK_SECS: u64 = 60;
+
+/// How often a build refreshes its lock, well within [`STALE_LOCK_SECS`]
+const LOCK_REFRESH_SECS: u64 = 10;
+
+/// Cached metadata of a single skill
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct ManifestEntry {
+    pub metadata: SkillMetadata,
+    pub mtime: Option<u64>, // SKILL.md modification time in nanoseconds since the epoch, None if missing
+    #[serde(default)]
+    pub size: Option<u64>, // SKILL.md size in bytes, None if missing; catches edits that keep the mtime
+}
+
+/// Cached metadata of every skill in one global root, keyed by directory name
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct Manifest {
+    pub skills: BTreeMap<String, ManifestEntry>,
+    #[serde(default)]
+    pub defaults_mtime: Option<u64>, // Modification time of the root's defaults file, None if missing
+}
+
+/// Modification time of a file, in nanoseconds since the epoch.
+fn file_mtime(path: &Path) -> Option<u64> {
+    let modified = fs::metadata(path).and_then(|m| m.modified()).ok()?;
+    let since_epoch = modified.duration_since(std::time::UNIX_EPOCH).ok()?;
+    u64::try_from(since_epoch.as_nanos()).ok()
+}
+
+/// Modification time of a skill's SKILL.md, in nanoseconds since the epoch.
+fn skill_md_mtime(skill_dir: &Path) -> Option<u64> {
+    file_mtime(&skill_dir.join("SKILL.md"))
+}
+
+/// Size of a skill's SKILL.md in bytes.
+fn skill_md_size(skill_dir: &Path) -> Option<u64> {
+    fs::metadata(skill_dir.join("SKILL.md")).map(|m| m.len()).ok()
+}
+
+/// Advisory lock on a root's manifest, released when dropped. The holder calls
+/// [`ManifestLock::refresh`] as it works so a long build is never taken for a crashed one.
+/// The lock file holds a token unique to the holder, so a holder whose stale lock was
+/// taken over does not remove the new owner's lock.
+struct ManifestLock {
+    path: PathBuf,
+    file: fs::File,
+    token: String,
+    refreshed: std::time::Instant,
+}
+
+impl ManifestLock {
+    /// Takes the lock by creating the lock file exclusively. Fails if another build
+    /// holds it, unless that lock has not been refreshed recently.
+    fn acquire(root: &Path) -> Result<Self, String> {
+        let path = root.join(MANIFEST_LOCK_FILE);
+        let is_stale = || {
+            fs::metadata(&path)
+                .and_then(|m| m.modified())
+                .ok()
+                .and_then(|modified| modified.elapsed().ok())
+                .map(|age| age.as_secs() >= STALE_LOCK_SECS)
+                .unwrap_or(false)
+        };
+        for _ in 0..2 {
+            match fs::OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(mut file) => {
+                    let token = lock_token();
+                    if let Err(e) = file.write_all(token.as_bytes()) {
+                        let _ = fs::remove_file(&path);
+                        return Err(format!("Failed to lock manifest: {}", e));
+                    }
+                    return Ok(Self { path, file, token, refreshed: std::time::Instant::now() });
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists && is_stale() => {
+                    let _ = fs::remove_file(&path);
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => break,
+                Err(e) => return Err(format!("Failed to lock manifest: {}", e)),
+            }
+        }
+        Err(format!("Manifest in '{}' is being rebuilt by another process", root.display()))
+    }
+
+    /// Bumps the lock file's modification time if it has not been bumped recently.
+    fn refresh(&mut self) {
+        if self.refreshed.elapsed().as_secs() >= LOCK_REFRESH_SECS {
+            let _ = self.file.set_modified(std::time::SystemTime::now());
+            self.refreshed = std::time::Instant::now();
+        }
+    }
+}
+
+impl Drop for ManifestLock {
+    fn drop(&mut self) {
+        if fs::read_to_string(&self.path).is_ok_and(|content| content == self.token) {
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
+
+/// A token identifying one lock holder: the process id plus a per-process counter
+/// and the current time.
+fn lock_token() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0);
+    format!("{}-{}-{}", std::process::id(), COUNTER.fetch_add(1, Ordering::Relaxed), nanos)
+}
+
+/// Writes the manifest to a temporary file and renames it over the old one, so readers
+/// see either the previous manifest or the complete new one.
+fn write_manifest_atomically(root: &Path, manifest: &Manifest) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(manifest).map_err(|e| format!("Failed to serialize manifest: {}", e))?;
+    let temp_path = root.join(format!("{}.tmp-{}", MANIFEST_FILE, std::process::id()));
+    fs::write(&temp_path, content).map_err(|e| format!("Failed to write manifest: {}", e))?;
+    fs::rename(&temp_path, root.join(MANIFEST_FILE)).map_err(|e| {
+        let _ = fs::remove_file(&temp_path);
+        format!("Failed to replace manifest: {}", e)
+    })
+}
+
+/// Reads the manifest of a global root, if one exists and is valid.
+pub fn read_manifest(root: &Path) -> Option<Manifest> {
+    let content = fs::read_to_string(root.join(MANIFEST_FILE)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Returns a skill's metadata from the manifest when its SKILL.md has the same
+/// modification time and size, otherwise parses SKILL.md. Editing the root's
+/// defaults file invalidates every entry.
+pub fn cached_skill_metadata(manifest: Option<&Manifest>, skill_dir: &Path, dir_name: &str) -> SkillMetadata {
+    let defaults_mtime = skill_dir.parent().and_then(|root| file_mtime(&root.join(DEFAULTS_FILE)));
+    if let Some(entry) = manifest.filter(|m| m.defaults_mtime == defaults_mtime).and_then(|m| m.skills.get(dir_name)) {
+        if entry.mtime == skill_md_mtime(skill_dir) && entry.size == skill_md_size(skill_dir) {
+            return entry.metadata.clone();
+        }
+    }
+    load_skill_metadata(skill_dir, dir_name)
+}
+
+/// Parses every global skill and writes a manifest into each existing global root.
+/// Fails without writing a root's manifest if another build holds its lock.
+pub fn build_manifest_with_home(home: &PathBuf) -> Result<(), String> {
+    ensure_not_safe_mode(home)?;
+    for root in global_skill_roots_with_home(home) {
+        if !root.is_dir() {
+            continue;
+        }
+        let mut lock = ManifestLock::acquire(&root)?;
+        let Ok(entries) = fs::read_dir(&root) else {
+            continue;
+        };
+        let mut manifest = Manifest { defaults_mtime: file_mtime(&root.join(DEFAULTS_FILE)), ..Default::default() };
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.starts_with('.') || !entry.path().is_dir() {
+                continue;
+            }
+            lock.refresh();
+            let skill_dir = entry.path();
+            manifest.skills.insert(
+                name.clone(),
+                ManifestEntry {
+                    mtime: skill_md_mtime(&skill_dir),
+                    size: skill_md_size(&skill_dir),
+                    metadata: load_skill_metadata(&skill_dir, &name),
+                },
+            );
+        }
+        write_manifest_atomically(&root, &manifest)?;
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn build_manifest() -> Result<(), String> {
+    let home = get_home_dir();
+    build_manifest_with_home(&home)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{get_app_data_with_home, SKILL_MD_PARSES};
+    use tempfile::TempDir;
+
+    /// Test that unchanged skills are served from the manifest and changed ones are re-parsed
+    #[test]
+    fn test_manifest_serves_unchanged_skills_without_parsing() {
+        // Arrange: Two skills and a freshly built manifest
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        for name in ["alpha", "beta"] {
+            fs::create_dir_all(global_dir.join(name)).expect("Failed to create skill directory");
+            fs::write(global_dir.join(name).join("SKILL.md"), format!("---\nname: {}\n---\n", name))
+                .expect("Failed to write SKILL.md");
+        }
+        build_manifest_with_home(&home_path).expect("build should succeed");
+        assert!(global_dir.join(MANIFEST_FILE).exists());
+
+        // Act: Load with nothing changed
+        SKILL_MD_PARSES.with(|count| count.set(0));
+        let app_data = get_app_data_with_home(&home_path);
+
+        // Assert: Both skills come from the manifest, which is not listed as a skill
+        assert_eq!(SKILL_MD_PARSES.with(|count| count.get()), 0);
+        assert_eq!(app_data.skills.len(), 2);
+
+        // Act: Change one skill, with a modification time that clearly differs
+        let beta_md = global_dir.join("beta/SKILL.md");
+        fs::write(&beta_md, "---\nname: Beta Updated\n---\n").expect("Failed to write SKILL.md");
+        let later = std::time::SystemTime::now() + std::time::Duration::from_secs(60);
+        fs::File::options().write(true).open(&beta_md).and_then(|f| f.set_modified(later))
+            .expect("Failed to set modification time");
+        SKILL_MD_PARSES.with(|count| count.set(0));
+        let app_data = get_app_data_with_home(&home_path);
+
+        // Assert: Only the changed skill was parsed again
+        assert_eq!(SKILL_MD_PARSES.with(|count| count.get()), 1);
+        let beta = app_data.skills.iter().find(|s| s.name == "beta").expect("beta not found");
+        assert_eq!(beta.metadata.name, "Beta Updated");
+    }
+
+    /// Test that an edit which keeps the modification time is still noticed by its size
+    #[test]
+    fn test_manifest_detects_edit_with_unchanged_mtime() {
+        // Arrange
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let skill_md = home_path.join(".agents/skills/alpha/SKILL.md");
+        fs::create_dir_all(skill_md.parent().unwrap()).expect("Failed to create skill directory");
+        fs::write(&skill_md, "---\nname: alpha\n---\n").expect("Failed to write SKILL.md");
+        build_manifest_with_home(&home_path).expect("build should succeed");
+        let modified = fs::metadata(&skill_md).and_then(|m| m.modified()).expect("Failed to read mtime");
+
+        // Act: Rewrite it and put the old modification time back
+        fs::write(&skill_md, "---\nname: Alpha Renamed\n---\n").expect("Failed to write SKILL.md");
+        fs::File::options().write(true).open(&skill_md).and_then(|f| f.set_modified(modified))
+            .expect("Failed to set modification time");
+        let app_data = get_app_data_with_home(&home_path);
+
+        // Assert
+        assert_eq!(app_data.skills[0].metadata.name, "Alpha Renamed");
+    }
+
+    /// Test that a corrupted manifest is ignored and every skill is parsed live
+    #[test]
+    fn test_corrupted_manifest_falls_back_to_full_scan() {
+        // Arrange: A built manifest that is then truncated mid-write
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        for name in ["alpha", "beta"] {
+            fs::create_dir_all(global_dir.join(name)).expect("Failed to create skill directory");
+            fs::write(global_dir.join(name).join("SKILL.md"), format!("---\nname: {}\n---\n", name))
+                .expect("Failed to write SKILL.md");
+        }
+        build_manifest_with_home(&home_path).expect("build should succeed");
+        let manifest_path = global_dir.join(MANIFEST_FILE);
+        let content = fs::read_to_string(&manifest_path).expect("Failed to read manifest");
+        fs::write(&manifest_path, &content[..content.len() / 2]).expect("Failed to corrupt manifest");
+
+        // Act
+        SKILL_MD_PARSES.with(|count| count.set(0));
+        let app_data = get_app_data_with_home(&home_path);
+
+        // Assert: Both skills were parsed again and loaded correctly
+        assert_eq!(SKILL_MD_PARSES.with(|count| count.get()), 2);
+        let mut names: Vec<String> = app_data.skills.iter().map(|s| s.metadata.name.clone()).collect();
+        names.sort();
+        assert_eq!(names, vec!["alpha", "beta"]);
+
+        // Assert: Rebuilding replaces the corrupted manifest and leaves no lock or temp file
+        build_manifest_with_home(&home_path).expect("rebuild should succeed");
+        assert!(read_manifest(&global_dir).is_some());
+        let leftovers: Vec<String> = fs::read_dir(&global_dir)
+            .expect("Failed to read global directory")
+            .flatten()
+            .map(|e| e.file_name().to_string_lossy().to_string())
+            .filter(|name| name.starts_with(".manifest") && name != MANIFEST_FILE)
+            .collect();
+        assert!(leftovers.is_empty(), "Unexpected files: {:?}", leftovers);
+    }
+
+    /// Test that a build is refused while another process holds the manifest lock
+    #[test]
+    fn test_build_manifest_refused_while_locked() {
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        fs::create_dir_all(global_dir.join("alpha")).expect("Failed to create skill directory");
+        fs::write(global_dir.join(MANIFEST_LOCK_FILE), "").expect("Failed to create lock file");
+
+        let error = build_manifest_with_home(&home_path).expect_err("locked build should error");
+
+        assert!(error.contains("being rebuilt"), "Unexpected error: {}", error);
+        assert!(!global_dir.join(MANIFEST_FILE).exists());
+        assert!(global_dir.join(MANIFEST_LOCK_FILE).exists(), "Another process's lock must not be removed");
+    }
+
+    /// Test that a lock is only taken over once its holder stops refreshing it
+    #[test]
+    fn test_manifest_lock_refresh_keeps_it_from_going_stale() {
+        // Arrange: A held lock whose last refresh is long past
+        let temp_root = TempDir::new().expect("Failed to create temp directory");
+        let mut lock = ManifestLock::acquire(temp_root.path()).expect("lock should be free");
+        let long_ago = std::time::SystemTime::now() - std::time::Duration::from_secs(STALE_LOCK_SECS * 2);
+        lock.file.set_modified(long_ago).expect("Failed to age lock file");
+        lock.refreshed -= std::time::Duration::from_secs(LOCK_REFRESH_SECS);
+
+        // Act
+        lock.refresh();
+        let while_refreshed = ManifestLock::acquire(temp_root.path());
+        lock.file.set_modified(long_ago).expect("Failed to age lock file");
+        let after_crash = ManifestLock::acquire(temp_root.path());
+        drop(lock);
+
+        // Assert: The old holder's drop leaves the new owner's lock in place
+        assert!(while_refreshed.is_err(), "A refreshed lock must not be taken over");
+        assert!(after_crash.is_ok(), "A lock that is no longer refreshed should be taken over");
+        assert!(temp_root.path().join(MANIFEST_LOCK_FILE).exists(), "The new owner's lock must not be removed");
+        drop(after_crash);
+        assert!(!temp_root.path().join(MANIFEST_LOCK_FILE).exists());
+    }
+}
@@ -0,0 +1,140 @@
+//! Permissions Module
+//!
+//! Normalizes file modes inside global skills on Unix: directories become 0o755,
+//! files 0o644, and scripts (`*.sh` or files starting with a `#!` shebang) 0o755.
+//! Symlinks inside a skill are left untouched.
+
+use crate::BatchResult;
+#[cfg(unix)]
+use crate::{
+    ensure_not_safe_mode, find_global_skill, get_home_dir, global_skill_names, global_skill_roots_with_home,
+    FailedOperation,
+};
+#[cfg(unix)]
+use std::fs;
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+#[cfg(unix)]
+use std::path::{Path, PathBuf};
+
+#[cfg(unix)]
+const DIR_MODE: u32 = 0o755;
+#[cfg(unix)]
+const FILE_MODE: u32 = 0o644;
+#[cfg(unix)]
+const SCRIPT_MODE: u32 = 0o755;
+
+/// Returns whether a file should be executable: a `.sh` name or a shebang line.
+#[cfg(unix)]
+fn is_script(path: &Path) -> bool {
+    if path.extension().map(|ext| ext == "sh").unwrap_or(false) {
+        return true;
+    }
+    let mut prefix = [0u8; 2];
+    fs::File::open(path)
+        .and_then(|mut file| std::io::Read::read_exact(&mut file, &mut prefix))
+        .map(|_| &prefix == b"#!")
+        .unwrap_or(false)
+}
+
+/// Recursively applies the standard modes below (and including) `dir`.
+#[cfg(unix)]
+fn fix_dir_permissions(dir: &Path) -> Result<(), String> {
+    let set_mode = |path: &Path, mode: u32| {
+        fs::set_permissions(path, fs::Permissions::from_mode(mode))
+            .map_err(|e| format!("Failed to set permissions on {}: {}", path.to_string_lossy(), e))
+    };
+
+    set_mode(dir, DIR_MODE)?;
+    let entries = fs::read_dir(dir).map_err(|e| format!("Failed to read directory: {}", e))?;
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("Failed to read entry: {}", e))?;
+        let path = entry.path();
+        let file_type = fs::symlink_metadata(&path)
+            .map_err(|e| format!("Failed to read metadata: {}", e))?
+            .file_type();
+        if file_type.is_symlink() {
+            continue;
+        } else if file_type.is_dir() {
+            fix_dir_permissions(&path)?;
+        } else {
+            set_mode(&path, if is_script(&path) { SCRIPT_MODE } else { FILE_MODE })?;
+        }
+    }
+    Ok(())
+}
+
+/// Fixes permissions in the named global skill, or every global skill when `None`.
+/// Results are keyed by skill name.
+#[cfg(unix)]
+pub fn fix_permissions_with_home(skill_name: Option<String>, home: &PathBuf) -> Result<BatchResult, String> {
+    ensure_not_safe_mode(home)?;
+    let roots = global_skill_roots_with_home(home);
+    let skill_names = skill_name.map(|name| vec![name]).unwrap_or_else(|| {
+        let mut names: Vec<String> = global_skill_names(&roots).into_iter().collect();
+        names.sort();
+        names
+    });
+
+    let mut success: Vec<String> = Vec::new();
+    let mut failed: Vec<FailedOperation> = Vec::new();
+    for name in skill_names {
+        let result = find_global_skill(&roots, &name)
+            .ok_or_else(|| "Skill not found in global skills".to_string())
+            .and_then(|skill_dir| fix_dir_permissions(&skill_dir));
+        match result {
+            Ok(_) => success.push(name),
+            Err(e) => failed.push(FailedOperation { agent_id: name, error: e }),
+        }
+    }
+
+    Ok(BatchResult { success, failed, ..Default::default() })
+}
+
+#[tauri::command]
+pub fn fix_permissions(skill_name: Option<String>) -> Result<BatchResult, String> {
+    #[cfg(unix)]
+    {
+        let home = get_home_dir();
+        fix_permissions_with_home(skill_name, &home)
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = skill_name;
+        Err("Fixing permissions is only supported on Unix".to_string())
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn mode(path: &Path) -> u32 {
+        fs::metadata(path).expect("Failed to read metadata").permissions().mode() & 0o777
+    }
+
+    /// Test that restrictive modes are normalized and scripts stay executable
+    #[test]
+    fn test_fix_permissions_corrects_modes() {
+        // Arrange: A skill with a 0o600 file, a 0o700 directory and a shebang script
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let skill_dir = home_path.join(".agents/skills/locked");
+        fs::create_dir_all(skill_dir.join("bin")).expect("Failed to create skill directory");
+        fs::write(skill_dir.join("SKILL.md"), "---\nname: Locked\n---\n").expect("Failed to write SKILL.md");
+        fs::write(skill_dir.join("bin/run"), "#!/bin/sh\necho hi\n").expect("Failed to write script");
+        fs::set_permissions(skill_dir.join("SKILL.md"), fs::Permissions::from_mode(0o600)).unwrap();
+        fs::set_permissions(skill_dir.join("bin/run"), fs::Permissions::from_mode(0o600)).unwrap();
+        fs::set_permissions(skill_dir.join("bin"), fs::Permissions::from_mode(0o700)).unwrap();
+
+        // Act
+        let result = fix_permissions_with_home(None, &home_path).expect("fix should succeed");
+
+        // Assert
+        assert_eq!(result.success, vec!["locked".to_string()]);
+        assert_eq!(mode(&skill_dir.join("SKILL.md")), 0o644);
+        assert_eq!(mode(&skill_dir.join("bin")), 0o755);
+        assert_eq!(mode(&skill_dir.join("bin/run")), 0o755);
+    }
+}
@@ -0,0 +1,207 @@
+//! Skill Quarantine Module
+//!
+//! This module moves skills out of the global library into `~/.agents/.quarantine`
+//! so that malformed skills cannot reach any agent. Only a skill that currently fails
+//! validation is quarantined. Quarantining removes the agent symlinks that resolve to
+//! the skill, leaving same-named links to anything else alone. The skill's original
+//! root is remembered in a hidden `.<name>.origin` file so it can be restored to the
+//! same place later.
+//!
+//! Because the quarantine directory is hidden and outside the global roots,
+//! quarantined skills never appear in `get_app_data`.
+
+use crate::{
+    ensure_not_safe_mode, find_global_skill, get_home_dir, global_skill_roots_with_home, load_skill_metadata, move_dir,
+    unlink_skill_from_all_verified_with_home, validate_skill_name, validate_skill_with_home, BatchResult,
+    SkillMetadata,
+};
+use std::fs;
+use std::path::PathBuf;
+
+/// Location of the quarantine directory, relative to the home directory
+pub const QUARANTINE_DIR: &str = ".agents/.quarantine";
+
+/// A skill currently held in quarantine
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct QuarantinedSkill {
+    pub name: String,
+    pub metadata: SkillMetadata,
+    pub original_root: String, // Global root the skill is restored to
+}
+
+/// Path of the file recording where a quarantined skill came from
+fn origin_file(quarantine_dir: &std::path::Path, skill_name: &str) -> PathBuf {
+    quarantine_dir.join(format!(".{}.origin", skill_name))
+}
+
+/// Moves a global skill that fails validation into quarantine and removes its symlinks
+/// from all agents. Returns the unlink results per agent; a same-named link pointing
+/// elsewhere is kept and reported in `failed`.
+pub fn quarantine_skill_with_home(skill_name: &str, home: &PathBuf) -> Result<BatchResult, String> {
+    ensure_not_safe_mode(home)?;
+    validate_skill_name(skill_name)?;
+    let roots = global_skill_roots_with_home(home);
+    let skill_path = find_global_skill(&roots, skill_name)
+        .ok_or_else(|| format!("Skill '{}' not found in global skills", skill_name))?;
+    if validate_skill_with_home(skill_name, home)?.is_empty() {
+        return Err(format!("Skill '{}' passes validation and is not quarantined", skill_name));
+    }
+    let quarantine_dir = home.join(QUARANTINE_DIR);
+    let quarantine_path = quarantine_dir.join(skill_name);
+    if fs::symlink_metadata(&quarantine_path).is_ok() {
+        return Err(format!("Skill '{}' is already in quarantine", skill_name));
+    }
+
+    // Remove links first so no agent is left pointing at a moved skill
+    let result = unlink_skill_from_all_verified_with_home(skill_name, home)?;
+
+    fs::create_dir_all(&quarantine_dir)
+        .map_err(|e| format!("Failed to create quarantine directory: {}", e))?;
+    let root = skill_path.parent().map(|p| p.to_path_buf()).unwrap_or_default();
+    move_dir(&skill_path, &quarantine_path)?;
+    fs::write(origin_file(&quarantine_dir, skill_name), root.to_string_lossy().as_bytes())
+        .map_err(|e| format!("Failed to record original location: {}", e))?;
+
+    Ok(result)
+}
+
+#[tauri::command]
+pub fn quarantine_skill(skill_name: String) -> Result<BatchResult, String> {
+    let home = get_home_dir();
+    quarantine_skill_with_home(&skill_name, &home)
+}
+
+/// Restores a quarantined skill to the root it came from (or the first global root
+/// if that is unknown). Agent links are not recreated.
+pub fn unquarantine_skill_with_home(skill_name: &str, home: &PathBuf) -> Result<(), String> {
+    ensure_not_safe_mode(home)?;
+    validate_skill_name(skill_name)?;
+    let quarantine_dir = home.join(QUARANTINE_DIR);
+    let quarantine_path = quarantine_dir.join(skill_name);
+    if !quarantine_path.is_dir() {
+        return Err(format!("Skill '{}' is not in quarantine", skill_name));
+    }
+
+    let roots = global_skill_roots_with_home(home);
+    if find_global_skill(&roots, skill_name).is_some() {
+        return Err(format!("Skill '{}' already exists in global skills", skill_name));
+    }
+    let root = fs::read_to_string(origin_file(&quarantine_dir, skill_name))
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| roots[0].clone());
+
+    fs::create_dir_all(&root).map_err(|e| format!("Failed to create global skills directory: {}", e))?;
+    move_dir(&quarantine_path, &root.join(skill_name))?;
+    let _ = fs::remove_file(origin_file(&quarantine_dir, skill_name));
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn unquarantine_skill(skill_name: String) -> Result<(), String> {
+    let home = get_home_dir();
+    unquarantine_skill_with_home(&skill_name, &home)
+}
+
+/// Lists the skills currently in quarantine, sorted by name.
+pub fn list_quarantined_with_home(home: &PathBuf) -> Vec<QuarantinedSkill> {
+    let quarantine_dir = home.join(QUARANTINE_DIR);
+    let default_root = global_skill_roots_with_home(home)[0].clone();
+
+    let mut skills: Vec<QuarantinedSkill> = fs::read_dir(&quarantine_dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|entry| entry.path().is_dir())
+                .map(|entry| entry.file_name().to_string_lossy().to_string())
+                .filter(|name| !name.starts_with('.'))
+                .map(|name| QuarantinedSkill {
+                    metadata: load_skill_metadata(&quarantine_dir.join(&name), &name),
+                    original_root: fs::read_to_string(origin_file(&quarantine_dir, &name))
+                        .unwrap_or_else(|_| default_root.to_string_lossy().to_string()),
+                    name,
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    skills.sort_by(|a, b| a.name.cmp(&b.name));
+    skills
+}
+
+#[tauri::command]
+pub fn list_quarantined() -> Vec<QuarantinedSkill> {
+    let home = get_home_dir();
+    list_quarantined_with_home(&home)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{create_symlink, get_app_data_with_home};
+    use tempfile::TempDir;
+
+    /// Test that a quarantined skill disappears from app data, loses its links, and can be restored
+    #[test]
+    fn test_quarantine_removes_skill_and_links() {
+        // Arrange: A global skill linked into cursor
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let skill_dir = home_path.join(".agents/skills/bad-skill");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        fs::write(skill_dir.join("SKILL.md"), "not valid").expect("Failed to write SKILL.md");
+        let cursor_link = home_path.join(".cursor/skills/bad-skill");
+        fs::create_dir_all(cursor_link.parent().unwrap()).expect("Failed to create cursor directory");
+        create_symlink(&skill_dir, &cursor_link).expect("Failed to create symlink");
+
+        // Act
+        let result = quarantine_skill_with_home("bad-skill", &home_path).expect("quarantine should succeed");
+
+        // Assert: Links removed, skill hidden from app data but listed in quarantine
+        assert_eq!(result.success, vec!["cursor".to_string()]);
+        assert!(fs::symlink_metadata(&cursor_link).is_err(), "Symlink should be removed");
+        let app_data = get_app_data_with_home(&home_path);
+        assert!(app_data.skills.iter().all(|s| s.name != "bad-skill"), "Skill should be excluded from app data");
+        let quarantined = list_quarantined_with_home(&home_path);
+        assert_eq!(quarantined.len(), 1);
+        assert_eq!(quarantined[0].name, "bad-skill");
+
+        // Act: Restore it
+        unquarantine_skill_with_home("bad-skill", &home_path).expect("unquarantine should succeed");
+
+        // Assert
+        assert!(skill_dir.join("SKILL.md").exists(), "Skill should be back in its root");
+        assert!(list_quarantined_with_home(&home_path).is_empty());
+    }
+
+    /// Test that a valid skill, a traversing name and a foreign same-named link are all refused or kept
+    #[test]
+    fn test_quarantine_guards() {
+        // Arrange: A valid skill and a failing one; amp's link of the failing name points elsewhere
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        fs::create_dir_all(global_dir.join("good-skill")).expect("Failed to create skill directory");
+        fs::write(global_dir.join("good-skill/SKILL.md"), "---\nname: Good Skill\ndescription: Does good things\n---\n")
+            .expect("Failed to write SKILL.md");
+        fs::create_dir_all(global_dir.join("bad-skill")).expect("Failed to create skill directory");
+        fs::write(global_dir.join("bad-skill/SKILL.md"), "not valid").expect("Failed to write SKILL.md");
+        let elsewhere = home_path.join("elsewhere/bad-skill");
+        fs::create_dir_all(&elsewhere).expect("Failed to create unrelated directory");
+        let amp_link = home_path.join(".config/agents/skills/bad-skill");
+        fs::create_dir_all(amp_link.parent().unwrap()).expect("Failed to create amp directory");
+        create_symlink(&elsewhere, &amp_link).expect("Failed to create symlink");
+
+        // Act
+        let valid = quarantine_skill_with_home("good-skill", &home_path);
+        let traversal = quarantine_skill_with_home("../skills/bad-skill", &home_path);
+        let failing = quarantine_skill_with_home("bad-skill", &home_path).expect("quarantine should succeed");
+
+        // Assert
+        assert!(valid.expect_err("valid skill should be refused").contains("passes validation"));
+        assert!(global_dir.join("good-skill").is_dir());
+        assert!(traversal.expect_err("traversal should be refused").contains("Invalid skill name"));
+        assert_eq!(failing.failed.len(), 1);
+        assert_eq!(failing.failed[0].agent_id, "amp");
+        assert_eq!(fs::read_link(&amp_link).expect("Foreign link should remain"), elsewhere);
+    }
+}
@@ -0,0 +1,152 @@
+//! Recommended Skills Module
+//!
+//! Teams can agree on a recommended baseline of global skills that every agent
+//! should have. This module compares an agent against such a list and links the
+//! skills it is missing. Anything already at a skill's path in the agent, whether a
+//! symlink or a local directory, counts as installed.
+
+use crate::{
+    detect_agents_with_home, ensure_not_safe_mode, find_global_skill, get_home_dir, global_skill_roots_with_home,
+    is_skill_disabled, toggle_skill_with_home, BatchResult, FailedOperation,
+};
+use std::fs;
+use std::path::PathBuf;
+
+/// How an agent compares to a recommended list
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct RecommendedGap {
+    pub missing: Vec<String>, // Recommended global skills the agent does not have, in list order
+    pub unknown: Vec<String>, // Recommended names with no global skill, in list order
+}
+
+/// Computes which recommended skills an agent is missing. Names without a global
+/// skill are reported as unknown rather than missing.
+pub fn missing_recommended_skills_with_home(
+    agent_id: &str,
+    recommended: &[String],
+    home: &PathBuf,
+) -> Result<RecommendedGap, String> {
+    let agent = detect_agents_with_home(home)
+        .into_iter()
+        .find(|a| a.id == agent_id)
+        .ok_or_else(|| format!("Agent '{}' not found", agent_id))?;
+    let agent_dir = home.join(&agent.path);
+    let roots = global_skill_roots_with_home(home);
+
+    let mut gap = RecommendedGap::default();
+    for name in recommended {
+        if gap.missing.contains(name) || gap.unknown.contains(name) {
+            continue;
+        }
+        if find_global_skill(&roots, name).is_none() {
+            gap.unknown.push(name.clone());
+        } else if fs::symlink_metadata(agent_dir.join(name)).is_err() {
+            gap.missing.push(name.clone());
+        }
+    }
+    Ok(gap)
+}
+
+#[tauri::command]
+pub fn missing_recommended_skills(agent_id: String, recommended: Vec<String>) -> Result<RecommendedGap, String> {
+    let home = get_home_dir();
+    missing_recommended_skills_with_home(&agent_id, &recommended, &home)
+}
+
+/// Links every recommended skill the agent is missing. Unknown names are reported as
+/// failed and disabled skills in `skipped_disabled`, as in the other batch link
+/// commands; results are keyed by skill name.
+pub fn install_recommended_with_home(agent_id: &str, recommended: &[String], home: &PathBuf) -> Result<BatchResult, String> {
+    ensure_not_safe_mode(home)?;
+    let gap = missing_recommended_skills_with_home(agent_id, recommended, home)?;
+
+    let roots = global_skill_roots_with_home(home);
+    let mut success: Vec<String> = Vec::new();
+    let mut skipped_disabled: Vec<FailedOperation> = Vec::new();
+    let mut failed: Vec<FailedOperation> = gap
+        .unknown
+        .into_iter()
+        .map(|name| FailedOperation { agent_id: name, error: "Skill not found in global skills".to_string() })
+        .collect();
+    for name in gap.missing {
+        if find_global_skill(&roots, &name).is_some_and(|skill_dir| is_skill_disabled(&skill_dir)) {
+            skipped_disabled.push(FailedOperation { agent_id: name, error: "Skill is disabled".to_string() });
+            continue;
+        }
+        match toggle_skill_with_home(agent_id, &name, true, home) {
+            Ok(()) => success.push(name),
+            Err(error) => failed.push(FailedOperation { agent_id: name, error: error.to_string() }),
+        }
+    }
+
+    Ok(BatchResult { success, failed, skipped_disabled, ..Default::default() })
+}
+
+#[tauri::command]
+pub fn install_recommended(agent_id: String, recommended: Vec<String>) -> Result<BatchResult, String> {
+    let home = get_home_dir();
+    install_recommended_with_home(&agent_id, &recommended, &home)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{create_symlink, DISABLED_MARKER};
+    use tempfile::TempDir;
+
+    /// Test that an agent missing one of three recommended skills reports and installs only that one
+    #[test]
+    fn test_missing_recommended_skills_and_install() {
+        // Arrange: Three global skills; cursor links one and has a local copy of another
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        for name in ["alpha", "beta", "gamma"] {
+            fs::create_dir_all(global_dir.join(name)).expect("Failed to create skill directory");
+        }
+        let cursor_dir = home_path.join(".cursor/skills");
+        fs::create_dir_all(cursor_dir.join("beta")).expect("Failed to create local skill");
+        create_symlink(global_dir.join("alpha"), cursor_dir.join("alpha")).expect("Failed to create symlink");
+        let recommended: Vec<String> = ["alpha", "beta", "gamma", "nonexistent"].iter().map(|s| s.to_string()).collect();
+
+        // Act
+        let gap = missing_recommended_skills_with_home("cursor", &recommended, &home_path).expect("should succeed");
+
+        // Assert
+        assert_eq!(gap.missing, vec!["gamma".to_string()]);
+        assert_eq!(gap.unknown, vec!["nonexistent".to_string()]);
+
+        // Act: Install the missing ones
+        let result = install_recommended_with_home("cursor", &recommended, &home_path).expect("install should succeed");
+
+        // Assert
+        assert_eq!(result.success, vec!["gamma".to_string()]);
+        assert_eq!(result.failed.len(), 1);
+        assert_eq!(result.failed[0].agent_id, "nonexistent");
+        assert!(fs::symlink_metadata(cursor_dir.join("gamma")).unwrap().file_type().is_symlink());
+        let gap = missing_recommended_skills_with_home("cursor", &recommended, &home_path).expect("should succeed");
+        assert!(gap.missing.is_empty());
+    }
+
+    /// Test that a disabled recommended skill is skipped rather than linked
+    #[test]
+    fn test_install_recommended_skips_disabled_skill() {
+        // Arrange
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        fs::create_dir_all(global_dir.join("paused")).expect("Failed to create skill directory");
+        fs::write(global_dir.join("paused").join(DISABLED_MARKER), "").expect("Failed to write marker");
+        let cursor_dir = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_dir).expect("Failed to create cursor directory");
+
+        // Act
+        let result = install_recommended_with_home("cursor", &["paused".to_string()], &home_path).expect("install should succeed");
+
+        // Assert
+        assert!(result.success.is_empty());
+        assert_eq!(result.skipped_disabled.len(), 1);
+        assert_eq!(result.skipped_disabled[0].agent_id, "paused");
+        assert!(fs::symlink_metadata(cursor_dir.join("paused")).is_err());
+    }
+}
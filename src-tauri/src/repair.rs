@@ -0,0 +1,527 @@
+//! Link Repair Module
+//!
+//! This module finds and fixes agent symlinks that no longer point where they should.
+//! Stale absolute links are symlinks created under a different home directory (after a
+//! machine migration or username change): their target still encodes the old home,
+//! e.g. `/home/olduser/.agents/skills/foo`, while the library now lives under the
+//! current home. Symlink chains are agent links whose target is itself a symlink;
+//! they resolve, but break as soon as any intermediate link moves. Links into an old
+//! library location, for example after moving the global skills elsewhere, can be
+//! pruned by path.
+
+use crate::config::resolve_path;
+use crate::{
+    detect_agents_with_home, ensure_not_safe_mode, get_home_dir, global_skill_roots_with_home, link, BatchResult,
+    FailedOperation,
+};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+/// If `target` is an absolute path into `rel_root` under some other home, returns the
+/// equivalent path under `home`.
+fn rebase_onto_home(target: &Path, home: &Path, rel_root: &Path) -> Option<PathBuf> {
+    if !target.is_absolute() || target.starts_with(home) {
+        return None;
+    }
+    let target_parts: Vec<Component> = target.components().collect();
+    let root_parts: Vec<Component> = rel_root.components().collect();
+    if root_parts.is_empty() {
+        return None;
+    }
+    // Find the relative root inside the target, after at least one leading component
+    (1..target_parts.len().saturating_sub(root_parts.len())).find_map(|start| {
+        if target_parts[start..start + root_parts.len()] == root_parts[..] {
+            let rest: PathBuf = target_parts[start + root_parts.len()..].iter().collect();
+            Some(home.join(rel_root).join(rest))
+        } else {
+            None
+        }
+    })
+}
+
+/// A symlink whose absolute target points into another home's library
+struct StaleLink {
+    agent_id: String,
+    skill_name: String,
+    link: PathBuf,
+    old_target: PathBuf,
+    new_target: PathBuf,
+}
+
+/// Finds symlinks whose absolute target references a global root under a different home,
+/// in agent definition order, then skill name.
+fn stale_absolute_links(home: &PathBuf) -> Vec<StaleLink> {
+    let roots = global_skill_roots_with_home(home);
+    // Only roots under the home directory can be rebased onto another home
+    let rel_roots: Vec<PathBuf> = roots
+        .iter()
+        .filter_map(|root| root.strip_prefix(home).ok().map(|rel| rel.to_path_buf()))
+        .collect();
+
+    let mut stale = Vec::new();
+    for agent in detect_agents_with_home(home).into_iter().filter(|a| a.detected) {
+        let Ok(entries) = fs::read_dir(home.join(&agent.path)) else {
+            continue;
+        };
+        let mut entries: Vec<_> = entries.flatten().collect();
+        entries.sort_by_key(|entry| entry.file_name());
+        for entry in entries {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.starts_with('.') {
+                continue;
+            }
+            let Ok(target) = fs::read_link(entry.path()) else {
+                continue;
+            };
+            // A link into any configured root, inside the home or not, is current
+            if roots.iter().any(|root| target.starts_with(root)) {
+                continue;
+            }
+            if let Some(new_target) = rel_roots.iter().find_map(|rel| rebase_onto_home(&target, home, rel)) {
+                stale.push(StaleLink {
+                    agent_id: agent.id.clone(),
+                    skill_name: name,
+                    link: entry.path(),
+                    old_target: target,
+                    new_target,
+                });
+            }
+        }
+    }
+    stale
+}
+
+/// Detects symlinks whose absolute target references a different home directory.
+/// Returns `(agent_id, skill_name, old_target)` triples.
+pub fn find_stale_absolute_links_with_home(home: &PathBuf) -> Vec<(String, String, String)> {
+    stale_absolute_links(home)
+        .into_iter()
+        .map(|stale| (stale.agent_id, stale.skill_name, stale.old_target.to_string_lossy().to_string()))
+        .collect()
+}
+
+#[tauri::command]
+pub fn find_stale_absolute_links() -> Vec<(String, String, String)> {
+    let home = get_home_dir();
+    find_stale_absolute_links_with_home(&home)
+}
+
+/// Rewrites stale absolute links to the same location under the current home.
+/// Links whose rebased target does not exist are left alone and reported as failed.
+/// Results are keyed by `agent_id/skill_name`.
+pub fn fix_stale_absolute_links_with_home(home: &PathBuf) -> Result<BatchResult, String> {
+    ensure_not_safe_mode(home)?;
+    let mut success: Vec<String> = Vec::new();
+    let mut failed: Vec<FailedOperation> = Vec::new();
+
+    for StaleLink { agent_id, skill_name, link, new_target, .. } in stale_absolute_links(home) {
+        let key = format!("{}/{}", agent_id, skill_name);
+        if !new_target.exists() {
+            failed.push(FailedOperation {
+                agent_id: key,
+                error: format!("Target {} does not exist under the current home", new_target.to_string_lossy()),
+            });
+            continue;
+        }
+        match link::replace_skill_link(&new_target, &link) {
+            Ok(_) => success.push(key),
+            Err(e) => failed.push(FailedOperation { agent_id: key, error: format!("Failed to relink: {}", e) }),
+        }
+    }
+
+    Ok(BatchResult { success, failed, ..Default::default() })
+}
+
+#[tauri::command]
+pub fn fix_stale_absolute_links() -> Result<BatchResult, String> {
+    let home = get_home_dir();
+    fix_stale_absolute_links_with_home(&home)
+}
+
+/// Rewrites every agent symlink whose absolute target starts with `old_home_prefix` to
+/// the same location under the current home, across all agents and skills. Unlike
+/// [`fix_stale_absolute_links_with_home`], targets outside the global roots are moved
+/// too. Links that already point into the current home are reported as skipped, and links
+/// whose rebased target does not exist as failed. Each link is replaced in one step, so a
+/// failed rewrite leaves it as it was. Results are keyed by `agent_id/skill_name`.
+pub fn relink_all_after_home_change_with_home(old_home_prefix: &str, home: &PathBuf) -> Result<BatchResult, String> {
+    ensure_not_safe_mode(home)?;
+    let old_home = Path::new(old_home_prefix);
+    if !old_home.is_absolute() {
+        return Err(format!("Old home prefix '{}' must be an absolute path", old_home_prefix));
+    }
+    let mut success: Vec<String> = Vec::new();
+    let mut failed: Vec<FailedOperation> = Vec::new();
+    let mut skipped: Vec<FailedOperation> = Vec::new();
+
+    for agent in detect_agents_with_home(home).into_iter().filter(|a| a.detected) {
+        let Ok(entries) = fs::read_dir(home.join(&agent.path)) else {
+            continue;
+        };
+        let mut entries: Vec<_> = entries.flatten().collect();
+        entries.sort_by_key(|entry| entry.file_name());
+        for entry in entries {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.starts_with('.') {
+                continue;
+            }
+            let Ok(target) = fs::read_link(entry.path()) else {
+                continue;
+            };
+            let key = format!("{}/{}", agent.id, name);
+            if target.starts_with(home) {
+                skipped.push(FailedOperation { agent_id: key, error: "Already points into the current home".to_string() });
+                continue;
+            }
+            let Ok(rest) = target.strip_prefix(old_home) else {
+                continue;
+            };
+
+            let new_target = home.join(rest);
+            if !new_target.exists() {
+                failed.push(FailedOperation {
+                    agent_id: key,
+                    error: format!("Target {} does not exist under the current home", new_target.to_string_lossy()),
+                });
+                continue;
+            }
+            match link::replace_skill_link(&new_target, &entry.path()) {
+                Ok(_) => success.push(key),
+                Err(e) => failed.push(FailedOperation { agent_id: key, error: format!("Failed to relink: {}", e) }),
+            }
+        }
+    }
+
+    Ok(BatchResult { success, failed, skipped, ..Default::default() })
+}
+
+#[tauri::command]
+pub fn relink_all_after_home_change(old_home_prefix: String) -> Result<BatchResult, String> {
+    let home = get_home_dir();
+    relink_all_after_home_change_with_home(&old_home_prefix, &home)
+}
+
+/// Upper bound on symlink hops followed before a chain is treated as a loop
+const MAX_CHAIN_HOPS: usize = 40;
+
+/// Follows the symlink chain starting at `link` one hop at a time and returns the first
+/// target inside a global root, or the final target if the chain never enters one.
+/// Links inside the roots themselves are not followed, so skills stay addressed by the
+/// configured root path. Returns `None` if the chain loops or ends at a missing path.
+fn resolve_chain_into_roots(link: &Path, roots: &[PathBuf]) -> Option<PathBuf> {
+    let mut current = link.to_path_buf();
+    for _ in 0..MAX_CHAIN_HOPS {
+        // Relative targets are relative to the directory holding the link
+        let next = normalize_lexically(&current.parent()?.join(fs::read_link(&current).ok()?));
+        let is_symlink = fs::symlink_metadata(&next).ok()?.file_type().is_symlink();
+        if !is_symlink || roots.iter().any(|root| next.starts_with(root)) {
+            return next.exists().then_some(next);
+        }
+        current = next;
+    }
+    None
+}
+
+/// Rewrites every agent symlink whose immediate target is itself a symlink so that it
+/// points directly at the first directory of the chain inside a global root (or its end,
+/// if it never enters one). Chains that do not resolve are reported as failed, and a
+/// link that cannot be rewritten is left as it was. Results are keyed by `agent_id/skill_name`.
+pub fn flatten_symlink_chains_with_home(home: &PathBuf) -> Result<BatchResult, String> {
+    ensure_not_safe_mode(home)?;
+    let roots: Vec<PathBuf> = global_skill_roots_with_home(home).iter().map(|root| normalize_lexically(root)).collect();
+    let mut success: Vec<String> = Vec::new();
+    let mut failed: Vec<FailedOperation> = Vec::new();
+
+    for agent in detect_agents_with_home(home).into_iter().filter(|a| a.detected) {
+        let agent_dir = home.join(&agent.path);
+        let Ok(entries) = fs::read_dir(&agent_dir) else {
+            continue;
+        };
+        let mut entries: Vec<_> = entries.flatten().collect();
+        entries.sort_by_key(|entry| entry.file_name());
+        for entry in entries {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.starts_with('.') {
+                continue;
+            }
+            let Ok(target) = fs::read_link(entry.path()) else {
+                continue;
+            };
+            // Relative targets are relative to the directory holding the link
+            let direct = normalize_lexically(&agent_dir.join(&target));
+            let target_is_symlink = fs::symlink_metadata(&direct)
+                .map(|m| m.file_type().is_symlink())
+                .unwrap_or(false);
+            // A link straight into a global root is not a chain, even if the root entry is a link
+            if !target_is_symlink || roots.iter().any(|root| direct.starts_with(root)) {
+                continue;
+            }
+
+            let key = format!("{}/{}", agent.id, name);
+            let result = resolve_chain_into_roots(&entry.path(), &roots)
+                .ok_or_else(|| format!("Symlink chain from {} does not resolve", target.to_string_lossy()))
+                .and_then(|resolved| {
+                    link::replace_skill_link(&resolved, &entry.path()).map_err(|e| format!("Failed to relink: {}", e))
+                });
+            match result {
+                Ok(_) => success.push(key),
+                Err(e) => failed.push(FailedOperation { agent_id: key, error: e }),
+            }
+        }
+    }
+
+    Ok(BatchResult { success, failed, ..Default::default() })
+}
+
+#[tauri::command]
+pub fn flatten_symlink_chains() -> Result<BatchResult, String> {
+    let home = get_home_dir();
+    flatten_symlink_chains_with_home(&home)
+}
+
+/// Removes `.` and `..` components without touching the filesystem, so paths that no
+/// longer exist can still be compared.
+pub(crate) fn normalize_lexically(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+/// Removes every agent symlink whose target lives under `old_root`, whether or not the
+/// target still exists. Local directories are never touched. Refuses a path that is or
+/// contains a current global root. Results are keyed by `agent_id/skill_name`.
+pub fn prune_links_to_path_with_home(old_root: &str, home: &PathBuf) -> Result<BatchResult, String> {
+    ensure_not_safe_mode(home)?;
+    let old_root = normalize_lexically(&resolve_path(home, old_root));
+    if let Some(root) = global_skill_roots_with_home(home)
+        .iter()
+        .map(|root| normalize_lexically(root))
+        .find(|root| root.starts_with(&old_root))
+    {
+        return Err(format!("'{}' contains the current global root {}", old_root.to_string_lossy(), root.to_string_lossy()));
+    }
+    let canonical_old_root = fs::canonicalize(&old_root).ok();
+
+    let mut success: Vec<String> = Vec::new();
+    let mut failed: Vec<FailedOperation> = Vec::new();
+    for agent in detect_agents_with_home(home).into_iter().filter(|a| a.detected) {
+        let agent_dir = home.join(&agent.path);
+        let Ok(entries) = fs::read_dir(&agent_dir) else {
+            continue;
+        };
+        let mut entries: Vec<_> = entries.flatten().collect();
+        entries.sort_by_key(|entry| entry.file_name());
+        for entry in entries {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let Ok(target) = fs::read_link(entry.path()) else {
+                continue;
+            };
+            // Relative targets are relative to the directory holding the link
+            let under_old_root = normalize_lexically(&agent_dir.join(&target)).starts_with(&old_root)
+                || match (fs::canonicalize(entry.path()), &canonical_old_root) {
+                    (Ok(resolved), Some(root)) => resolved.starts_with(root),
+                    _ => false,
+                };
+            if name.starts_with('.') || !under_old_root {
+                continue;
+            }
+
+            let key = format!("{}/{}", agent.id, name);
+            match link::remove_skill_link(&entry.path()) {
+                Ok(_) => success.push(key),
+                Err(e) => failed.push(FailedOperation { agent_id: key, error: format!("Failed to remove symlink: {}", e) }),
+            }
+        }
+    }
+
+    Ok(BatchResult { success, failed, ..Default::default() })
+}
+
+#[tauri::command]
+pub fn prune_links_to_path(old_root: String) -> Result<BatchResult, String> {
+    let home = get_home_dir();
+    prune_links_to_path_with_home(&old_root, &home)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::save_config_with_home;
+    use crate::{create_symlink, AppConfig};
+    use tempfile::TempDir;
+
+    /// Test that a link into another home's library is detected and rewritten to the current home
+    #[test]
+    fn test_stale_absolute_link_is_found_and_fixed() {
+        // Arrange: The skill exists under the current home, but cursor's link uses an old home prefix
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let skill_dir = home_path.join(".agents/skills/review");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        let cursor_dir = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_dir).expect("Failed to create cursor directory");
+        let old_target = PathBuf::from("/home/olduser/.agents/skills/review");
+        create_symlink(&old_target, cursor_dir.join("review")).expect("Failed to create stale symlink");
+        // A healthy link under the current home is not stale
+        fs::create_dir_all(home_path.join(".agents/skills/healthy")).expect("Failed to create skill directory");
+        create_symlink(home_path.join(".agents/skills/healthy"), cursor_dir.join("healthy")).expect("Failed to create symlink");
+
+        // Act
+        let stale = find_stale_absolute_links_with_home(&home_path);
+
+        // Assert
+        assert_eq!(stale, vec![("cursor".to_string(), "review".to_string(), old_target.to_string_lossy().to_string())]);
+
+        // Act: Fix it
+        let result = fix_stale_absolute_links_with_home(&home_path).expect("fix should succeed");
+
+        // Assert
+        assert_eq!(result.success, vec!["cursor/review".to_string()]);
+        assert_eq!(fs::read_link(cursor_dir.join("review")).expect("Should be a symlink"), skill_dir);
+        assert!(find_stale_absolute_links_with_home(&home_path).is_empty());
+    }
+
+    /// Test that a link into a configured root outside the home is not treated as stale
+    #[test]
+    fn test_link_into_configured_outside_root_is_not_stale() {
+        // Arrange: A second root whose path also ends in `.agents/skills`, outside the home
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let shared = TempDir::new().expect("Failed to create temp directory");
+        let shared_root = shared.path().join(".agents/skills");
+        fs::create_dir_all(shared_root.join("team")).expect("Failed to create skill directory");
+        let config = AppConfig {
+            global_paths: vec!["~/.agents/skills".to_string(), shared_root.to_string_lossy().to_string()],
+            ..Default::default()
+        };
+        save_config_with_home(&config, &home_path).expect("Failed to save config");
+        let cursor_dir = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_dir).expect("Failed to create cursor directory");
+        create_symlink(shared_root.join("team"), cursor_dir.join("team")).expect("Failed to create symlink");
+
+        // Act
+        let stale = find_stale_absolute_links_with_home(&home_path);
+
+        // Assert
+        assert!(stale.is_empty(), "Unexpected stale links: {:?}", stale);
+    }
+
+    /// Test that links from an old home are moved to the current home across agents and roots
+    #[test]
+    fn test_relink_all_after_home_change() {
+        // Arrange: Links from the old home into the global library, a team root and a missing skill
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        fs::create_dir_all(home_path.join(".agents/skills/review")).expect("Failed to create skill directory");
+        fs::create_dir_all(home_path.join("team/skills/deploy")).expect("Failed to create skill directory");
+        let cursor_dir = home_path.join(".cursor/skills");
+        let claude_dir = home_path.join(".claude/skills");
+        fs::create_dir_all(&cursor_dir).expect("Failed to create cursor directory");
+        fs::create_dir_all(&claude_dir).expect("Failed to create claude directory");
+        create_symlink("/home/olduser/.agents/skills/review", cursor_dir.join("review")).expect("Failed to create symlink");
+        create_symlink("/home/olduser/team/skills/deploy", claude_dir.join("deploy")).expect("Failed to create symlink");
+        create_symlink("/home/olduser/.agents/skills/gone", claude_dir.join("gone")).expect("Failed to create symlink");
+        create_symlink(home_path.join(".agents/skills/review"), claude_dir.join("review")).expect("Failed to create symlink");
+
+        // Act
+        let result = relink_all_after_home_change_with_home("/home/olduser", &home_path).expect("relink should succeed");
+
+        // Assert
+        assert_eq!(result.success, vec!["claude-code/deploy".to_string(), "cursor/review".to_string()]);
+        assert_eq!(result.failed.len(), 1);
+        assert_eq!(result.failed[0].agent_id, "claude-code/gone");
+        assert_eq!(result.skipped.len(), 1);
+        assert_eq!(result.skipped[0].agent_id, "claude-code/review");
+        assert_eq!(fs::read_link(cursor_dir.join("review")).unwrap(), home_path.join(".agents/skills/review"));
+        assert_eq!(fs::read_link(claude_dir.join("deploy")).unwrap(), home_path.join("team/skills/deploy"));
+        assert_eq!(fs::read_link(claude_dir.join("review")).unwrap(), home_path.join(".agents/skills/review"));
+    }
+
+    /// Test that a two-hop symlink chain is rewritten to point directly at the skill
+    #[test]
+    fn test_flatten_symlink_chain() {
+        // Arrange: cursor -> claude's link -> global skill
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let skill_dir = home_path.join(".agents/skills/review");
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        let claude_dir = home_path.join(".claude/skills");
+        let cursor_dir = home_path.join(".cursor/skills");
+        fs::create_dir_all(&claude_dir).expect("Failed to create claude directory");
+        fs::create_dir_all(&cursor_dir).expect("Failed to create cursor directory");
+        create_symlink(&skill_dir, claude_dir.join("review")).expect("Failed to create symlink");
+        create_symlink(claude_dir.join("review"), cursor_dir.join("review")).expect("Failed to create chained symlink");
+
+        // Act
+        let result = flatten_symlink_chains_with_home(&home_path).expect("flatten should succeed");
+
+        // Assert: Only the chained link is rewritten, now one hop from the skill
+        assert_eq!(result.success, vec!["cursor/review".to_string()]);
+        let target = fs::read_link(cursor_dir.join("review")).expect("Should be a symlink");
+        assert_eq!(target, skill_dir);
+        assert!(!fs::symlink_metadata(&target).unwrap().file_type().is_symlink(), "Target should not be a symlink");
+        assert!(flatten_symlink_chains_with_home(&home_path).expect("flatten should succeed").success.is_empty());
+    }
+
+    /// Test that a chain stops at the global root even when the skill there is itself a link
+    #[test]
+    fn test_flatten_symlink_chain_stops_at_global_root() {
+        // Arrange: cursor -> claude's link -> global entry, which links to a checkout elsewhere
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let checkout = home_path.join("src/review");
+        fs::create_dir_all(&checkout).expect("Failed to create checkout");
+        let global_dir = home_path.join(".agents/skills");
+        fs::create_dir_all(&global_dir).expect("Failed to create global directory");
+        create_symlink(&checkout, global_dir.join("review")).expect("Failed to create symlink");
+        let claude_dir = home_path.join(".claude/skills");
+        let cursor_dir = home_path.join(".cursor/skills");
+        fs::create_dir_all(&claude_dir).expect("Failed to create claude directory");
+        fs::create_dir_all(&cursor_dir).expect("Failed to create cursor directory");
+        create_symlink(global_dir.join("review"), claude_dir.join("review")).expect("Failed to create symlink");
+        create_symlink(claude_dir.join("review"), cursor_dir.join("review")).expect("Failed to create chained symlink");
+
+        // Act
+        let result = flatten_symlink_chains_with_home(&home_path).expect("flatten should succeed");
+
+        // Assert: Both agents now link the global entry, not the checkout behind it
+        assert_eq!(result.success, vec!["cursor/review".to_string()]);
+        assert_eq!(fs::read_link(cursor_dir.join("review")).unwrap(), global_dir.join("review"));
+        assert_eq!(fs::read_link(claude_dir.join("review")).unwrap(), global_dir.join("review"));
+    }
+
+    /// Test that links into an old root are pruned while links into the current root and local skills survive
+    #[test]
+    fn test_prune_links_to_old_root() {
+        // Arrange: cursor links one skill from the moved-away root and one from the current root
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        fs::create_dir_all(home_path.join(".agents/skills/current")).expect("Failed to create skill directory");
+        let cursor_dir = home_path.join(".cursor/skills");
+        fs::create_dir_all(cursor_dir.join("local")).expect("Failed to create local skill");
+        create_symlink(home_path.join("old-library/moved"), cursor_dir.join("moved")).expect("Failed to create symlink");
+        create_symlink(home_path.join(".agents/skills/current"), cursor_dir.join("current")).expect("Failed to create symlink");
+
+        // Act
+        let result = prune_links_to_path_with_home("~/old-library", &home_path).expect("prune should succeed");
+
+        // Assert
+        assert_eq!(result.success, vec!["cursor/moved".to_string()]);
+        assert!(fs::symlink_metadata(cursor_dir.join("moved")).is_err());
+        assert!(fs::symlink_metadata(cursor_dir.join("current")).is_ok());
+        assert!(cursor_dir.join("local").is_dir());
+
+        // Assert: Neither the current root nor a directory holding it can be pruned
+        assert!(prune_links_to_path_with_home("~/.agents/skills", &home_path).is_err());
+        assert!(prune_links_to_path_with_home("~/.agents", &home_path).is_err());
+        assert!(fs::symlink_metadata(cursor_dir.join("current")).is_ok());
+    }
+}
@@ -0,0 +1,249 @@
+//! Skill Files Module
+//!
+//! This module lists the file tree of a skill for the detail view and reads individual
+//! text files for preview, either from the global library or from an agent's installed
+//! copy (following its symlink). Listings are capped in depth and size so a skill that
+//! vendors a large directory cannot produce a huge payload.
+
+use crate::{
+    detect_agents_with_home, find_global_skill, get_home_dir, global_skill_roots_with_home, validate_skill_name,
+};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+/// Deepest directory level listed; the skill directory itself is depth 0
+pub const MAX_LIST_DEPTH: usize = 8;
+
+/// Maximum number of entries returned for one skill
+pub const MAX_LIST_ENTRIES: usize = 1000;
+
+/// A file or directory inside a skill
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct SkillFileEntry {
+    pub path: String,      // Relative to the skill directory, `/`-separated
+    pub is_dir: bool,
+    pub size: Option<u64>, // File size in bytes, None for directories
+}
+
+/// Walks `dir` depth-first in name order, skipping hidden entries, until a cap is hit.
+fn walk(dir: &Path, prefix: &str, depth: usize, entries: &mut Vec<SkillFileEntry>) {
+    let Ok(read) = fs::read_dir(dir) else {
+        return;
+    };
+    let mut children: Vec<_> = read.flatten().collect();
+    children.sort_by_key(|entry| entry.file_name());
+
+    for child in children {
+        if entries.len() >= MAX_LIST_ENTRIES {
+            return;
+        }
+        let name = child.file_name().to_string_lossy().to_string();
+        if name.starts_with('.') {
+            continue;
+        }
+        let path = format!("{}{}", prefix, name);
+        // Follows symlinks, so linked entries show what they point at
+        match fs::metadata(child.path()) {
+            Ok(metadata) if metadata.is_dir() => {
+                entries.push(SkillFileEntry { path: path.clone(), is_dir: true, size: None });
+                if depth < MAX_LIST_DEPTH {
+                    walk(&child.path(), &format!("{}/", path), depth + 1, entries);
+                }
+            }
+            Ok(metadata) => entries.push(SkillFileEntry { path, is_dir: false, size: Some(metadata.len()) }),
+            Err(_) => {}
+        }
+    }
+}
+
+/// Resolves a skill's directory, from an agent's copy when `agent_id` is given and
+/// from the global library otherwise. The skill name is validated, but the resolved
+/// directory may live anywhere, so global skills symlinked to a checkout still work.
+fn resolve_skill_dir(skill_name: &str, agent_id: Option<String>, home: &PathBuf) -> Result<PathBuf, String> {
+    validate_skill_name(skill_name)?;
+    let skill_dir = match agent_id {
+        Some(agent_id) => {
+            let agent = detect_agents_with_home(home)
+                .into_iter()
+                .find(|a| a.id == agent_id)
+                .ok_or_else(|| format!("Agent '{}' not found", agent_id))?;
+            fs::canonicalize(home.join(&agent.path).join(skill_name))
+                .map_err(|_| format!("Skill '{}' is not installed for agent '{}'", skill_name, agent_id))?
+        }
+        None => {
+            let roots = global_skill_roots_with_home(home);
+            let path = find_global_skill(&roots, skill_name)
+                .ok_or_else(|| format!("Skill '{}' not found in global skills", skill_name))?;
+            fs::canonicalize(&path).map_err(|e| format!("Failed to resolve skill path: {}", e))?
+        }
+    };
+    if !skill_dir.is_dir() {
+        return Err(format!("'{}' is not a skill directory", skill_name));
+    }
+    Ok(skill_dir)
+}
+
+/// Lists the files and directories of a skill, from an agent's copy when `agent_id`
+/// is given and from the global library otherwise.
+pub fn list_skill_files_with_home(
+    skill_name: &str,
+    agent_id: Option<String>,
+    home: &PathBuf,
+) -> Result<Vec<SkillFileEntry>, String> {
+    let skill_dir = resolve_skill_dir(skill_name, agent_id, home)?;
+    let mut entries = Vec::new();
+    walk(&skill_dir, "", 1, &mut entries);
+    Ok(entries)
+}
+
+#[tauri::command]
+pub fn list_skill_files(skill_name: String, agent_id: Option<String>) -> Result<Vec<SkillFileEntry>, String> {
+    let home = get_home_dir();
+    list_skill_files_with_home(&skill_name, agent_id, &home)
+}
+
+/// Reads a text file inside a skill for preview.
+///
+/// `relative_path` must stay inside the skill: `..`, absolute paths and symlinks leading
+/// out of the skill are rejected. Binary and non-UTF-8 files are an error.
+pub fn read_skill_file_with_home(
+    skill_name: &str,
+    relative_path: &str,
+    agent_id: Option<String>,
+    home: &PathBuf,
+) -> Result<String, String> {
+    let relative = Path::new(relative_path);
+    if relative_path.is_empty() || !relative.components().all(|c| matches!(c, Component::Normal(_) | Component::CurDir)) {
+        return Err(format!("Invalid path '{}': must be relative and inside the skill", relative_path));
+    }
+
+    let skill_dir = resolve_skill_dir(skill_name, agent_id, home)?;
+    let file = fs::canonicalize(skill_dir.join(relative))
+        .map_err(|_| format!("File '{}' not found in skill '{}'", relative_path, skill_name))?;
+    if !file.starts_with(&skill_dir) {
+        return Err(format!("Invalid path '{}': must be relative and inside the skill", relative_path));
+    }
+    if !file.is_file() {
+        return Err(format!("'{}' is not a file", relative_path));
+    }
+
+    let bytes = fs::read(&file).map_err(|e| format!("Failed to read {}: {}", relative_path, e))?;
+    if bytes.contains(&0) {
+        return Err(format!("'{}' is a binary file", relative_path));
+    }
+    String::from_utf8(bytes).map_err(|_| format!("'{}' is not valid UTF-8 text", relative_path))
+}
+
+#[tauri::command]
+pub fn read_skill_file(skill_name: String, relative_path: String, agent_id: Option<String>) -> Result<String, String> {
+    let home = get_home_dir();
+    read_skill_file_with_home(&skill_name, &relative_path, agent_id, &home)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::create_symlink;
+    use tempfile::TempDir;
+
+    /// Test that a multi-file skill lists files with sizes and directories without, via an agent's symlink
+    #[test]
+    fn test_list_skill_files_multi_file_skill() {
+        // Arrange: A global skill with a nested script and a hidden file, linked into cursor
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let skill_dir = home_path.join(".agents/skills/my-skill");
+        fs::create_dir_all(skill_dir.join("scripts")).expect("Failed to create skill directory");
+        fs::write(skill_dir.join("SKILL.md"), "---\nname: My Skill\n---\n").expect("Failed to write SKILL.md");
+        fs::write(skill_dir.join("scripts/run.sh"), "echo hi\n").expect("Failed to write script");
+        fs::write(skill_dir.join(".DS_Store"), "junk").expect("Failed to write hidden file");
+        let cursor_dir = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_dir).expect("Failed to create cursor directory");
+        create_symlink(&skill_dir, cursor_dir.join("my-skill")).expect("Failed to create symlink");
+
+        // Act
+        let global = list_skill_files_with_home("my-skill", None, &home_path).expect("listing should succeed");
+        let via_agent = list_skill_files_with_home("my-skill", Some("cursor".to_string()), &home_path)
+            .expect("listing should succeed");
+
+        // Assert
+        assert_eq!(global, vec![
+            SkillFileEntry { path: "SKILL.md".to_string(), is_dir: false, size: Some(23) },
+            SkillFileEntry { path: "scripts".to_string(), is_dir: true, size: None },
+            SkillFileEntry { path: "scripts/run.sh".to_string(), is_dir: false, size: Some(8) },
+        ]);
+        assert_eq!(via_agent, global);
+    }
+
+    /// Test that a helper script can be read and path traversal is rejected
+    #[test]
+    fn test_read_skill_file_and_reject_traversal() {
+        // Arrange: A skill with a helper script, and a secret file next to the library
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let skill_dir = home_path.join(".agents/skills/my-skill");
+        fs::create_dir_all(skill_dir.join("scripts")).expect("Failed to create skill directory");
+        fs::write(skill_dir.join("scripts/run.sh"), "#!/bin/sh\necho hi\n").expect("Failed to write script");
+        fs::write(skill_dir.join("logo.png"), [0x89, b'P', b'N', b'G', 0x00]).expect("Failed to write binary");
+        fs::write(home_path.join(".agents/secret.txt"), "secret").expect("Failed to write secret");
+
+        // Act
+        let script = read_skill_file_with_home("my-skill", "scripts/run.sh", None, &home_path);
+        let traversal = read_skill_file_with_home("my-skill", "../../secret.txt", None, &home_path);
+        let absolute = read_skill_file_with_home("my-skill", "/etc/passwd", None, &home_path);
+        let binary = read_skill_file_with_home("my-skill", "logo.png", None, &home_path);
+
+        // Assert
+        assert_eq!(script, Ok("#!/bin/sh\necho hi\n".to_string()));
+        assert!(traversal.expect_err("traversal should be rejected").contains("Invalid path"));
+        assert!(absolute.expect_err("absolute path should be rejected").contains("Invalid path"));
+        assert!(binary.expect_err("binary file should be rejected").contains("binary"));
+    }
+
+    /// Test that a skill name cannot climb out of the library to read or list other files
+    #[test]
+    fn test_skill_name_traversal_is_rejected() {
+        // Arrange: A private file directly in home and an agent directory
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        fs::create_dir_all(home_path.join(".ssh")).expect("Failed to create .ssh");
+        fs::write(home_path.join(".ssh/id_rsa"), "private key").expect("Failed to write key");
+        fs::create_dir_all(home_path.join(".agents/skills")).expect("Failed to create library");
+        fs::create_dir_all(home_path.join(".cursor/skills")).expect("Failed to create cursor directory");
+
+        // Act
+        let global = read_skill_file_with_home("../..", ".ssh/id_rsa", None, &home_path);
+        let via_agent = read_skill_file_with_home("../..", ".ssh/id_rsa", Some("cursor".to_string()), &home_path);
+        let listing = list_skill_files_with_home("..", None, &home_path);
+
+        // Assert
+        assert!(global.expect_err("traversal should be rejected").contains("Invalid skill name"));
+        assert!(via_agent.expect_err("traversal should be rejected").contains("Invalid skill name"));
+        assert!(listing.expect_err("traversal should be rejected").contains("Invalid skill name"));
+    }
+
+    /// Test that a global skill symlinked to a checkout can be listed and read, but not escaped
+    #[test]
+    fn test_symlinked_global_skill_is_readable() {
+        // Arrange: A checkout outside the library, linked in as a global skill
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let checkout = home_path.join("src/my-skill");
+        fs::create_dir_all(&checkout).expect("Failed to create checkout");
+        fs::write(checkout.join("SKILL.md"), "---\nname: My Skill\n---\n").expect("Failed to write SKILL.md");
+        fs::write(home_path.join("src/secret.txt"), "secret").expect("Failed to write secret");
+        let global_dir = home_path.join(".agents/skills");
+        fs::create_dir_all(&global_dir).expect("Failed to create library");
+        create_symlink(&checkout, global_dir.join("my-skill")).expect("Failed to create symlink");
+
+        // Act
+        let listing = list_skill_files_with_home("my-skill", None, &home_path);
+        let content = read_skill_file_with_home("my-skill", "SKILL.md", None, &home_path);
+        let sibling = read_skill_file_with_home("my-skill", "../secret.txt", None, &home_path);
+
+        // Assert
+        assert_eq!(listing.expect("listing should succeed").len(), 1);
+        assert_eq!(content, Ok("---\nname: My Skill\n---\n".to_string()));
+        assert!(sibling.expect_err("traversal should be rejected").contains("Invalid path"));
+    }
+}
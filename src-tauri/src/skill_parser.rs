@@ -19,6 +19,24 @@ pub struct SkillMetadata {
     pub name: String,
     pub description: String,
     pub allowed_tools: Vec<String>,
+    /// Guidance on when an agent should use the skill (`when-to-use` or a "When to use" section)
+    #[serde(default)]
+    pub when_to_use: Option<String>,
+    /// Author of the skill (`author` frontmatter field)
+    #[serde(default)]
+    pub author: Option<String>,
+    /// Source repository URL of the skill (`repository` frontmatter field)
+    #[serde(default)]
+    pub repository: Option<String>,
+    /// License of the skill (`license` frontmatter field)
+    #[serde(default)]
+    pub license: Option<String>,
+    /// Version of the skill (`version` frontmatter field), kept verbatim
+    #[serde(default)]
+    pub version: Option<String>,
+    /// Free-form tags for grouping and search (`tags` frontmatter list)
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 impl Default for SkillMetadata {
@@ -27,10 +45,392 @@ impl Default for SkillMetadata {
             name: String::new(),
             description: String::new(),
             allowed_tools: Vec::new(),
+            when_to_use: None,
+            author: None,
+            repository: None,
+            license: None,
+            version: None,
+            tags: Vec::new(),
         }
     }
 }
 
+impl SkillMetadata {
+    /// Frontmatter fields that can be set by name with [`SkillMetadata::set_field`]
+    pub const SETTABLE_FIELDS: &'static [&'static str] = &["name", "description", "when-to-use", "author", "repository", "license", "version"];
+
+    /// Sets a single-valued frontmatter field by its SKILL.md key.
+    /// An empty value clears optional fields. Unknown field names are an error.
+    pub fn set_field(&mut self, field: &str, value: &str) -> Result<(), String> {
+        let optional = || Some(value.to_string()).filter(|v| !v.is_empty());
+        match field {
+            "name" => self.name = value.to_string(),
+            "description" => self.description = value.to_string(),
+            "when-to-use" => self.when_to_use = optional(),
+            "author" => self.author = optional(),
+            "repository" => self.repository = optional(),
+            "license" => self.license = optional(),
+            "version" => self.version = optional(),
+            _ => {
+                return Err(format!(
+                    "Unknown field '{}' (expected one of: {})",
+                    field,
+                    Self::SETTABLE_FIELDS.join(", ")
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies the fields present in `patch`, leaving the others unchanged.
+    pub fn apply_patch(&mut self, patch: &PartialSkillMetadata) {
+        let optional = |value: &String| Some(value.clone()).filter(|v| !v.is_empty());
+        if let Some(name) = &patch.name {
+            self.name = name.clone();
+        }
+        if let Some(description) = &patch.description {
+            self.description = description.clone();
+        }
+        if let Some(allowed_tools) = &patch.allowed_tools {
+            self.allowed_tools = allowed_tools.clone();
+        }
+        if let Some(when_to_use) = &patch.when_to_use {
+            self.when_to_use = optional(when_to_use);
+        }
+        if let Some(author) = &patch.author {
+            self.author = optional(author);
+        }
+        if let Some(repository) = &patch.repository {
+            self.repository = optional(repository);
+        }
+        if let Some(license) = &patch.license {
+            self.license = optional(license);
+        }
+        if let Some(version) = &patch.version {
+            self.version = optional(version);
+        }
+        if let Some(tags) = &patch.tags {
+            self.tags = tags.clone();
+        }
+    }
+
+    /// Fills fields that are empty or unset from `defaults`. The name is never inherited.
+    pub fn fill_missing(&mut self, defaults: &SkillMetadata) {
+        if self.description.is_empty() {
+            self.description = defaults.description.clone();
+        }
+        if self.allowed_tools.is_empty() {
+            self.allowed_tools = defaults.allowed_tools.clone();
+        }
+        if self.tags.is_empty() {
+            self.tags = defaults.tags.clone();
+        }
+        for (field, default) in [
+            (&mut self.when_to_use, &defaults.when_to_use),
+            (&mut self.author, &defaults.author),
+            (&mut self.repository, &defaults.repository),
+            (&mut self.license, &defaults.license),
+            (&mut self.version, &defaults.version),
+        ] {
+            if field.is_none() {
+                *field = default.clone();
+            }
+        }
+    }
+}
+
+/// A partial update to [`SkillMetadata`]; only fields that are `Some` are applied.
+/// For optional fields, an empty string clears the field.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct PartialSkillMetadata {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub allowed_tools: Option<Vec<String>>,
+    pub when_to_use: Option<String>,
+    pub author: Option<String>,
+    pub repository: Option<String>,
+    pub license: Option<String>,
+    pub version: Option<String>,
+    pub tags: Option<Vec<String>>,
+}
+
+impl PartialSkillMetadata {
+    /// The patch that turns `before` into `after`: only fields that differ are set, and an
+    /// optional field removed in `after` is set to an empty string so it gets cleared.
+    pub fn changes(before: &SkillMetadata, after: &SkillMetadata) -> Self {
+        fn changed<T: Clone + PartialEq>(before: &T, after: &T) -> Option<T> {
+            (before != after).then(|| after.clone())
+        }
+        let optional = |before: &Option<String>, after: &Option<String>| {
+            changed(before, after).map(|value| value.unwrap_or_default())
+        };
+        Self {
+            name: changed(&before.name, &after.name),
+            description: changed(&before.description, &after.description),
+            allowed_tools: changed(&before.allowed_tools, &after.allowed_tools),
+            when_to_use: optional(&before.when_to_use, &after.when_to_use),
+            author: optional(&before.author, &after.author),
+            repository: optional(&before.repository, &after.repository),
+            license: optional(&before.license, &after.license),
+            version: optional(&before.version, &after.version),
+            tags: changed(&before.tags, &after.tags),
+        }
+    }
+}
+
+/// Which SKILL.md format the metadata was parsed from
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ParseSource {
+    /// YAML frontmatter between `---` markers
+    Frontmatter,
+    /// Heading-based fallback format
+    Heading,
+}
+
+/// A non-fatal problem found while reading or validating a skill
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ValidationWarning {
+    /// SKILL.md is not valid UTF-8 and was decoded as the given encoding
+    NonUtf8 { encoding: String },
+    /// The description is longer than `max` characters; `suggestion` is a shorter version
+    DescriptionTooLong { length: usize, max: usize, suggestion: String },
+    /// The skill could not be validated at all; `message` says why
+    ReadError { message: String },
+    /// The skill directory has no SKILL.md
+    MissingSkillMd,
+    /// A listed allowed tool is disclaimed in the body ("do not use Bash") on the given 1-based line
+    ContradictedTool { tool: String, line: usize },
+    /// The skill has no name
+    MissingName,
+    /// The skill has no description
+    MissingDescription,
+    /// The allowed-tools entry at the given 0-based index is empty
+    EmptyTool { index: usize },
+    /// A tool is listed more than once in allowed-tools
+    DuplicateTool { tool: String },
+    /// The file has frontmatter that is not valid YAML, so it was read as the heading format
+    InvalidFrontmatter,
+}
+
+impl std::fmt::Display for ValidationWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NonUtf8 { encoding } => write!(f, "SKILL.md is not UTF-8 (decoded as {})", encoding),
+            Self::DescriptionTooLong { length, max, .. } => {
+                write!(f, "Description is {} characters long (max {})", length, max)
+            }
+            Self::ReadError { message } => write!(f, "{}", message),
+            Self::MissingSkillMd => write!(f, "SKILL.md is missing"),
+            Self::ContradictedTool { tool, line } => {
+                write!(f, "Allowed tool {} is disclaimed on line {}", tool, line)
+            }
+            Self::MissingName => write!(f, "Name is missing"),
+            Self::MissingDescription => write!(f, "Description is missing"),
+            Self::EmptyTool { index } => write!(f, "Allowed tool #{} is empty", index + 1),
+            Self::DuplicateTool { tool } => write!(f, "Allowed tool {} is listed more than once", tool),
+            Self::InvalidFrontmatter => write!(f, "Frontmatter is not valid YAML; read as heading format"),
+        }
+    }
+}
+
+/// Phrases that introduce a tool the body tells the agent not to use
+const TOOL_DISCLAIMERS: [&str; 3] = ["do not use ", "don't use ", "never use "];
+
+/// Flags allowed tools that the body disclaims with a phrase like "do not use Bash". Only
+/// the word right after the phrase is checked, and it must equal the tool's name exactly
+/// (ignoring surrounding backticks, asterisks, quotes and trailing punctuation), so a
+/// passing mention of a tool is never flagged. Each tool is reported once, at its first
+/// disclaimer.
+pub fn check_tool_disclaimers(content: &str, allowed_tools: &[String]) -> Vec<ValidationWarning> {
+    let body = split_frontmatter(content).map(|(_, body)| body).unwrap_or(content);
+    let header_lines = content[..content.len() - body.len()].lines().count();
+    let tool_names: Vec<&str> = allowed_tools
+        .iter()
+        .map(|tool| tool.split('(').next().unwrap_or_default().trim())
+        .collect();
+
+    let mut warnings: Vec<ValidationWarning> = Vec::new();
+    for (index, line) in body.lines().enumerate() {
+        let lower = line.to_ascii_lowercase();
+        for phrase in TOOL_DISCLAIMERS {
+            for (start, _) in lower.match_indices(phrase) {
+                let word = line[start + phrase.len()..].split_whitespace().next().unwrap_or_default();
+                let word = word
+                    .trim_start_matches(['`', '*', '"', '\''])
+                    .trim_end_matches(['`', '*', '"', '\'', '.', ',', ';', ':', '!', '?', ')']);
+                let already = warnings
+                    .iter()
+                    .any(|w| matches!(w, ValidationWarning::ContradictedTool { tool, .. } if tool == word));
+                if !word.is_empty() && tool_names.contains(&word) && !already {
+                    warnings.push(ValidationWarning::ContradictedTool {
+                        tool: word.to_string(),
+                        line: header_lines + index + 1,
+                    });
+                }
+            }
+        }
+    }
+    warnings
+}
+
+/// Description length limit used when none is configured
+pub const DEFAULT_MAX_DESCRIPTION_LEN: usize = 1024;
+
+/// Lints SKILL.md content: a missing name or description, empty or duplicate
+/// allowed-tools entries, and frontmatter that failed to parse.
+pub fn validate_skill_md(content: &str) -> Vec<ValidationWarning> {
+    let (metadata, source) = parse_skill_md_with_source(content);
+    let mut warnings = Vec::new();
+    if source == ParseSource::Heading && split_frontmatter(content).is_some() {
+        warnings.push(ValidationWarning::InvalidFrontmatter);
+    }
+    if metadata.name.trim().is_empty() {
+        warnings.push(ValidationWarning::MissingName);
+    }
+    if metadata.description.trim().is_empty() {
+        warnings.push(ValidationWarning::MissingDescription);
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    for (index, tool) in metadata.allowed_tools.iter().enumerate() {
+        let tool = tool.trim();
+        if tool.is_empty() {
+            warnings.push(ValidationWarning::EmptyTool { index });
+        } else if !seen.insert(tool) {
+            // Reported once per tool, however often it repeats
+            if !warnings.contains(&ValidationWarning::DuplicateTool { tool: tool.to_string() }) {
+                warnings.push(ValidationWarning::DuplicateTool { tool: tool.to_string() });
+            }
+        }
+    }
+    warnings
+}
+
+/// Flags a description longer than `max_len` characters, suggesting a version shortened to
+/// whole sentences. If even the first sentence is too long, it is cut at a word boundary.
+pub fn check_description_length(description: &str, max_len: usize) -> Option<ValidationWarning> {
+    let length = description.chars().count();
+    if length <= max_len {
+        return None;
+    }
+
+    // Longest run of whole sentences that fits
+    let mut suggestion = String::new();
+    let mut sentence = String::new();
+    let mut chars = description.trim().chars().peekable();
+    while let Some(c) = chars.next() {
+        sentence.push(c);
+        let ends_sentence = matches!(c, '.' | '!' | '?') && chars.peek().map(|next| next.is_whitespace()).unwrap_or(true);
+        if ends_sentence {
+            if suggestion.chars().count() + sentence.chars().count() > max_len {
+                break;
+            }
+            suggestion.push_str(&sentence);
+            sentence.clear();
+        }
+    }
+
+    if suggestion.trim().is_empty() {
+        // No sentence fits: keep whole words and mark the cut
+        let limit = max_len.saturating_sub(3);
+        for word in description.split_whitespace() {
+            let needed = if suggestion.is_empty() { word.chars().count() } else { word.chars().count() + 1 };
+            if suggestion.chars().count() + needed > limit {
+                break;
+            }
+            if !suggestion.is_empty() {
+                suggestion.push(' ');
+            }
+            suggestion.push_str(word);
+        }
+        suggestion.push_str("...");
+    }
+
+    Some(ValidationWarning::DescriptionTooLong { length, max: max_len, suggestion: suggestion.trim().to_string() })
+}
+
+/// Decodes raw SKILL.md bytes into text.
+///
+/// A byte order mark selects UTF-8, UTF-16LE or UTF-16BE and is stripped. Without a BOM
+/// the content is read as UTF-8, falling back to Latin-1 (which accepts any byte sequence).
+/// Any decoding other than UTF-8 is reported with a [`ValidationWarning::NonUtf8`].
+pub fn decode_skill_md(bytes: &[u8]) -> (String, Option<ValidationWarning>) {
+    let non_utf8 = |encoding: &str| Some(ValidationWarning::NonUtf8 { encoding: encoding.to_string() });
+    let utf16 = |rest: &[u8], from_bytes: fn([u8; 2]) -> u16| {
+        let units: Vec<u16> = rest.chunks_exact(2).map(|pair| from_bytes([pair[0], pair[1]])).collect();
+        String::from_utf16_lossy(&units)
+    };
+
+    if let Some(rest) = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]) {
+        return match std::str::from_utf8(rest) {
+            Ok(text) => (text.to_string(), None),
+            Err(_) => (String::from_utf8_lossy(rest).to_string(), non_utf8("utf-8 (invalid)")),
+        };
+    }
+    if let Some(rest) = bytes.strip_prefix(&[0xFF, 0xFE]) {
+        return (utf16(rest, u16::from_le_bytes), non_utf8("utf-16le"));
+    }
+    if let Some(rest) = bytes.strip_prefix(&[0xFE, 0xFF]) {
+        return (utf16(rest, u16::from_be_bytes), non_utf8("utf-16be"));
+    }
+    match std::str::from_utf8(bytes) {
+        Ok(text) => (text.to_string(), None),
+        Err(_) => (bytes.iter().map(|b| char::from(*b)).collect(), non_utf8("latin-1")),
+    }
+}
+
+/// Encoding of a SKILL.md file, as chosen by [`decode_skill_md`]. Rewriting a file in
+/// its own encoding keeps editors that created it (often UTF-16 on Windows) working.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SkillMdEncoding {
+    Utf8,
+    /// UTF-8 with a byte order mark
+    Utf8Bom,
+    Utf16Le,
+    Utf16Be,
+    Latin1,
+}
+
+impl SkillMdEncoding {
+    /// Detects the encoding of raw SKILL.md bytes the same way [`decode_skill_md`] does.
+    pub fn detect(bytes: &[u8]) -> Self {
+        if bytes.starts_with(&[0xEF, 0xBB, 0xBF]) {
+            Self::Utf8Bom
+        } else if bytes.starts_with(&[0xFF, 0xFE]) {
+            Self::Utf16Le
+        } else if bytes.starts_with(&[0xFE, 0xFF]) {
+            Self::Utf16Be
+        } else if std::str::from_utf8(bytes).is_ok() {
+            Self::Utf8
+        } else {
+            Self::Latin1
+        }
+    }
+
+    /// Encodes text in this encoding, with its byte order mark if it has one.
+    /// Fails for Latin-1 if the text holds a character Latin-1 cannot represent.
+    pub fn encode(self, text: &str) -> Result<Vec<u8>, String> {
+        let utf16 = |bom: [u8; 2], to_bytes: fn(u16) -> [u8; 2]| {
+            let mut bytes = bom.to_vec();
+            bytes.extend(text.encode_utf16().flat_map(to_bytes));
+            bytes
+        };
+        Ok(match self {
+            Self::Utf8 => text.as_bytes().to_vec(),
+            Self::Utf8Bom => [&[0xEF, 0xBB, 0xBF], text.as_bytes()].concat(),
+            Self::Utf16Le => utf16([0xFF, 0xFE], u16::to_le_bytes),
+            Self::Utf16Be => utf16([0xFE, 0xFF], u16::to_be_bytes),
+            Self::Latin1 => text
+                .chars()
+                .map(|c| u8::try_from(u32::from(c)).map_err(|_| format!("'{}' cannot be written as Latin-1", c)))
+                .collect::<Result<Vec<u8>, String>>()?,
+        })
+    }
+}
+
 /// Internal struct for deserializing YAML frontmatter
 #[derive(Deserialize, Debug)]
 struct FrontmatterData {
@@ -38,6 +438,13 @@ struct FrontmatterData {
     description: Option<String>,
     #[serde(rename = "allowed-tools")]
     allowed_tools: Option<Vec<String>>,
+    #[serde(rename = "when-to-use")]
+    when_to_use: Option<String>,
+    author: Option<String>,
+    repository: Option<String>,
+    license: Option<String>,
+    version: Option<String>,
+    tags: Option<Vec<String>>,
 }
 
 /// Parses a SKILL.md file content and extracts metadata.
@@ -71,6 +478,14 @@ pub fn parse_skill_md(content: &str) -> SkillMetadata {
     parse_heading_format(content)
 }
 
+/// Parses SKILL.md content like [`parse_skill_md`], also reporting which format was used.
+pub fn parse_skill_md_with_source(content: &str) -> (SkillMetadata, ParseSource) {
+    match parse_frontmatter(content) {
+        Some(metadata) => (metadata, ParseSource::Frontmatter),
+        None => (parse_heading_format(content), ParseSource::Heading),
+    }
+}
+
 /// Attempts to parse YAML frontmatter from the content.
 ///
 /// Frontmatter is expected to be at the start of the file, enclosed by --- markers.
@@ -87,6 +502,47 @@ pub fn parse_skill_md(content: &str) -> SkillMetadata {
 /// ---
 /// ```
 fn parse_frontmatter(content: &str) -> Option<SkillMetadata> {
+    // Extract the YAML content between delimiters
+    let (yaml_content, _body) = split_frontmatter(content)?;
+    let yaml_content = yaml_content.trim();
+
+    // Parse the YAML
+    let frontmatter: FrontmatterData = serde_yaml::from_str(yaml_content).ok()?;
+
+    Some(metadata_from_frontmatter(frontmatter))
+}
+
+/// Converts parsed frontmatter fields into metadata, trimming and dropping empty optional values.
+fn metadata_from_frontmatter(frontmatter: FrontmatterData) -> SkillMetadata {
+    SkillMetadata {
+        name: frontmatter.name.unwrap_or_default(),
+        description: frontmatter.description.unwrap_or_default(),
+        allowed_tools: frontmatter.allowed_tools.unwrap_or_default(),
+        // Only surrounding blank lines are dropped; a block scalar's first line may be indented
+        when_to_use: frontmatter
+            .when_to_use
+            .map(|text| text.trim_end().trim_start_matches(['\n', '\r']).to_string())
+            .filter(|text| !text.trim().is_empty()),
+        author: frontmatter.author.filter(|author| !author.is_empty()),
+        repository: frontmatter.repository.map(|url| url.trim().to_string()).filter(|url| !url.is_empty()),
+        license: frontmatter.license.map(|license| license.trim().to_string()).filter(|license| !license.is_empty()),
+        version: frontmatter.version.map(|version| version.trim().to_string()).filter(|version| !version.is_empty()),
+        tags: frontmatter.tags.unwrap_or_default(),
+    }
+}
+
+/// Parses a shared defaults file: plain YAML with the same keys as SKILL.md frontmatter.
+/// Returns `None` if the YAML is invalid.
+pub fn parse_defaults(content: &str) -> Option<SkillMetadata> {
+    let frontmatter: FrontmatterData = serde_yaml::from_str(content).ok()?;
+    Some(metadata_from_frontmatter(frontmatter))
+}
+
+/// Splits SKILL.md content into the frontmatter YAML and the markdown body after it.
+///
+/// Returns `None` if the content does not start with a closed `---` block.
+/// The body starts on the line following the closing delimiter.
+pub fn split_frontmatter(content: &str) -> Option<(&str, &str)> {
     let trimmed = content.trim_start();
 
     // Check if content starts with frontmatter delimiter
@@ -97,18 +553,54 @@ fn parse_frontmatter(content: &str) -> Option<SkillMetadata> {
     // Find the closing delimiter
     let after_first_delimiter = &trimmed[3..];
     let closing_pos = after_first_delimiter.find("\n---")?;
+    let yaml_content = &after_first_delimiter[..closing_pos];
 
-    // Extract the YAML content between delimiters
-    let yaml_content = &after_first_delimiter[..closing_pos].trim();
+    // Skip the rest of the closing delimiter line
+    let after_closing = &after_first_delimiter[closing_pos + 4..];
+    let body = match after_closing.find('\n') {
+        Some(newline) => &after_closing[newline + 1..],
+        None => "",
+    };
 
-    // Parse the YAML
-    let frontmatter: FrontmatterData = serde_yaml::from_str(yaml_content).ok()?;
+    Some((yaml_content, body))
+}
 
-    Some(SkillMetadata {
-        name: frontmatter.name.unwrap_or_default(),
-        description: frontmatter.description.unwrap_or_default(),
-        allowed_tools: frontmatter.allowed_tools.unwrap_or_default(),
-    })
+/// Frontmatter keys [`SkillMetadata`] understands; any other key is carried over untouched
+const KNOWN_FRONTMATTER_KEYS: &[&str] =
+    &["name", "description", "allowed-tools", "when-to-use", "author", "repository", "license", "version", "tags"];
+
+/// Replaces the frontmatter of SKILL.md content with the given metadata, preserving the body.
+///
+/// Keys the metadata does not cover (e.g. `model`) are kept, in their original order,
+/// after the known fields. Heading-format content is kept intact and a new frontmatter
+/// block is prepended to it. Frontmatter that does not parse is an error, so a file is
+/// never rewritten from metadata that did not come from it.
+pub fn update_frontmatter(content: &str, metadata: &SkillMetadata) -> Result<String, String> {
+    let Some((yaml, body)) = split_frontmatter(content) else {
+        return Ok(format!("{}{}", format_skill_md(metadata), content));
+    };
+    let invalid = || "Existing frontmatter is not valid YAML; fix it before editing".to_string();
+    let yaml = yaml.trim();
+    let existing = if yaml.is_empty() {
+        serde_yaml::Mapping::new()
+    } else {
+        serde_yaml::from_str::<FrontmatterData>(yaml).map_err(|_| invalid())?;
+        serde_yaml::from_str::<serde_yaml::Mapping>(yaml).map_err(|_| invalid())?
+    };
+
+    let unknown: serde_yaml::Mapping = existing
+        .into_iter()
+        .filter(|(key, _)| !key.as_str().is_some_and(|key| KNOWN_FRONTMATTER_KEYS.contains(&key)))
+        .collect();
+    let mut frontmatter = format_skill_md(metadata);
+    if !unknown.is_empty() {
+        let extra = serde_yaml::to_string(&unknown).map_err(|e| format!("Failed to serialize frontmatter: {}", e))?;
+        // Insert before the closing delimiter
+        frontmatter.truncate(frontmatter.len() - "---\n".len());
+        frontmatter.push_str(&extra);
+        frontmatter.push_str("---\n");
+    }
+    Ok(format!("{}{}", frontmatter, body))
 }
 
 /// Parses the heading-based format when no frontmatter is present.
@@ -205,6 +697,42 @@ fn parse_heading_format(content: &str) -> SkillMetadata {
         name,
         description,
         allowed_tools,
+        when_to_use: parse_when_to_use_section(&lines),
+        author: None,
+        repository: None,
+        license: None,
+        version: None,
+        tags: Vec::new(),
+    }
+}
+
+/// Extracts the content of a "When to use" section (any heading level, case-insensitive,
+/// e.g. "## When To Use This Skill"). The section ends at the next heading of the same
+/// or a higher level. Returns `None` if there is no such section or it is empty.
+fn parse_when_to_use_section(lines: &[&str]) -> Option<String> {
+    let heading_level = |line: &str| line.chars().take_while(|c| *c == '#').count();
+
+    let start = lines.iter().position(|line| {
+        let line = line.trim();
+        heading_level(line) > 0
+            && line.trim_start_matches('#').trim().to_lowercase().starts_with("when to use")
+    })?;
+    let level = heading_level(lines[start].trim());
+
+    let section: Vec<&str> = lines[start + 1..]
+        .iter()
+        .take_while(|line| {
+            let line_level = heading_level(line.trim());
+            line_level == 0 || line_level > level
+        })
+        .copied()
+        .collect();
+
+    let text = section.join("\n").trim().to_string();
+    if text.is_empty() {
+        None
+    } else {
+        Some(text)
     }
 }
 
@@ -237,6 +765,20 @@ fn parse_heading_format(content: &str) -> SkillMetadata {
 ///
 /// - 2.5: THE Pretty_Printer SHALL format SkillMetadata objects back into valid SKILL.md content
 pub fn format_skill_md(metadata: &SkillMetadata) -> String {
+    format_skill_md_with_options(metadata, &FormatOptions::default())
+}
+
+/// Options controlling how [`format_skill_md_with_options`] lays out the frontmatter.
+/// The defaults reproduce [`format_skill_md`].
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct FormatOptions {
+    /// Emit `allowed-tools` in case-insensitive alphabetical order instead of input order
+    pub sort_tools: bool,
+}
+
+/// Formats a SkillMetadata object into SKILL.md content using the given options.
+pub fn format_skill_md_with_options(metadata: &SkillMetadata, options: &FormatOptions) -> String {
     let mut output = String::new();
 
     // Start frontmatter
@@ -245,23 +787,111 @@ pub fn format_skill_md(metadata: &SkillMetadata) -> String {
     // Format name - use quoted string if it contains special YAML characters
     output.push_str(&format_yaml_field("name", &metadata.name));
 
-    // Format description - use quoted string if it contains special YAML characters
-    output.push_str(&format_yaml_field("description", &metadata.description));
+    // Format description - long descriptions are wrapped as a folded scalar, others
+    // use a quoted string if they contain special YAML characters
+    match format_folded_field("description", &metadata.description) {
+        Some(folded) => output.push_str(&folded),
+        None => output.push_str(&format_yaml_field("description", &metadata.description)),
+    }
 
     // Format allowed-tools list (only if non-empty)
     if !metadata.allowed_tools.is_empty() {
+        let mut tools: Vec<&String> = metadata.allowed_tools.iter().collect();
+        if options.sort_tools {
+            tools.sort_by_key(|t| t.to_lowercase());
+        }
         output.push_str("allowed-tools:\n");
-        for tool in &metadata.allowed_tools {
+        for tool in tools {
             output.push_str(&format!("  - {}\n", tool));
         }
     }
 
+    // Format when-to-use (only if present), as a block scalar when it spans lines or is
+    // indented. YAML takes a block's indentation from its first line, so an indented first
+    // line needs an explicit indentation indicator.
+    if let Some(when_to_use) = &metadata.when_to_use {
+        if when_to_use.contains('\n') || when_to_use.starts_with([' ', '\t']) {
+            let indicator = if when_to_use.starts_with([' ', '\t']) { "2" } else { "" };
+            output.push_str(&format!("when-to-use: |{}-\n", indicator));
+            for line in when_to_use.lines() {
+                if line.is_empty() {
+                    output.push('\n');
+                } else {
+                    output.push_str(&format!("  {}\n", line));
+                }
+            }
+        } else {
+            output.push_str(&format_yaml_field("when-to-use", when_to_use));
+        }
+    }
+
+    // Format author (only if present)
+    if let Some(author) = &metadata.author {
+        output.push_str(&format_yaml_field("author", author));
+    }
+
+    // Format repository (only if present)
+    if let Some(repository) = &metadata.repository {
+        output.push_str(&format_yaml_field("repository", repository));
+    }
+
+    // Format license (only if present)
+    if let Some(license) = &metadata.license {
+        output.push_str(&format_yaml_field("license", license));
+    }
+
+    // Format version (only if present)
+    if let Some(version) = &metadata.version {
+        output.push_str(&format_yaml_field("version", version));
+    }
+
+    // Format tags list (only if non-empty)
+    if !metadata.tags.is_empty() {
+        output.push_str("tags:\n");
+        for tag in &metadata.tags {
+            output.push_str(&format!("  - {}\n", tag));
+        }
+    }
+
     // End frontmatter
     output.push_str("---\n");
 
     output
 }
 
+/// Line width at which long single-line values are wrapped into a folded scalar
+const FOLD_WIDTH: usize = 80;
+
+/// Formats a long single-line value as a YAML folded scalar (`>-`) wrapped at [`FOLD_WIDTH`].
+///
+/// Folding joins lines with single spaces, so it is only used when that reproduces the
+/// value exactly: no line breaks, tabs, repeated spaces, or surrounding whitespace.
+/// Returns `None` when the value should be emitted inline instead.
+fn format_folded_field(key: &str, value: &str) -> Option<String> {
+    let foldable = value.chars().count() > FOLD_WIDTH
+        && value.trim() == value
+        && !value.contains(['\n', '\r', '\t'])
+        && !value.contains("  ");
+    if !foldable {
+        return None;
+    }
+
+    let mut output = format!("{}: >-\n", key);
+    let mut line = String::new();
+    for word in value.split(' ') {
+        if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > FOLD_WIDTH - 2 {
+            output.push_str(&format!("  {}\n", line));
+            line.clear();
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    output.push_str(&format!("  {}\n", line));
+    Some(output)
+}
+
 /// Formats a YAML field with proper escaping for special characters.
 ///
 /// If the value contains characters that need escaping in YAML (like colons, quotes, etc.),
@@ -641,6 +1271,7 @@ Heading description.
             name: "My Skill Name".to_string(),
             description: "A brief description of what this skill does".to_string(),
             allowed_tools: vec!["tool1".to_string(), "tool2".to_string()],
+            ..Default::default()
         };
 
         let output = format_skill_md(&metadata);
@@ -663,6 +1294,7 @@ Heading description.
             name: "Simple Skill".to_string(),
             description: "A simple skill".to_string(),
             allowed_tools: vec![],
+            ..Default::default()
         };
 
         let output = format_skill_md(&metadata);
@@ -684,6 +1316,7 @@ Heading description.
             name: String::new(),
             description: String::new(),
             allowed_tools: vec![],
+            ..Default::default()
         };
 
         let output = format_skill_md(&metadata);
@@ -703,6 +1336,7 @@ Heading description.
             name: "Special: Skill".to_string(),
             description: "Description with \"quotes\" and 'apostrophes'".to_string(),
             allowed_tools: vec!["tool-with-dash".to_string()],
+            ..Default::default()
         };
 
         let output = format_skill_md(&metadata);
@@ -714,6 +1348,366 @@ Heading description.
         assert_eq!(parsed.allowed_tools, metadata.allowed_tools);
     }
 
+    /// Test that sort_tools orders tools alphabetically, ignoring case, while the default keeps input order
+    #[test]
+    fn test_format_skill_md_sort_tools_option() {
+        let metadata = SkillMetadata {
+            name: "Skill".to_string(),
+            description: "Desc".to_string(),
+            allowed_tools: vec!["write".to_string(), "Bash".to_string(), "agent".to_string()],
+            ..Default::default()
+        };
+
+        let default_output = format_skill_md(&metadata);
+        let sorted_output = format_skill_md_with_options(&metadata, &FormatOptions { sort_tools: true });
+
+        assert!(default_output.contains("allowed-tools:\n  - write\n  - Bash\n  - agent\n"));
+        assert!(sorted_output.contains("allowed-tools:\n  - agent\n  - Bash\n  - write\n"));
+        assert_eq!(default_output, format_skill_md_with_options(&metadata, &FormatOptions::default()));
+    }
+
+    /// Test that a long description is emitted as a wrapped folded scalar and parses back unchanged
+    #[test]
+    fn test_format_skill_md_folds_long_description() {
+        let metadata = SkillMetadata {
+            name: "Long Skill".to_string(),
+            description: "Reviews every pull request for style: naming, formatting, and test coverage. \
+                It also checks that docs are updated whenever a public API changes, and flags #todo markers."
+                .to_string(),
+            ..Default::default()
+        };
+
+        let output = format_skill_md(&metadata);
+        let parsed = parse_skill_md(&output);
+
+        assert!(output.contains("description: >-\n  Reviews"), "Expected folded output:\n{}", output);
+        assert!(output.lines().all(|line| line.chars().count() <= 80), "Lines should wrap:\n{}", output);
+        assert_eq!(parsed, metadata);
+    }
+
+    /// Test that a description folding would alter keeps the inline form
+    #[test]
+    fn test_format_skill_md_does_not_fold_spacing_sensitive_description() {
+        let metadata = SkillMetadata {
+            name: "Spaced".to_string(),
+            description: format!("{}  double spaced", "word ".repeat(20)),
+            ..Default::default()
+        };
+
+        let output = format_skill_md(&metadata);
+
+        assert!(!output.contains(">-"));
+        assert_eq!(parse_skill_md(&output).description, metadata.description);
+    }
+
+    // ==================== update_frontmatter tests ====================
+
+    /// Test that updating frontmatter keeps the markdown body intact
+    #[test]
+    fn test_update_frontmatter_preserves_body() {
+        let content = "---\nname: Old Name\ndescription: Old description\n---\n\n# Docs\n\nBody text.\n";
+        let metadata = SkillMetadata {
+            name: "New Name".to_string(),
+            description: "Old description".to_string(),
+            allowed_tools: vec!["tool1".to_string()],
+            ..Default::default()
+        };
+
+        let updated = update_frontmatter(content, &metadata).expect("update should succeed");
+
+        assert!(updated.starts_with(&format_skill_md(&metadata)));
+        assert!(updated.ends_with("---\n\n# Docs\n\nBody text.\n"));
+        assert_eq!(parse_skill_md(&updated), metadata);
+    }
+
+    /// Test that heading-format content gets a frontmatter block prepended
+    #[test]
+    fn test_update_frontmatter_prepends_for_heading_format() {
+        let content = "# Heading Skill\n\nDescription.\n";
+        let metadata = parse_skill_md(content);
+
+        let updated = update_frontmatter(content, &metadata).expect("update should succeed");
+
+        assert_eq!(updated, format!("{}{}", format_skill_md(&metadata), content));
+    }
+
+    /// Test that keys the metadata does not know are kept, in order, after the known fields
+    #[test]
+    fn test_update_frontmatter_keeps_unknown_keys() {
+        let content = "---\nname: Old\nmodel: opus\ndescription: Desc\nx-team:\n  owner: infra\n---\nBody\n";
+        let mut metadata = parse_skill_md(content);
+        metadata.name = "New".to_string();
+
+        let updated = update_frontmatter(content, &metadata).expect("update should succeed");
+
+        assert_eq!(updated, "---\nname: New\ndescription: Desc\nmodel: opus\nx-team:\n  owner: infra\n---\nBody\n");
+        assert_eq!(update_frontmatter(&updated, &metadata), Ok(updated.clone()));
+    }
+
+    /// Test that frontmatter which does not parse is refused instead of gaining a second block
+    #[test]
+    fn test_update_frontmatter_refuses_invalid_yaml() {
+        let content = "---\nname: [unclosed\n---\n# Fallback\n";
+
+        let result = update_frontmatter(content, &parse_skill_md(content));
+
+        assert!(result.expect_err("invalid YAML should be refused").contains("not valid YAML"));
+    }
+
+    // ==================== when_to_use tests ====================
+
+    /// Test that the when-to-use frontmatter field is parsed
+    #[test]
+    fn test_parse_frontmatter_when_to_use() {
+        let content = "---\nname: Skill\ndescription: Desc\nwhen-to-use: Use when reviewing PRs\n---\n";
+
+        let result = parse_skill_md(content);
+
+        assert_eq!(result.when_to_use, Some("Use when reviewing PRs".to_string()));
+    }
+
+    /// Test that a "When to use" heading section is extracted, case-insensitively
+    #[test]
+    fn test_parse_heading_when_to_use_section() {
+        let content = r#"# My Skill
+
+Does things.
+
+## When To Use This Skill
+
+- Writing release notes
+- Summarizing changes
+
+### Example
+
+Run it after tagging.
+
+## Allowed Tools
+
+- tool1
+"#;
+
+        let result = parse_skill_md(content);
+
+        assert_eq!(
+            result.when_to_use,
+            Some("- Writing release notes\n- Summarizing changes\n\n### Example\n\nRun it after tagging.".to_string())
+        );
+        assert_eq!(result.allowed_tools, vec!["tool1"]);
+    }
+
+    /// Test that skills without guidance have no when_to_use
+    #[test]
+    fn test_parse_when_to_use_absent() {
+        assert_eq!(parse_skill_md("# My Skill\n\nDoes things.\n").when_to_use, None);
+        assert_eq!(parse_skill_md("---\nname: Skill\n---\n").when_to_use, None);
+    }
+
+    /// Test that multi-line when_to_use survives a format/parse round trip
+    #[test]
+    fn test_format_when_to_use_round_trip() {
+        let metadata = SkillMetadata {
+            name: "Skill".to_string(),
+            description: "Desc".to_string(),
+            when_to_use: Some("First line: with colon\n\n- a bullet".to_string()),
+            ..Default::default()
+        };
+
+        let parsed = parse_skill_md(&format_skill_md(&metadata));
+
+        assert_eq!(parsed, metadata);
+    }
+
+    /// Test that a block whose first line is indented further keeps that indentation both ways
+    #[test]
+    fn test_when_to_use_block_with_indented_first_line() {
+        let content = "---\nname: Skill\nwhen-to-use: |2-\n    run_tool --flag\n  Then review the output\n---\n";
+        let metadata = SkillMetadata {
+            name: "Skill".to_string(),
+            when_to_use: Some("  run_tool --flag\nThen review the output".to_string()),
+            ..Default::default()
+        };
+
+        let parsed = parse_skill_md(content);
+        let round_trip = parse_skill_md(&format_skill_md(&metadata));
+
+        assert_eq!(parsed.when_to_use, metadata.when_to_use);
+        assert_eq!(round_trip.when_to_use, metadata.when_to_use);
+    }
+
+    // ==================== tags and parse source tests ====================
+
+    /// Test that tags are parsed from frontmatter and survive a round trip
+    #[test]
+    fn test_parse_and_format_tags() {
+        let content = "---\nname: Tagged\ndescription: Desc\ntags:\n  - git\n  - review\n---\n";
+
+        let metadata = parse_skill_md(content);
+
+        assert_eq!(metadata.tags, vec!["git", "review"]);
+        assert_eq!(parse_skill_md(&format_skill_md(&metadata)), metadata);
+    }
+
+    /// Test that the repository URL is extracted from frontmatter and survives a round trip
+    #[test]
+    fn test_parse_and_format_repository() {
+        let content = "---\nname: Sourced\nrepository: https://github.com/example/skills\n---\n";
+
+        let metadata = parse_skill_md(content);
+
+        assert_eq!(metadata.repository, Some("https://github.com/example/skills".to_string()));
+        assert_eq!(parse_skill_md(&format_skill_md(&metadata)), metadata);
+        assert_eq!(parse_skill_md("---\nname: Plain\n---\n").repository, None);
+    }
+
+    /// Test that an unquoted numeric version is kept verbatim and survives a round trip
+    #[test]
+    fn test_parse_and_format_version() {
+        let content = "---\nname: Versioned\nversion: 1.10\nauthor: Jo\ntags:\n  - git\n---\n";
+
+        let metadata = parse_skill_md(content);
+
+        assert_eq!(metadata.version, Some("1.10".to_string()));
+        assert_eq!(parse_skill_md(&format_skill_md(&metadata)), metadata);
+        assert_eq!(parse_skill_md("---\nname: Plain\n---\n").version, None);
+    }
+
+    /// Test that the parse source reflects the format that produced the metadata
+    #[test]
+    fn test_parse_skill_md_with_source() {
+        let (_, frontmatter_source) = parse_skill_md_with_source("---\nname: A\n---\n");
+        let (heading, heading_source) = parse_skill_md_with_source("# Heading Skill\n\nDescription.\n");
+        let (_, invalid_source) = parse_skill_md_with_source("---\nname: [unclosed\n---\n# Fallback\n");
+
+        assert_eq!(frontmatter_source, ParseSource::Frontmatter);
+        assert_eq!(heading_source, ParseSource::Heading);
+        assert_eq!(heading.name, "Heading Skill");
+        assert_eq!(invalid_source, ParseSource::Heading, "Invalid YAML falls back to the heading format");
+    }
+
+    // ==================== decode_skill_md tests ====================
+
+    /// Test that UTF-16 with a BOM decodes and its name parses
+    #[test]
+    fn test_decode_utf16_with_bom() {
+        let text = "---\nname: Wide Skill\ndescription: Saved on Windows\n---\n";
+        let mut le = vec![0xFF, 0xFE];
+        le.extend(text.encode_utf16().flat_map(|unit| unit.to_le_bytes()));
+        let mut be = vec![0xFE, 0xFF];
+        be.extend(text.encode_utf16().flat_map(|unit| unit.to_be_bytes()));
+
+        let (decoded_le, warning_le) = decode_skill_md(&le);
+        let (decoded_be, warning_be) = decode_skill_md(&be);
+
+        assert_eq!(parse_skill_md(&decoded_le).name, "Wide Skill");
+        assert_eq!(decoded_be, decoded_le);
+        assert_eq!(warning_le, Some(ValidationWarning::NonUtf8 { encoding: "utf-16le".to_string() }));
+        assert_eq!(warning_be, Some(ValidationWarning::NonUtf8 { encoding: "utf-16be".to_string() }));
+    }
+
+    /// Test that UTF-8 (with or without BOM) decodes without a warning and Latin-1 falls back
+    #[test]
+    fn test_decode_utf8_and_latin1() {
+        let (plain, plain_warning) = decode_skill_md(b"# Caf\xc3\xa9\n");
+        let (bom, bom_warning) = decode_skill_md(b"\xEF\xBB\xBF---\nname: Bom\n---\n");
+        let (latin1, latin1_warning) = decode_skill_md(b"# Caf\xe9\n");
+
+        assert_eq!(plain, "# Café\n");
+        assert_eq!(plain_warning, None);
+        assert_eq!(parse_skill_md(&bom).name, "Bom", "BOM should be stripped before parsing");
+        assert_eq!(bom_warning, None);
+        assert_eq!(latin1, "# Café\n");
+        assert_eq!(latin1_warning, Some(ValidationWarning::NonUtf8 { encoding: "latin-1".to_string() }));
+    }
+
+    /// Test that text re-encoded in its detected encoding decodes back unchanged
+    #[test]
+    fn test_skill_md_encoding_round_trip() {
+        let text = "---\nname: Café\n---\n";
+        for encoding in [
+            SkillMdEncoding::Utf8,
+            SkillMdEncoding::Utf8Bom,
+            SkillMdEncoding::Utf16Le,
+            SkillMdEncoding::Utf16Be,
+            SkillMdEncoding::Latin1,
+        ] {
+            let bytes = encoding.encode(text).expect("Failed to encode");
+
+            assert_eq!(SkillMdEncoding::detect(&bytes), encoding);
+            assert_eq!(decode_skill_md(&bytes).0, text);
+        }
+        assert!(SkillMdEncoding::Latin1.encode("→").is_err());
+    }
+
+    // ==================== description length tests ====================
+
+    /// Test that a long description is flagged with a suggestion cut at a sentence boundary
+    #[test]
+    fn test_check_description_length_truncates_at_sentence() {
+        let description = "Formats code. Runs the linter on every file. Then it also writes a very long report.";
+
+        let warning = check_description_length(description, 50);
+
+        assert_eq!(warning, Some(ValidationWarning::DescriptionTooLong {
+            length: description.chars().count(),
+            max: 50,
+            suggestion: "Formats code. Runs the linter on every file.".to_string(),
+        }));
+        assert_eq!(check_description_length(description, 200), None);
+    }
+
+    /// Test that a single overlong sentence is cut at a word boundary
+    #[test]
+    fn test_check_description_length_cuts_long_sentence() {
+        let warning = check_description_length("one two three four five six seven", 15);
+
+        let Some(ValidationWarning::DescriptionTooLong { suggestion, .. }) = warning else {
+            panic!("Expected a description warning, got {:?}", warning);
+        };
+        assert_eq!(suggestion, "one two...");
+    }
+
+    // ==================== validate_skill_md tests ====================
+
+    /// Test that an empty file is reported as missing both name and description
+    #[test]
+    fn test_validate_skill_md_empty_file() {
+        assert_eq!(
+            validate_skill_md(""),
+            vec![ValidationWarning::MissingName, ValidationWarning::MissingDescription]
+        );
+    }
+
+    /// Test that empty and duplicate tools and broken frontmatter are reported, and a clean file is not
+    #[test]
+    fn test_validate_skill_md_reports_problems() {
+        let tools = "---\nname: Tools\ndescription: Desc\nallowed-tools:\n  - Bash\n  - \"\"\n  - Bash\n  - Bash\n---\n";
+        let broken = "---\nname: [unclosed\n---\n# Fallback\n\nStill described.\n";
+        let clean = "---\nname: Clean\ndescription: Desc\nallowed-tools:\n  - Read\n---\n";
+
+        assert_eq!(
+            validate_skill_md(tools),
+            vec![ValidationWarning::EmptyTool { index: 1 }, ValidationWarning::DuplicateTool { tool: "Bash".to_string() }]
+        );
+        assert_eq!(validate_skill_md(broken), vec![ValidationWarning::InvalidFrontmatter]);
+        assert!(validate_skill_md(clean).is_empty());
+    }
+
+    // ==================== tool disclaimer tests ====================
+
+    /// Test that a body disclaimer about a listed tool is flagged, and other mentions are not
+    #[test]
+    fn test_check_tool_disclaimers() {
+        let content = "---\nname: Review\nallowed-tools:\n  - Read\n  - Bash(git:*)\n---\n\
+            # Review\n\nUse Read to inspect files.\nDo not use `Bash`.\nDo not use bash either.\n\
+            Never use Bash for edits.\nDo not use Write.\n";
+        let tools = vec!["Read".to_string(), "Bash(git:*)".to_string()];
+
+        let warnings = check_tool_disclaimers(content, &tools);
+
+        assert_eq!(warnings, vec![ValidationWarning::ContradictedTool { tool: "Bash".to_string(), line: 10 }]);
+    }
+
     /// Test formatting SkillMetadata with single tool
     ///
     /// **Validates: Requirements 2.5**
@@ -723,6 +1717,7 @@ Heading description.
             name: "Single Tool Skill".to_string(),
             description: "Has one tool".to_string(),
             allowed_tools: vec!["only_tool".to_string()],
+            ..Default::default()
         };
 
         let output = format_skill_md(&metadata);
@@ -791,6 +1786,7 @@ mod proptests {
                 name,
                 description,
                 allowed_tools,
+                ..Default::default()
             })
     }
 
@@ -0,0 +1,158 @@
+//! Library Snapshot Module
+//!
+//! A snapshot records the name and content hash of every global skill. Diffing a
+//! saved snapshot against the current library reports which skills were added,
+//! removed, or changed in between, keyed by skill name. A fingerprint condenses
+//! the same information into one string for quick "did anything change" checks.
+
+use crate::{get_app_data_with_home, get_home_dir, skill_content_hash, Fnv1a};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// Content hash of a single skill at snapshot time
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct SkillHash {
+    pub name: String,
+    pub hash: String, // Hex-encoded so it survives a round trip through JavaScript numbers
+}
+
+/// Serializable record of the library's skills and their content hashes
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct Snapshot {
+    pub skills: Vec<SkillHash>,
+}
+
+/// Differences between a snapshot and the current library, each list sorted by name
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct LibraryDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<String>,
+}
+
+/// Records the content hash of every global skill, sorted by name.
+/// Skills that cannot be read are recorded with an empty hash.
+pub fn snapshot_library_with_home(home: &PathBuf) -> Snapshot {
+    let mut skills: Vec<SkillHash> = get_app_data_with_home(home)
+        .skills
+        .into_iter()
+        .map(|skill| SkillHash {
+            hash: skill_content_hash(&PathBuf::from(&skill.root).join(&skill.name))
+                .map(|hash| format!("{:016x}", hash))
+                .unwrap_or_default(),
+            name: skill.name,
+        })
+        .collect();
+    skills.sort_by(|a, b| a.name.cmp(&b.name));
+    Snapshot { skills }
+}
+
+#[tauri::command]
+pub fn snapshot_library() -> Snapshot {
+    let home = get_home_dir();
+    snapshot_library_with_home(&home)
+}
+
+/// Compares a saved snapshot with the current library.
+pub fn diff_snapshot_with_home(snapshot: &Snapshot, home: &PathBuf) -> LibraryDiff {
+    let before: BTreeMap<&str, &str> = snapshot.skills.iter().map(|s| (s.name.as_str(), s.hash.as_str())).collect();
+    let current = snapshot_library_with_home(home);
+    let after: BTreeMap<&str, &str> = current.skills.iter().map(|s| (s.name.as_str(), s.hash.as_str())).collect();
+
+    let mut diff = LibraryDiff::default();
+    for (name, hash) in &after {
+        match before.get(name) {
+            None => diff.added.push(name.to_string()),
+            Some(old_hash) if old_hash != hash => diff.changed.push(name.to_string()),
+            Some(_) => {}
+        }
+    }
+    diff.removed = before.keys().filter(|name| !after.contains_key(*name)).map(|name| name.to_string()).collect();
+    diff
+}
+
+#[tauri::command]
+pub fn diff_snapshot(snapshot: Snapshot) -> LibraryDiff {
+    let home = get_home_dir();
+    diff_snapshot_with_home(&snapshot, &home)
+}
+
+/// Computes a hash over every global skill's name and content hash, in name order.
+/// Identical libraries produce the same fingerprint regardless of directory order.
+pub fn library_fingerprint_with_home(home: &PathBuf) -> String {
+    let mut hash = Fnv1a::default();
+    for skill in snapshot_library_with_home(home).skills {
+        hash.feed_field(skill.name.as_bytes());
+        hash.feed_field(skill.hash.as_bytes());
+    }
+    format!("{:016x}", hash.0)
+}
+
+#[tauri::command]
+pub fn library_fingerprint() -> String {
+    let home = get_home_dir();
+    library_fingerprint_with_home(&home)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    /// Test that modifying, adding and removing skills after a snapshot are all reported
+    #[test]
+    fn test_diff_snapshot_reports_changes() {
+        // Arrange: Three skills, then a snapshot
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        for name in ["edited", "untouched", "deleted"] {
+            fs::create_dir_all(global_dir.join(name)).expect("Failed to create skill directory");
+            fs::write(global_dir.join(name).join("SKILL.md"), format!("---\nname: {}\n---\n", name))
+                .expect("Failed to write SKILL.md");
+        }
+        let snapshot = snapshot_library_with_home(&home_path);
+        assert_eq!(snapshot.skills.len(), 3);
+
+        // Act: Edit one skill, delete one and add one
+        fs::write(global_dir.join("edited/SKILL.md"), "---\nname: edited\ndescription: now longer\n---\n")
+            .expect("Failed to edit SKILL.md");
+        fs::remove_dir_all(global_dir.join("deleted")).expect("Failed to delete skill");
+        fs::create_dir_all(global_dir.join("new")).expect("Failed to create skill directory");
+        let diff = diff_snapshot_with_home(&snapshot, &home_path);
+
+        // Assert
+        assert_eq!(diff, LibraryDiff {
+            added: vec!["new".to_string()],
+            removed: vec!["deleted".to_string()],
+            changed: vec!["edited".to_string()],
+        });
+    }
+
+    /// Test that the fingerprint is stable across calls and changes with a skill's content
+    #[test]
+    fn test_library_fingerprint_stable_and_content_sensitive() {
+        // Arrange
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        for name in ["alpha", "beta"] {
+            fs::create_dir_all(global_dir.join(name)).expect("Failed to create skill directory");
+            fs::write(global_dir.join(name).join("SKILL.md"), format!("---\nname: {}\n---\n", name))
+                .expect("Failed to write SKILL.md");
+        }
+
+        // Act
+        let first = library_fingerprint_with_home(&home_path);
+        let second = library_fingerprint_with_home(&home_path);
+        fs::write(global_dir.join("beta/SKILL.md"), "---\nname: beta\ndescription: edited\n---\n")
+            .expect("Failed to edit SKILL.md");
+        let edited = library_fingerprint_with_home(&home_path);
+
+        // Assert
+        assert_eq!(first, second);
+        assert_eq!(first.len(), 16);
+        assert_ne!(first, edited);
+    }
+}
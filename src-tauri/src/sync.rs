@@ -0,0 +1,170 @@
+//! Agent Sync Planning Module
+//!
+//! Syncing an agent to the global library means linking every global skill the
+//! agent is missing, except disabled ones, and removing its symlinks that no longer resolve. Local skill
+//! directories and files are never touched. This module computes that plan without
+//! changing anything, for one agent, a saved agent group, or aggregated across all
+//! detected agents, so the UI can preview the impact before the user commits.
+
+use crate::{
+    config, detect_agents_with_home, find_global_skill, get_home_dir, global_skill_names, global_skill_roots_with_home,
+    is_skill_disabled,
+};
+use std::fs;
+use std::path::PathBuf;
+
+/// Changes a sync would make to one agent
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct SyncPlan {
+    pub add: Vec<String>,    // Global skills that would be linked, sorted
+    pub remove: Vec<String>, // Broken symlinks that would be removed, sorted
+}
+
+/// Computes what syncing one agent to the global library would change.
+pub fn sync_agent_to_global_preview_with_home(agent_id: &str, home: &PathBuf) -> Result<SyncPlan, String> {
+    let agent = detect_agents_with_home(home)
+        .into_iter()
+        .find(|a| a.id == agent_id)
+        .ok_or_else(|| format!("Agent '{}' not found", agent_id))?;
+    let agent_dir = home.join(&agent.path);
+
+    let mut plan = SyncPlan::default();
+    let roots = global_skill_roots_with_home(home);
+    let mut global_names: Vec<String> = global_skill_names(&roots).into_iter().collect();
+    global_names.sort();
+    for name in global_names {
+        let disabled = find_global_skill(&roots, &name).map(|dir| is_skill_disabled(&dir)).unwrap_or(false);
+        // Anything already at the path, even a local copy, counts as present
+        if !disabled && fs::symlink_metadata(agent_dir.join(&name)).is_err() {
+            plan.add.push(name);
+        }
+    }
+
+    if let Ok(entries) = fs::read_dir(&agent_dir) {
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let is_symlink = fs::symlink_metadata(entry.path()).map(|m| m.file_type().is_symlink()).unwrap_or(false);
+            if !name.starts_with('.') && is_symlink && fs::metadata(entry.path()).is_err() {
+                plan.remove.push(name);
+            }
+        }
+    }
+    plan.remove.sort();
+
+    Ok(plan)
+}
+
+#[tauri::command]
+pub fn sync_agent_to_global_preview(agent_id: String) -> Result<SyncPlan, String> {
+    let home = get_home_dir();
+    sync_agent_to_global_preview_with_home(&agent_id, &home)
+}
+
+/// Computes the sync plan of every detected agent, in agent definition order.
+pub fn sync_all_agents_preview_with_home(home: &PathBuf) -> Vec<(String, SyncPlan)> {
+    detect_agents_with_home(home)
+        .into_iter()
+        .filter(|agent| agent.detected)
+        .filter_map(|agent| {
+            sync_agent_to_global_preview_with_home(&agent.id, home)
+                .ok()
+                .map(|plan| (agent.id, plan))
+        })
+        .collect()
+}
+
+#[tauri::command]
+pub fn sync_all_agents_preview() -> Vec<(String, SyncPlan)> {
+    let home = get_home_dir();
+    sync_all_agents_preview_with_home(&home)
+}
+
+/// Computes the sync plan of every agent in a saved agent group, in group order.
+/// Agents that are not found are left out; an unknown group yields no plans.
+pub fn sync_group_preview_with_home(group_name: &str, home: &PathBuf) -> Vec<(String, SyncPlan)> {
+    let config = config::load_config_with_home(home);
+    config
+        .agent_groups
+        .get(group_name)
+        .into_iter()
+        .flatten()
+        .filter_map(|agent_id| {
+            sync_agent_to_global_preview_with_home(agent_id, home)
+                .ok()
+                .map(|plan| (agent_id.clone(), plan))
+        })
+        .collect()
+}
+
+#[tauri::command]
+pub fn sync_group_preview(group_name: String) -> Vec<(String, SyncPlan)> {
+    let home = get_home_dir();
+    sync_group_preview_with_home(&group_name, &home)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::create_symlink;
+    use tempfile::TempDir;
+
+    /// Test that a group preview returns each member's own plan, in group order
+    #[test]
+    fn test_sync_group_preview_two_agents() {
+        // Arrange: cursor already links "alpha", claude has nothing; a group lists both
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        for name in ["alpha", "beta"] {
+            fs::create_dir_all(global_dir.join(name)).expect("Failed to create skill directory");
+        }
+        let cursor_dir = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_dir).expect("Failed to create cursor directory");
+        fs::create_dir_all(home_path.join(".claude/skills")).expect("Failed to create claude directory");
+        create_symlink(global_dir.join("alpha"), cursor_dir.join("alpha")).expect("Failed to create symlink");
+        let mut config = config::AppConfig::default();
+        config
+            .agent_groups
+            .insert("team".to_string(), vec!["cursor".to_string(), "claude-code".to_string()]);
+        config::save_config_with_home(&config, &home_path).expect("Failed to save config");
+
+        // Act
+        let preview = sync_group_preview_with_home("team", &home_path);
+
+        // Assert
+        assert_eq!(preview, vec![
+            ("cursor".to_string(), SyncPlan { add: vec!["beta".to_string()], remove: vec![] }),
+            ("claude-code".to_string(), SyncPlan { add: vec!["alpha".to_string(), "beta".to_string()], remove: vec![] }),
+        ]);
+        assert!(sync_group_preview_with_home("unknown", &home_path).is_empty());
+    }
+
+    /// Test that the aggregate preview matches each agent's own plan
+    #[test]
+    fn test_sync_all_agents_preview_matches_per_agent_plans() {
+        // Arrange: Two global skills; cursor links one and has a broken link, claude has a local copy
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let global_dir = home_path.join(".agents/skills");
+        fs::create_dir_all(global_dir.join("alpha")).expect("Failed to create skill directory");
+        fs::create_dir_all(global_dir.join("beta")).expect("Failed to create skill directory");
+        let cursor_dir = home_path.join(".cursor/skills");
+        fs::create_dir_all(&cursor_dir).expect("Failed to create cursor directory");
+        create_symlink(global_dir.join("alpha"), cursor_dir.join("alpha")).expect("Failed to create symlink");
+        create_symlink(global_dir.join("gone"), cursor_dir.join("gone")).expect("Failed to create broken symlink");
+        fs::create_dir_all(home_path.join(".claude/skills/beta")).expect("Failed to create local skill");
+
+        // Act
+        let preview = sync_all_agents_preview_with_home(&home_path);
+
+        // Assert
+        assert_eq!(preview, vec![
+            ("claude-code".to_string(), SyncPlan { add: vec!["alpha".to_string()], remove: vec![] }),
+            ("cursor".to_string(), SyncPlan { add: vec!["beta".to_string()], remove: vec!["gone".to_string()] }),
+        ]);
+        for (agent_id, plan) in &preview {
+            assert_eq!(&sync_agent_to_global_preview_with_home(agent_id, &home_path).unwrap(), plan);
+        }
+        assert!(fs::symlink_metadata(cursor_dir.join("gone")).is_ok(), "Preview must not change anything");
+    }
+}
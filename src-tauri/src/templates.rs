@@ -0,0 +1,140 @@
+//! Skill Templates Module
+//!
+//! Templates are ordinary skill directories kept in `~/.agents/.templates/<template>/`.
+//! Creating a skill from a template copies the template into the highest-priority
+//! global root and sets the SKILL.md `name` to the new skill's name.
+
+use crate::{
+    copy_dir_recursive, edit_skill_md, ensure_not_safe_mode, find_global_skill, get_home_dir,
+    global_skill_roots_with_home, skill_parser::format_skill_md, validate_skill_name, SkillMetadata,
+};
+use std::fs;
+use std::path::PathBuf;
+
+/// Location of the templates directory, relative to the home directory
+pub const TEMPLATES_DIR: &str = ".agents/.templates";
+
+/// Lists the available templates, sorted by name.
+pub fn list_templates_with_home(home: &PathBuf) -> Vec<String> {
+    let mut templates: Vec<String> = fs::read_dir(home.join(TEMPLATES_DIR))
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|entry| entry.path().is_dir())
+                .map(|entry| entry.file_name().to_string_lossy().to_string())
+                .filter(|name| !name.starts_with('.'))
+                .collect()
+        })
+        .unwrap_or_default();
+    templates.sort();
+    templates
+}
+
+#[tauri::command]
+pub fn list_templates() -> Vec<String> {
+    let home = get_home_dir();
+    list_templates_with_home(&home)
+}
+
+/// Creates a new global skill by copying a template, then sets its SKILL.md name.
+/// A template without a SKILL.md gets a minimal one.
+pub fn create_skill_from_template_with_home(name: &str, template: &str, home: &PathBuf) -> Result<(), String> {
+    ensure_not_safe_mode(home)?;
+    validate_skill_name(name)?;
+    // Template names follow the same rules, so a template cannot be read from outside the templates directory
+    validate_skill_name(template).map_err(|_| format!("Invalid template name '{}'", template))?;
+    let template_dir = home.join(TEMPLATES_DIR).join(template);
+    if !template_dir.is_dir() {
+        return Err(format!("Template '{}' not found in {}", template, TEMPLATES_DIR));
+    }
+
+    let roots = global_skill_roots_with_home(home);
+    if find_global_skill(&roots, name).is_some() {
+        return Err(format!("Skill '{}' already exists in global skills", name));
+    }
+
+    // New skills are created in the highest-priority root
+    let skill_dir = roots[0].join(name);
+    copy_dir_recursive(&template_dir, &skill_dir)?;
+
+    if skill_dir.join("SKILL.md").exists() {
+        edit_skill_md(&skill_dir, |metadata| metadata.name = name.to_string())?;
+    } else {
+        let metadata = SkillMetadata { name: name.to_string(), ..Default::default() };
+        fs::write(skill_dir.join("SKILL.md"), format_skill_md(&metadata))
+            .map_err(|e| format!("Failed to write SKILL.md: {}", e))?;
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn create_skill_from_template(name: String, template: String) -> Result<(), String> {
+    let home = get_home_dir();
+    create_skill_from_template_with_home(&name, &template, &home)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_skill_md;
+    use tempfile::TempDir;
+
+    /// Test that a skill created from a template gets the template files and the new name
+    #[test]
+    fn test_create_skill_from_template_substitutes_name() {
+        // Arrange: A template with a SKILL.md and an extra file
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let template_dir = home_path.join(TEMPLATES_DIR).join("basic");
+        fs::create_dir_all(template_dir.join("scripts")).expect("Failed to create template directory");
+        fs::write(
+            template_dir.join("SKILL.md"),
+            "---\nname: TEMPLATE\ndescription: Describe the skill\n---\n\n# Usage\n",
+        ).expect("Failed to write template SKILL.md");
+        fs::write(template_dir.join("scripts/run.sh"), "echo run").expect("Failed to write script");
+
+        // Act
+        create_skill_from_template_with_home("my-new-skill", "basic", &home_path).expect("create should succeed");
+
+        // Assert
+        let skill_dir = home_path.join(".agents/skills/my-new-skill");
+        let content = fs::read_to_string(skill_dir.join("SKILL.md")).expect("Failed to read SKILL.md");
+        let metadata = parse_skill_md(&content);
+        assert_eq!(metadata.name, "my-new-skill");
+        assert_eq!(metadata.description, "Describe the skill");
+        assert!(content.ends_with("# Usage\n"), "Template body should be kept");
+        assert!(skill_dir.join("scripts/run.sh").exists(), "Template files should be copied");
+        assert_eq!(list_templates_with_home(&home_path), vec!["basic".to_string()]);
+    }
+
+    /// Test that a missing template is a clear error
+    #[test]
+    fn test_create_skill_from_missing_template() {
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+
+        let error = create_skill_from_template_with_home("my-new-skill", "nope", &home_path)
+            .expect_err("missing template should error");
+
+        assert!(error.contains("Template 'nope' not found"), "Unexpected error: {}", error);
+        assert!(!home_path.join(".agents/skills/my-new-skill").exists());
+    }
+
+    /// Test that a template name cannot reach outside the templates directory
+    #[test]
+    fn test_create_skill_from_template_rejects_traversal() {
+        // Arrange: A directory next to the templates directory
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        fs::create_dir_all(home_path.join(".agents/private")).expect("Failed to create directory");
+
+        // Act
+        let error = create_skill_from_template_with_home("my-new-skill", "../private", &home_path)
+            .expect_err("traversal should error");
+
+        // Assert
+        assert_eq!(error, "Invalid template name '../private'");
+        assert!(!home_path.join(".agents/skills/my-new-skill").exists());
+    }
+}
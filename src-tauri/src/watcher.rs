@@ -0,0 +1,121 @@
+//! Agent Watcher Module
+//!
+//! This module watches a single agent's skills directory and emits an
+//! `agent-<id>-changed` event when anything under it changes, so an agent detail
+//! page can refresh without polling. Each agent has its own watcher; starting or
+//! stopping one never affects the others. Events are debounced so a burst of file
+//! writes produces a single notification.
+
+use crate::{detect_agents_with_home, get_home_dir};
+use notify_debouncer_mini::notify::{RecommendedWatcher, RecursiveMode};
+use notify_debouncer_mini::{new_debouncer, DebounceEventResult, Debouncer};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::Emitter;
+
+/// Quiet period after the last change before an event is emitted
+pub const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// A running watcher; dropping it stops watching
+pub type AgentWatcher = Debouncer<RecommendedWatcher>;
+
+/// Active agent watchers, by agent ID. Registered as Tauri managed state.
+#[derive(Default)]
+pub struct AgentWatchers(Mutex<HashMap<String, AgentWatcher>>);
+
+/// Name of the event emitted when an agent's skills directory changes
+pub fn agent_changed_event(agent_id: &str) -> String {
+    format!("agent-{}-changed", agent_id)
+}
+
+/// Starts watching an agent's skills directory, calling `on_change` once per debounced
+/// burst of changes. The directory must exist.
+pub fn watch_agent_with_home<F>(agent_id: &str, home: &PathBuf, on_change: F) -> Result<AgentWatcher, String>
+where
+    F: Fn() + Send + 'static,
+{
+    let agent = detect_agents_with_home(home)
+        .into_iter()
+        .find(|a| a.id == agent_id)
+        .ok_or_else(|| format!("Agent '{}' not found", agent_id))?;
+    let agent_dir = home.join(&agent.path);
+    if !agent_dir.is_dir() {
+        return Err(format!("Agent '{}' has no skills directory to watch", agent_id));
+    }
+
+    let mut debouncer = new_debouncer(DEBOUNCE, move |result: DebounceEventResult| {
+        if matches!(result, Ok(events) if !events.is_empty()) {
+            on_change();
+        }
+    })
+    .map_err(|e| format!("Failed to create watcher: {}", e))?;
+    debouncer
+        .watcher()
+        .watch(&agent_dir, RecursiveMode::Recursive)
+        .map_err(|e| format!("Failed to watch {}: {}", agent_dir.to_string_lossy(), e))?;
+
+    Ok(debouncer)
+}
+
+/// Starts emitting `agent-<id>-changed` events for an agent, replacing any
+/// previous watcher for the same agent.
+#[tauri::command]
+pub fn watch_agent(app: tauri::AppHandle, watchers: tauri::State<'_, AgentWatchers>, agent_id: String) -> Result<(), String> {
+    let home = get_home_dir();
+    let event = agent_changed_event(&agent_id);
+    let watcher = watch_agent_with_home(&agent_id, &home, move || {
+        let _ = app.emit(&event, ());
+    })?;
+    watchers
+        .0
+        .lock()
+        .map_err(|_| "Watcher state is unavailable".to_string())?
+        .insert(agent_id, watcher);
+    Ok(())
+}
+
+/// Stops the watcher for an agent, if one is running.
+#[tauri::command]
+pub fn unwatch_agent(watchers: tauri::State<'_, AgentWatchers>, agent_id: String) -> Result<(), String> {
+    watchers
+        .0
+        .lock()
+        .map_err(|_| "Watcher state is unavailable".to_string())?
+        .remove(&agent_id);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::sync::mpsc;
+    use tempfile::TempDir;
+
+    /// Test that a change in the watched agent's directory fires, and one elsewhere does not
+    #[test]
+    fn test_watch_agent_scoped_to_agent_directory() {
+        // Arrange: cursor is watched; claude's directory is unrelated
+        let temp_home = TempDir::new().expect("Failed to create temp directory");
+        let home_path = temp_home.path().to_path_buf();
+        let cursor_dir = home_path.join(".cursor/skills");
+        let claude_dir = home_path.join(".claude/skills");
+        fs::create_dir_all(&cursor_dir).expect("Failed to create cursor directory");
+        fs::create_dir_all(&claude_dir).expect("Failed to create claude directory");
+        let (tx, rx) = mpsc::channel();
+        let _watcher = watch_agent_with_home("cursor", &home_path, move || {
+            let _ = tx.send(());
+        })
+        .expect("watch should succeed");
+
+        // Act & Assert: A change outside the agent's directory is ignored
+        fs::write(claude_dir.join("other.md"), "other").expect("Failed to write file");
+        assert!(rx.recv_timeout(DEBOUNCE * 4).is_err(), "Unrelated change should not fire");
+
+        // Act & Assert: A change inside it fires
+        fs::create_dir_all(cursor_dir.join("new-skill")).expect("Failed to create skill directory");
+        assert!(rx.recv_timeout(Duration::from_secs(5)).is_ok(), "Change in watched agent should fire");
+    }
+}
@@ -4,6 +4,7 @@ import { Sidebar } from "@/components/Sidebar";
 import { GlobalSkillsPage } from "@/components/GlobalSkillsPage";
 import { AgentDetailPage } from "@/components/AgentDetailPage";
 import { ToastContainer, useToast } from "@/components/ui/toast";
+import { describeError } from "@/lib/api";
 import type { AppData, AgentDetailData } from "@/lib/types";
 
 const MIN_LOADING_DURATION = 800;
@@ -39,7 +40,7 @@ function App() {
       const result = await invoke<AppData>("get_app_data");
       setData(result);
     } catch (error) {
-      const errorMessage = error instanceof Error ? error.message : String(error);
+      const errorMessage = describeError(error);
       showError("Failed to load skills", errorMessage);
       console.error("Failed to fetch data:", error);
     } finally {
@@ -53,7 +54,7 @@ function App() {
       const result = await invoke<AgentDetailData>("get_agent_detail", { agentId });
       setAgentDetail(result);
     } catch (error) {
-      const errorMessage = error instanceof Error ? error.message : String(error);
+      const errorMessage = describeError(error);
       showError("Failed to load agent details", errorMessage);
       console.error("Failed to fetch agent detail:", error);
     } finally {
@@ -90,7 +91,7 @@ function App() {
       await fetchData();
       showSuccess("Skill linked", `Linked ${skillName}`);
     } catch (error) {
-      const errorMessage = error instanceof Error ? error.message : String(error);
+      const errorMessage = describeError(error);
       showError("Failed to link skill", errorMessage);
     }
   };
@@ -103,7 +104,7 @@ function App() {
       await fetchData();
       showSuccess("Skill unlinked", `Unlinked ${skillName}`);
     } catch (error) {
-      const errorMessage = error instanceof Error ? error.message : String(error);
+      const errorMessage = describeError(error);
       showError("Failed to unlink skill", errorMessage);
     }
   };
@@ -116,7 +117,7 @@ function App() {
       await fetchData();
       showSuccess("Skill deleted", `Deleted ${skillName}`);
     } catch (error) {
-      const errorMessage = error instanceof Error ? error.message : String(error);
+      const errorMessage = describeError(error);
       showError("Failed to delete skill", errorMessage);
     }
   };
@@ -129,7 +130,7 @@ function App() {
       await fetchData();
       showSuccess("Skill uploaded", `Uploaded ${skillName} to global skills`);
     } catch (error) {
-      const errorMessage = error instanceof Error ? error.message : String(error);
+      const errorMessage = describeError(error);
       showError("Failed to upload skill", errorMessage);
     }
   };
@@ -159,7 +160,7 @@ function App() {
         showSuccess("Skills linked", `Linked ${successCount} skill(s)`);
       }
     } catch (error) {
-      const errorMessage = error instanceof Error ? error.message : String(error);
+      const errorMessage = describeError(error);
       showError("Failed to link skills", errorMessage);
     } finally {
       setLoadingWithMinDuration(false);
@@ -191,7 +192,7 @@ function App() {
         showSuccess("Skills unlinked", `Unlinked ${successCount} skill(s)`);
       }
     } catch (error) {
-      const errorMessage = error instanceof Error ? error.message : String(error);
+      const errorMessage = describeError(error);
       showError("Failed to unlink skills", errorMessage);
     } finally {
       setLoadingWithMinDuration(false);
@@ -222,7 +223,7 @@ function App() {
         showSuccess("Skill linked", `Linked to ${successCount} agent(s)`);
       }
     } catch (error) {
-      const errorMessage = error instanceof Error ? error.message : String(error);
+      const errorMessage = describeError(error);
       showError("Failed to link skill", errorMessage);
     } finally {
       setLoadingWithMinDuration(false);
@@ -253,7 +254,7 @@ function App() {
         showSuccess("Skill unlinked", `Unlinked from ${successCount} agent(s)`);
       }
     } catch (error) {
-      const errorMessage = error instanceof Error ? error.message : String(error);
+      const errorMessage = describeError(error);
       showError("Failed to unlink skill", errorMessage);
     } finally {
       setLoadingWithMinDuration(false);
@@ -315,6 +315,8 @@ function StatusBadge({ status }: { status: AgentSkill['status'] }) {
       return <Badge variant="secondary" className="bg-warning/20 text-warning-foreground border-warning/30">Local</Badge>;
     case 'not_installed':
       return <Badge variant="outline" className="text-muted-foreground">Not Installed</Badge>;
+    case 'broken_symlink':
+      return <Badge variant="outline" className="text-destructive border-destructive/30">Broken Link</Badge>;
   }
 }
 
@@ -353,7 +355,7 @@ interface SkillActionsProps {
 }
 
 function SkillActions({ skill, agentDetected, loading, onLink, onUnlink, onDelete, onUpload }: SkillActionsProps) {
-  if (skill.status === 'symlink') {
+  if (skill.status === 'symlink' || skill.status === 'broken_symlink') {
     return (
       <Button
         variant="ghost"
@@ -10,7 +10,7 @@
  */
 
 import { invoke } from "@tauri-apps/api/core";
-import type { AppData, BatchResult } from "./types";
+import type { AppData, BatchResult, ConfirmPreview } from "./types";
 
 /**
  * Error class for API-related errors.
@@ -27,6 +27,18 @@ export class ApiError extends Error {
   }
 }
 
+/**
+ * Extracts a human-readable message from a rejected invoke, including typed
+ * `SkillError` objects, plain string errors and JavaScript errors.
+ */
+export function describeError(error: unknown): string {
+  if (error instanceof Error) return error.message;
+  if (typeof error === "object" && error !== null && "message" in error) {
+    return String((error as { message: unknown }).message);
+  }
+  return String(error);
+}
+
 /**
  * Fetches all application data including agents and skills.
  *
@@ -95,15 +107,26 @@ export async function toggleSkill(
  * Non-detected agents are skipped.
  *
  * @param skillName - The name of the skill to link to all agents
+ * @param verify - When true, agents whose new link does not resolve are reported as failed
+ * @param validateBeforeLink - When true, a skill that fails validation is not linked to any agent
+ * @param dryRun - When true, nothing is linked; the result reports what would happen
  * @returns Promise resolving to BatchResult with success and failed operations
  * @throws ApiError if the batch operation fails entirely
  *
  * Requirements: 6.1
  */
-export async function linkSkillToAll(skillName: string): Promise<BatchResult> {
+export async function linkSkillToAll(
+  skillName: string,
+  verify?: boolean,
+  validateBeforeLink?: boolean,
+  dryRun?: boolean
+): Promise<BatchResult> {
   try {
     return await invoke<BatchResult>("link_skill_to_all", {
       skillName,
+      verify,
+      validateBeforeLink,
+      dryRun,
     });
   } catch (error) {
     throw new ApiError(
@@ -114,6 +137,29 @@ export async function linkSkillToAll(skillName: string): Promise<BatchResult> {
   }
 }
 
+/**
+ * Previews unlinking a skill from all agents.
+ *
+ * @param skillName - The name of the skill to unlink from all agents
+ * @returns Promise resolving to the affected agent IDs and a confirmation token
+ * @throws ApiError if the preview fails
+ */
+export async function unlinkSkillFromAllPreview(
+  skillName: string
+): Promise<ConfirmPreview> {
+  try {
+    return await invoke<ConfirmPreview>("unlink_skill_from_all_preview", {
+      skillName,
+    });
+  } catch (error) {
+    throw new ApiError(
+      `Failed to preview unlinking skill "${skillName}"`,
+      "unlink_skill_from_all_preview",
+      error
+    );
+  }
+}
+
 /**
  * Unlinks a skill from all agents.
  *
@@ -122,17 +168,23 @@ export async function linkSkillToAll(skillName: string): Promise<BatchResult> {
  * detection status, and reports success/failure for each.
  *
  * @param skillName - The name of the skill to unlink from all agents
+ * @param confirmToken - Token from {@link unlinkSkillFromAllPreview}
+ * @param verifyTarget - When true, links that do not point at the global skill are left in place and reported as failed
  * @returns Promise resolving to BatchResult with success and failed operations
  * @throws ApiError if the batch operation fails entirely
  *
  * Requirements: 6.2
  */
 export async function unlinkSkillFromAll(
-  skillName: string
+  skillName: string,
+  confirmToken: string,
+  verifyTarget = false
 ): Promise<BatchResult> {
   try {
     return await invoke<BatchResult>("unlink_skill_from_all", {
       skillName,
+      confirmToken,
+      verifyTarget,
     });
   } catch (error) {
     throw new ApiError(
@@ -26,6 +26,14 @@ export interface Agent {
   path: string;
   /** Whether the agent's skills directory exists on the user's system */
   detected: boolean;
+  /** Whether the agent was found by directory discovery rather than the known agent list */
+  discovered?: boolean;
+  /** How global skills are linked: a directory symlink, a directory of per-entry symlinks, or a SKILL.md symlink only */
+  link_layout?: 'directory' | 'contents' | 'skill_md_only';
+  /** Whether skills inside plugin subdirectories are listed, named `<plugin>/<skill>` */
+  nested_scan?: boolean;
+  /** Installed agent version, when it can be detected */
+  version?: string | null;
 }
 
 /**
@@ -42,6 +50,18 @@ export interface SkillMetadata {
   description: string;
   /** List of tools that the skill is allowed to use */
   allowed_tools: string[];
+  /** Guidance on when an agent should use the skill */
+  when_to_use?: string | null;
+  /** Author of the skill */
+  author?: string | null;
+  /** Source repository URL of the skill */
+  repository?: string | null;
+  /** License of the skill */
+  license?: string | null;
+  /** Version of the skill, kept as written */
+  version?: string | null;
+  /** Free-form tags for grouping and search */
+  tags?: string[];
 }
 
 /**
@@ -61,12 +81,20 @@ export interface Skill {
   linked_agents: string[];
   /** List of agent IDs that have this skill linked via symlink only */
   symlinked_agents: string[];
+  /** Agent ID and symlink target for each symlinked agent whose link could be read */
+  symlink_targets?: [string, string][];
+  /** Global root directory the skill was found in */
+  root?: string;
+  /** Lower-priority global roots that also contain a skill with this name */
+  shadowed_roots?: string[];
+  /** Whether the skill has a `.disabled` marker, so batch link operations skip it */
+  disabled?: boolean;
 }
 
 /**
  * Status of a skill in an agent's directory
  */
-export type AgentSkillStatus = 'symlink' | 'local' | 'not_installed';
+export type AgentSkillStatus = 'symlink' | 'local' | 'not_installed' | 'broken_symlink';
 
 /**
  * Represents a skill as seen from an agent's perspective.
@@ -77,12 +105,14 @@ export interface AgentSkill {
   name: string;
   /** Metadata parsed from SKILL.md */
   metadata: SkillMetadata;
-  /** Status: symlink, local, or not_installed */
+  /** Status: symlink, local, not_installed, or broken_symlink */
   status: AgentSkillStatus;
   /** Source path (symlink target or local path), null if not installed */
   source_path: string | null;
   /** Whether this skill exists in global skills directory */
   in_global: boolean;
+  /** Whether the metadata comes from an agent-specific `<skill>.override/SKILL.md` */
+  has_override?: boolean;
 }
 
 /**
@@ -91,6 +121,8 @@ export interface AgentSkill {
 export interface AgentDetailData {
   agent: Agent;
   skills: AgentSkill[];
+  /** Whether any global skills directory exists; when none does, no skill is in global */
+  global_dir_exists?: boolean;
 }
 
 /**
@@ -117,6 +149,10 @@ export interface BatchResult {
   success: string[];
   /** List of failed operations with error details */
   failed: FailedOperation[];
+  /** Agents intentionally left alone (e.g. a local copy occupies the path), with the reason */
+  skipped?: FailedOperation[];
+  /** Agents left alone because the skill has a `.disabled` marker, with the reason */
+  skipped_disabled?: FailedOperation[];
 }
 
 /**
@@ -130,3 +166,37 @@ export interface FailedOperation {
   /** Error message describing why the operation failed */
   error: string;
 }
+
+/**
+ * What a destructive batch command would affect.
+ *
+ * Pass `confirm_token` back to the command to run it.
+ */
+export interface ConfirmPreview {
+  /** Items the command would remove, sorted */
+  affected: string[];
+  /** Token confirming this preview; stale once the affected items change */
+  confirm_token: string;
+}
+
+/**
+ * Typed error returned by `toggle_skill`, `delete_local_skill`, `upload_to_global` and
+ * `copy_local_skill`.
+ */
+export interface SkillError {
+  /** Which failure occurred, e.g. "agent_not_found" or "already_exists" */
+  kind:
+    | "agent_not_found"
+    | "skill_not_found"
+    | "already_exists"
+    | "invalid_name"
+    | "is_symlink"
+    | "not_a_symlink"
+    | "not_a_directory"
+    | "invalid_link"
+    | "root_not_found"
+    | "safe_mode"
+    | "io";
+  /** Human-readable description of the failure */
+  message: string;
+}